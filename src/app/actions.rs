@@ -31,7 +31,7 @@ use std::path::PathBuf;
 /// Actions are produced by the event handler and executed by the action processor.
 /// They represent the boundary between pure state transformations and effectful
 /// operations like session management and worker communication.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Action {
     /// Closes the focused floating pane, hiding the plugin UI.
     ///
@@ -53,6 +53,8 @@ pub enum Action {
         name: String,
         /// Filesystem path to set as working directory.
         path: PathBuf,
+        /// Layout chosen for the project, used if the session no longer exists.
+        layout: Option<String>,
     },
 
     /// Creates a new Zellij session.
@@ -64,6 +66,64 @@ pub enum Action {
         name: String,
         /// Filesystem path to set as working directory.
         path: PathBuf,
+        /// Layout chosen for the project, `None` for Zellij's default.
+        layout: Option<String>,
+    },
+
+    /// Opens a new tab in the current Zellij session.
+    ///
+    /// Used when the Enter action for the current view is configured as `tab`,
+    /// keeping the user in their session while working in another project.
+    OpenTab {
+        /// Name for the new tab.
+        name: String,
+        /// Filesystem path to set as the tab's working directory.
+        path: PathBuf,
+    },
+
+    /// Opens a project's layout file in an editor pane.
+    ///
+    /// The file is created from a template if it does not exist yet.
+    EditLayout {
+        /// Project name, used for the template's tab name.
+        name: String,
+        /// Path of the layout file to edit, relative to the plugin's working
+        /// directory.
+        path: PathBuf,
+    },
+
+    /// Opens a project directory in the configured editor, without a session.
+    ///
+    /// The editor runs in a command pane of the current session.
+    OpenInEditor {
+        /// Project directory, used as the editor's working directory.
+        path: PathBuf,
+    },
+
+    /// Copies text to the host clipboard.
+    CopyToClipboard {
+        /// Text to copy.
+        text: String,
+    },
+
+    /// Renames the current Zellij session.
+    ///
+    /// Zellij can only rename the session the plugin runs in, so `old_name`
+    /// is always the current session.
+    RenameSession {
+        /// Current name of the session.
+        old_name: String,
+        /// New name for the session.
+        new_name: String,
+    },
+
+    /// Clears the footer notice once `secs` seconds have passed.
+    ///
+    /// A later notice replaces the pending expiry, so each notice stays
+    /// visible for its full duration.
+    ExpireFooterNotice {
+        /// Seconds until the notice is cleared.
+        secs: f64,
     },
 
     /// Kills an existing Zellij session.
@@ -74,4 +134,12 @@ pub enum Action {
         /// Name of the session to terminate.
         name: String,
     },
+
+    /// Kills several Zellij sessions at once.
+    ///
+    /// Used for sessions marked with Space in the Sessions view.
+    KillSessions {
+        /// Names of the sessions to terminate.
+        names: Vec<String>,
+    },
 }
@@ -16,9 +16,18 @@
 //! # Event Types
 //!
 //! Events fall into several categories:
-//! - **Navigation**: `KeyDown`, `KeyUp`, `SelectProject`
-//! - **Input**: `Char`, `Backspace`, `Escape`
-//! - **Mode Switching**: `SearchMode`, `ShowProjects`, `ShowSessions`
+//! - **Navigation**: `KeyDown`, `KeyUp`, `PageDown`, `PageUp`, `SelectFirst`,
+//!   `SelectLast`, `SelectProject`, `OpenProjectInTab`
+//! - **Input**: `Char`, `Backspace`, `Escape`, `RecallOlderSearch`,
+//!   `RecallNewerSearch`
+//! - **Session Names**: `StartRename`, `ConfirmRename`, `StartNewSession`,
+//!   `ConfirmNewSession`
+//! - **Layouts**: `OpenLayoutPicker`, `UpdateProjectLayout`
+//! - **Mode Switching**: `SearchMode`, `ShowProjects`, `ShowSessions`, `ShowAll`
+//! - **Display**: `TogglePath`, `ToggleExpandPath`, `ToggleSearchScope`, `CycleSortMode`
+//! - **Sessions**: `KillSession`, `ToggleMark`
+//! - **Project Management**: `DeleteProject`, `TogglePin`, `ResetFrecency`,
+//!   `ResetAllFrecency`, `OpenInEditor`
 //! - **System**: `SessionUpdate`, `ProjectsScanned`, `PermissionsResult`
 //! - **Worker**: `WorkerResponse` with typed message variants
 //!
@@ -33,13 +42,21 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
-use crate::app::{Action, AppState};
+use crate::app::search_history::SearchHistory;
+use crate::app::session_name::{is_session_of, sanitize_session_name};
+use crate::app::{Action, AppState, LayoutPicker};
 use crate::domain::error::Result;
+use crate::domain::Project;
+use crate::infrastructure::clipboard::shell_quote;
+use crate::infrastructure::{layout, scanner, strip_host_prefix};
 use crate::worker::{WorkerMessage, WorkerResponse};
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use zellij_tile::prelude::PermissionType;
 
+/// Seconds a scan summary stays in the footer.
+const SCAN_SUMMARY_SECS: f64 = 3.0;
+
 /// Events triggered by user input, system changes, or worker responses.
 ///
 /// Each event represents a discrete occurrence that may cause state changes
@@ -51,12 +68,146 @@ pub enum Event {
     KeyDown,
     /// Moves selection cursor up by one position (wraps to bottom).
     KeyUp,
+    /// Moves selection cursor down by one page of visible rows.
+    PageDown,
+    /// Moves selection cursor up by one page of visible rows.
+    PageUp,
+    /// Selects the first visible project.
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// assert_eq!(handle_event(&mut state, &Event::SelectLast).unwrap(), (false, vec![]));
+    /// assert_eq!(state.selected_index, 0);
+    ///
+    /// state.projects = (0..5)
+    ///     .map(|i| Project::new(format!("/code/p{i}"), format!("p{i}")))
+    ///     .collect();
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// handle_event(&mut state, &Event::SelectLast).unwrap();
+    /// assert_eq!(state.selected_index, 4);
+    /// handle_event(&mut state, &Event::SelectFirst).unwrap();
+    /// assert_eq!(state.selected_index, 0);
+    /// ```
+    SelectFirst,
+    /// Selects the last visible project.
+    SelectLast,
     /// Closes the floating pane and hides the plugin UI.
     CloseFocus,
-    /// Selects the currently highlighted project (creates or switches session).
+    /// Selects the currently highlighted project.
+    ///
+    /// Switches or creates a session, or opens a tab, depending on the
+    /// configured Enter action for the current view mode. New sessions are
+    /// named with [`sanitize_session_name`]; the project keeps its name.
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use zessionizer::{handle_event, Action, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![Project::new("/code/my.project v2".to_string(), "my.project v2".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// let (_, actions) = handle_event(&mut state, &Event::SelectProject).unwrap();
+    /// assert_eq!(
+    ///     actions.last(),
+    ///     Some(&Action::CreateSession {
+    ///         name: "my-project-v2".to_string(),
+    ///         path: PathBuf::from("/code/my.project v2"),
+    ///         layout: None,
+    ///     })
+    /// );
+    /// assert_eq!(state.compute_viewmodel(24, 80).display_items[0].name, "my.project v2");
+    ///
+    /// // Once the session exists, the project switches to it.
+    /// state.active_sessions.insert("my-project-v2".to_string());
+    /// state.apply_search_filter();
+    /// let (_, actions) = handle_event(&mut state, &Event::SelectProject).unwrap();
+    /// assert!(matches!(actions.last(), Some(Action::SwitchSession { name, .. }) if name == "my-project-v2"));
+    /// ```
     SelectProject,
-    /// Kills the currently selected session (Sessions view only).
+    /// Opens the currently highlighted project in a new tab, regardless of the
+    /// configured Enter action.
+    ///
+    /// Mapped to `Ctrl+Enter`, next to `Enter`'s [`Event::SelectProject`].
+    OpenProjectInTab,
+    /// Kills the marked sessions, or the selected session if none are marked
+    /// (Sessions view only).
+    ///
+    /// Sessions above the `kill_confirm_panes` threshold are only marked for
+    /// confirmation; a second `KillSession` on the same session kills it. A
+    /// bulk kill asks once if any marked session needs confirmation.
     KillSession,
+    /// Marks or unmarks the selected session for a bulk kill (Sessions view
+    /// only). Marks are cleared once the marked sessions are killed.
+    ToggleMark,
+    /// Opens the layout picker for the selected project.
+    OpenLayoutPicker,
+    /// Stores the layout picked for a project and closes the picker.
+    UpdateProjectLayout {
+        /// Path of the project.
+        path: String,
+        /// Picked layout name, `None` to use Zellij's default layout.
+        layout: Option<String>,
+    },
+    /// Starts renaming the selected session (Sessions view only).
+    ///
+    /// Only the current session can be renamed; other selections show an
+    /// error in the footer.
+    StartRename,
+    /// Applies the typed session name, sanitized like new session names.
+    ///
+    /// Names already used by another active session are rejected with an
+    /// error in the footer, keeping the input open.
+    ConfirmRename,
+    /// Starts naming a new session for the selected project.
+    ///
+    /// The input is pre-filled with the project name. Projects that already
+    /// have an active session show an error in the footer instead.
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use zessionizer::{handle_event, Action, AppState, Event, InputMode, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![Project::new("/code/api".to_string(), "api".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::ProjectsWithoutSessions;
+    /// state.apply_search_filter();
+    ///
+    /// handle_event(&mut state, &Event::StartNewSession).unwrap();
+    /// assert_eq!(state.input_mode, InputMode::NewSession);
+    /// assert_eq!(state.rename_buffer, "api");
+    ///
+    /// for c in "-review".chars() {
+    ///     handle_event(&mut state, &Event::Char(c)).unwrap();
+    /// }
+    /// let (_, actions) = handle_event(&mut state, &Event::ConfirmNewSession).unwrap();
+    /// assert_eq!(
+    ///     actions.last(),
+    ///     Some(&Action::CreateSession {
+    ///         name: "api-review".to_string(),
+    ///         path: PathBuf::from("/code/api"),
+    ///         layout: None,
+    ///     })
+    /// );
+    /// assert_eq!(state.input_mode, InputMode::Normal);
+    ///
+    /// // An empty name falls back to the project name.
+    /// handle_event(&mut state, &Event::StartNewSession).unwrap();
+    /// state.rename_buffer.clear();
+    /// let (_, actions) = handle_event(&mut state, &Event::ConfirmNewSession).unwrap();
+    /// assert!(matches!(actions.last(), Some(Action::CreateSession { name, .. }) if name == "api"));
+    /// ```
+    StartNewSession,
+    /// Creates a session with the typed name for the project being named.
+    ///
+    /// An empty name falls back to the project name. Names of active sessions
+    /// are rejected with an error in the footer, keeping the input open.
+    ConfirmNewSession,
     /// Enters search mode with typing focus.
     SearchMode,
     /// Focuses the search input field (from navigating mode).
@@ -65,17 +216,331 @@ pub enum Event {
     FocusResults,
     /// Exits search mode and clears the query.
     ExitSearch,
-    /// Appends a character to the search query.
+    /// Inserts a character at the cursor in the search query, or appends it
+    /// to the session name.
     Char(char),
-    /// Removes the last character from the search query.
+    /// Removes the character before the cursor from the search query, or the
+    /// last character from the session name.
     Backspace,
-    /// Clears search query and returns to normal mode.
+    /// Deletes the word before the cursor from the search query, with the
+    /// whitespace after it.
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// handle_event(&mut state, &Event::SearchMode).unwrap();
+    /// state.set_search_query("api  web   ".to_string());
+    ///
+    /// handle_event(&mut state, &Event::DeleteWord).unwrap();
+    /// assert_eq!(state.search_query, "api  ");
+    /// handle_event(&mut state, &Event::DeleteWord).unwrap();
+    /// assert_eq!(state.search_query, "");
+    /// assert_eq!(handle_event(&mut state, &Event::DeleteWord).unwrap(), (false, vec![]));
+    ///
+    /// // Text after the cursor is kept.
+    /// state.set_search_query("api web cli".to_string());
+    /// for _ in 0..3 {
+    ///     handle_event(&mut state, &Event::CursorLeft).unwrap();
+    /// }
+    /// handle_event(&mut state, &Event::DeleteWord).unwrap();
+    /// assert_eq!(state.search_query, "api cli");
+    /// assert_eq!(state.search_cursor(), 4);
+    /// ```
+    DeleteWord,
+    /// Clears the whole search query.
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// handle_event(&mut state, &Event::SearchMode).unwrap();
+    /// state.set_search_query("api web".to_string());
+    /// assert_eq!(state.filtered_projects.len(), 0);
+    ///
+    /// handle_event(&mut state, &Event::ClearQuery).unwrap();
+    /// assert_eq!(state.search_query, "");
+    /// assert_eq!(state.search_cursor(), 0);
+    /// assert_eq!(state.filtered_projects.len(), 2);
+    /// ```
+    ClearQuery,
+    /// Moves the search query cursor one character left.
+    ///
+    /// Typing and `Backspace` then edit the middle of the query:
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// handle_event(&mut state, &Event::SearchMode).unwrap();
+    /// for c in "apiweb".chars() {
+    ///     handle_event(&mut state, &Event::Char(c)).unwrap();
+    /// }
+    /// for _ in 0..3 {
+    ///     handle_event(&mut state, &Event::CursorLeft).unwrap();
+    /// }
+    /// assert_eq!(state.search_cursor(), 3);
+    ///
+    /// handle_event(&mut state, &Event::Char(' ')).unwrap();
+    /// assert_eq!(state.search_query, "api web");
+    /// assert_eq!(state.search_cursor(), 4);
+    ///
+    /// handle_event(&mut state, &Event::CursorLeft).unwrap();
+    /// handle_event(&mut state, &Event::Backspace).unwrap();
+    /// assert_eq!(state.search_query, "ap web");
+    /// assert_eq!(state.search_cursor(), 2);
+    ///
+    /// // The cursor stops at either end of the query.
+    /// for _ in 0..3 {
+    ///     handle_event(&mut state, &Event::CursorLeft).unwrap();
+    /// }
+    /// assert_eq!(state.search_cursor(), 0);
+    /// assert_eq!(handle_event(&mut state, &Event::Backspace).unwrap().0, true);
+    /// assert_eq!(state.search_query, "ap web");
+    /// for _ in 0..10 {
+    ///     handle_event(&mut state, &Event::CursorRight).unwrap();
+    /// }
+    /// assert_eq!(state.search_cursor(), 6);
+    /// ```
+    CursorLeft,
+    /// Moves the search query cursor one character right.
+    ///
+    /// Works on characters, not bytes:
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// handle_event(&mut state, &Event::SearchMode).unwrap();
+    /// state.set_search_query("café".to_string());
+    /// handle_event(&mut state, &Event::CursorLeft).unwrap();
+    /// handle_event(&mut state, &Event::CursorLeft).unwrap();
+    /// handle_event(&mut state, &Event::CursorRight).unwrap();
+    /// handle_event(&mut state, &Event::Char('-')).unwrap();
+    /// assert_eq!(state.search_query, "caf-é");
+    /// handle_event(&mut state, &Event::CursorRight).unwrap();
+    /// handle_event(&mut state, &Event::Backspace).unwrap();
+    /// assert_eq!(state.search_query, "caf-");
+    /// ```
+    CursorRight,
+    /// Clears search query (or cancels renaming) and returns to normal mode,
+    /// dismissing any footer warning.
     Escape,
+    /// Replaces the search query with the next older query from history.
+    ///
+    /// Only applies while typing into an empty search box or browsing the
+    /// history (see [`AppState::browses_search_history`]). Queries enter the
+    /// history when a search leads to a project or the plugin closes.
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, InputMode, SearchFocus, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// state.search_history.push("api");
+    /// state.search_history.push("web");
+    /// state.input_mode = InputMode::Search(SearchFocus::Typing);
+    ///
+    /// handle_event(&mut state, &Event::RecallOlderSearch).unwrap();
+    /// assert_eq!(state.search_query, "web");
+    /// handle_event(&mut state, &Event::RecallOlderSearch).unwrap();
+    /// assert_eq!(state.search_query, "api");
+    /// handle_event(&mut state, &Event::RecallNewerSearch).unwrap();
+    /// assert_eq!(state.search_query, "web");
+    /// handle_event(&mut state, &Event::RecallNewerSearch).unwrap();
+    /// assert_eq!(state.search_query, "");
+    ///
+    /// // Typed queries are not replaced.
+    /// handle_event(&mut state, &Event::Char('x')).unwrap();
+    /// let (changed, _) = handle_event(&mut state, &Event::RecallOlderSearch).unwrap();
+    /// assert!(!changed);
+    /// assert_eq!(state.search_query, "x");
+    /// ```
+    RecallOlderSearch,
+    /// Replaces the search query with the next newer query from history,
+    /// clearing it once past the newest.
+    RecallNewerSearch,
 
     /// Switches view to show projects without active sessions.
     ShowProjects,
     /// Switches view to show projects with active sessions.
     ShowSessions,
+    /// Switches view to show every project regardless of session state.
+    ShowAll,
+    /// Shows or hides the PATH column.
+    ///
+    /// Without the PATH column, names use the freed width.
+    TogglePath,
+    /// Shows the selected project's full path instead of a truncated one.
+    ///
+    /// Only the selected row is expanded; the others stay truncated.
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let paths = ["/srv/projects/clients/acme/api", "/srv/projects/clients/acme/web"];
+    /// let projects = paths.map(|path| Project::new(path.to_string(), "acme".to_string())).to_vec();
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// let items = state.compute_viewmodel(24, 50).display_items;
+    /// assert!(items.iter().all(|item| item.path.starts_with("...") && !item.path_expanded));
+    ///
+    /// handle_event(&mut state, &Event::ToggleExpandPath).unwrap();
+    /// let items = state.compute_viewmodel(24, 50).display_items;
+    /// assert_eq!(items[0].path, paths[0]);
+    /// assert!(items[0].path_expanded);
+    /// assert!(items[1].path.starts_with("...") && !items[1].path_expanded);
+    ///
+    /// handle_event(&mut state, &Event::ToggleExpandPath).unwrap();
+    /// assert!(!state.compute_viewmodel(24, 50).display_items[0].path_expanded);
+    /// ```
+    ToggleExpandPath,
+    /// Cycles search matching between name, name or path, and path only.
+    ///
+    /// The query is re-applied, and match highlighting follows the scope too:
+    /// names and paths are only highlighted while the scope includes them.
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Project, SearchScope, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/work/backend/svc".to_string(), "svc".to_string()),
+    ///     Project::new("/home/notes".to_string(), "backend-notes".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query("work/back".to_string());
+    /// let names = |state: &AppState| -> Vec<String> {
+    ///     state.filtered_projects.iter().map(|p| p.name.clone()).collect()
+    /// };
+    /// assert_eq!(state.search_scope, SearchScope::Name);
+    /// assert!(names(&state).is_empty());
+    ///
+    /// for scope in [SearchScope::NameAndPath, SearchScope::Path] {
+    ///     handle_event(&mut state, &Event::ToggleSearchScope).unwrap();
+    ///     assert_eq!(state.search_scope, scope);
+    ///     assert_eq!(names(&state), ["svc"]);
+    /// }
+    ///
+    /// let item = &state.compute_viewmodel(24, 120).display_items[0];
+    /// assert!(item.highlight_ranges.is_empty());
+    /// assert!(!item.path_highlight_ranges.is_empty());
+    ///
+    /// handle_event(&mut state, &Event::ToggleSearchScope).unwrap();
+    /// assert_eq!(state.search_scope, SearchScope::Name);
+    /// assert!(names(&state).is_empty());
+    /// ```
+    ToggleSearchScope,
+    /// Switches to the next sort mode (frecency, alphabetical, recent, frequent).
+    CycleSortMode,
+    /// Opens the selected project's layout file for editing.
+    ///
+    /// The file is the project's picked layout, or `<name>.kdl`, in the Zellij
+    /// layout directory, so sessions created afterwards pick it up.
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use zessionizer::{handle_event, Action, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project { layout: Some("dev".to_string()), ..Project::new("/code/web".to_string(), "web".to_string()) },
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// state.select_by_name("api");
+    /// let (_, actions) = handle_event(&mut state, &Event::EditLayout).unwrap();
+    /// assert_eq!(actions, [Action::EditLayout {
+    ///     name: "api".to_string(),
+    ///     path: PathBuf::from(".config/zellij/layouts/api.kdl"),
+    /// }]);
+    ///
+    /// state.select_by_name("web");
+    /// let (_, actions) = handle_event(&mut state, &Event::EditLayout).unwrap();
+    /// assert_eq!(actions, [Action::EditLayout {
+    ///     name: "web".to_string(),
+    ///     path: PathBuf::from(".config/zellij/layouts/dev.kdl"),
+    /// }]);
+    /// ```
+    EditLayout,
+    /// Opens the selected project's directory in the editor.
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use zessionizer::{handle_event, Action, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// state.selected_index = 1;
+    ///
+    /// let (_, actions) = handle_event(&mut state, &Event::OpenInEditor).unwrap();
+    /// assert_eq!(actions, vec![Action::OpenInEditor { path: PathBuf::from("/code/web") }]);
+    ///
+    /// state.view_mode = ViewMode::Sessions;
+    /// state.apply_search_filter();
+    /// assert_eq!(handle_event(&mut state, &Event::OpenInEditor).unwrap(), (false, vec![]));
+    /// ```
+    OpenInEditor,
+    /// Copies the shell command that switches to the selected project.
+    CopySwitchCommand,
+    /// Removes the selected project from storage.
+    ///
+    /// The project reappears on the next scan if its directory still exists.
+    DeleteProject,
+    /// Pins or unpins the selected project.
+    ///
+    /// Pinned projects sort above all unpinned ones.
+    TogglePin,
+    /// Resets the selected project's frecency data, as if newly added.
+    ///
+    /// ```rust
+    /// use zessionizer::worker::WorkerMessage;
+    /// use zessionizer::{handle_event, Action, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![Project::new("/code/api".to_string(), "api".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// let (_, actions) = handle_event(&mut state, &Event::ResetFrecency).unwrap();
+    /// let [Action::PostToWorker(WorkerMessage::ResetFrecency { path, .. })] = actions.as_slice() else {
+    ///     panic!("expected a reset");
+    /// };
+    /// assert_eq!(path.as_deref(), Some("/code/api"));
+    /// assert_eq!(state.pending_selection.as_deref(), Some("/code/api"));
+    /// ```
+    ResetFrecency,
+    /// Resets every project's frecency data, if `allow_reset_all` is set.
+    ///
+    /// ```rust
+    /// use zessionizer::worker::WorkerMessage;
+    /// use zessionizer::{handle_event, Action, AppState, Event, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// assert_eq!(handle_event(&mut state, &Event::ResetAllFrecency).unwrap(), (true, vec![]));
+    /// assert!(state.footer_notice.is_some());
+    ///
+    /// state.allow_reset_all = true;
+    /// let (_, actions) = handle_event(&mut state, &Event::ResetAllFrecency).unwrap();
+    /// assert!(matches!(
+    ///     actions.as_slice(),
+    ///     [Action::PostToWorker(WorkerMessage::ResetFrecency { path: None, .. })]
+    /// ));
+    /// ```
+    ResetAllFrecency,
 
     /// Updates the set of active Zellij sessions.
     ///
@@ -86,14 +551,42 @@ pub enum Event {
         active_sessions: HashSet<String>,
         /// Name of the current session.
         current_session: Option<String>,
+        /// Number of terminal panes in each active session.
+        session_panes: HashMap<String, usize>,
+        /// Working directory of each session, where a pane title reveals it.
+        session_cwds: HashMap<String, PathBuf>,
     },
 
     /// Reports discovered project directories from filesystem scan.
     ///
     /// Triggered after scanning completes. Causes batch project addition
-    /// via worker if new directories are found.
+    /// via worker if new directories are found. Projects are deduplicated by
+    /// canonical path, so repositories sharing a name are kept apart.
+    ///
+    /// ```rust
+    /// use zessionizer::worker::WorkerMessage;
+    /// use zessionizer::{handle_event, Action, AppState, Event, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// let event = Event::ProjectsScanned {
+    ///     git_directories: vec![
+    ///         "/host/work/config/.git".to_string(),
+    ///         "/host/dotfiles/config/.git".to_string(),
+    ///         "/host/work/./config/.zessionizer".to_string(),
+    ///     ],
+    /// };
+    ///
+    /// let (_, actions) = handle_event(&mut state, &event).unwrap();
+    /// let [Action::PostToWorker(WorkerMessage::AddProjectsBatch { projects, .. })] = actions.as_slice() else {
+    ///     panic!("expected one batch, got {actions:?}");
+    /// };
+    /// assert_eq!(projects, &[
+    ///     ("/work/config".to_string(), "config".to_string()),
+    ///     ("/dotfiles/config".to_string(), "config".to_string()),
+    /// ]);
+    /// ```
     ProjectsScanned {
-        /// Paths to marker files (`.git` directories or `.zessionizer` files)
+        /// Paths to marker files (`.git` directories or files, or `.zessionizer` files)
         /// that identify project directories.
         git_directories: Vec<String>,
     },
@@ -119,6 +612,28 @@ pub enum Event {
     ///
     /// Processed by matching on the inner [`WorkerResponse`] variant. May
     /// cause project list updates, state changes, or error handling.
+    ///
+    /// A reloaded project list keeps the cursor on the previously selected
+    /// project, even if reordering moved it:
+    ///
+    /// ```rust
+    /// use zessionizer::worker::WorkerResponse;
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let api = Project::new("/code/api".to_string(), "api".to_string());
+    /// let web = Project::new("/code/web".to_string(), "web".to_string());
+    ///
+    /// let mut state = AppState::new(vec![api.clone(), web.clone()], Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query(String::new());
+    /// assert!(state.select_by_name("web"));
+    ///
+    /// let reordered = WorkerResponse::ProjectsLoaded { projects: vec![web, api] };
+    /// handle_event(&mut state, &Event::WorkerResponse(reordered))?;
+    /// assert_eq!(state.selected_index, 0);
+    /// assert_eq!(state.selected_project().unwrap().name, "web");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     WorkerResponse(WorkerResponse),
 }
 
@@ -163,23 +678,63 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
 
     match event {
         Event::KeyDown => {
+            if let Some(picker) = &mut state.layout_picker {
+                picker.move_down();
+                return Ok((true, vec![]));
+            }
             state.move_selection_down();
-            Ok((true, vec![]))
+            Ok((true, page_actions(state)))
         }
         Event::KeyUp => {
-            state.move_selection_up();
+            if let Some(picker) = &mut state.layout_picker {
+                picker.move_up();
+            } else {
+                state.move_selection_up();
+            }
+            Ok((true, vec![]))
+        }
+        Event::PageDown => {
+            if state.layout_picker.is_some() {
+                return Ok((true, vec![]));
+            }
+            state.page_down(state.page_size());
+            Ok((true, page_actions(state)))
+        }
+        Event::PageUp => {
+            if state.layout_picker.is_none() {
+                state.page_up(state.page_size());
+            }
+            Ok((true, vec![]))
+        }
+        Event::SelectFirst => {
+            if state.layout_picker.is_some() || state.filtered_projects.is_empty() {
+                return Ok((false, vec![]));
+            }
+            state.selected_index = 0;
             Ok((true, vec![]))
         }
-        Event::CloseFocus => Ok((false, vec![Action::CloseFocus])),
+        Event::SelectLast => {
+            if state.layout_picker.is_some() || state.filtered_projects.is_empty() {
+                return Ok((false, vec![]));
+            }
+            state.selected_index = state.filtered_projects.len() - 1;
+            Ok((true, page_actions(state)))
+        }
+        Event::CloseFocus => {
+            let mut actions = save_state_actions(state);
+            actions.push(Action::CloseFocus);
+            Ok((false, actions))
+        }
         Event::SelectProject => {
-            use super::modes::InputMode;
+            use super::modes::{EnterAction, InputMode};
 
-            let Some(project) = state.selected_project() else {
+            let Some(project) = state.selected_project().cloned() else {
                 tracing::debug!("no project selected");
                 if matches!(state.input_mode, InputMode::Search(_)) {
                     tracing::debug!("exiting search mode (no selection)");
                     state.input_mode = InputMode::Normal;
                     state.search_query = String::new();
+                    state.cursor_pos = 0;
                     state.apply_search_filter();
                     return Ok((true, vec![]));
                 }
@@ -189,33 +744,56 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
             tracing::debug!(
                 project_name = %project.name,
                 project_path = %project.path,
-                has_active_session = state.active_sessions.contains(&project.name),
+                has_active_session = state.has_session(&project),
                 "project selected"
             );
 
-            let mut actions = vec![];
+            let mut actions = save_state_actions(state);
 
-            if state.active_sessions.contains(&project.name) {
-                tracing::debug!(session_name = %project.name, "switching to existing session");
-                actions.push(Action::SwitchSession {
+            if state.enter_actions.for_view(state.view_mode) == EnterAction::Tab {
+                tracing::debug!(tab_name = %project.name, "opening project in new tab");
+                actions.push(Action::OpenTab {
                     name: project.name.clone(),
                     path: PathBuf::from(&project.path),
                 });
+            } else if let Some(session) = state.active_session(&project) {
+                tracing::debug!(session_name = %session, "switching to existing session");
+                actions.push(Action::SwitchSession {
+                    name: session.to_string(),
+                    path: PathBuf::from(&project.path),
+                    layout: session_layout(&project),
+                });
             } else {
-                tracing::debug!(session_name = %project.name, "creating new session");
+                let name = sanitize_session_name(&project.name);
+                tracing::debug!(session_name = %name, "creating new session");
                 actions.push(Action::CreateSession {
-                    name: project.name.clone(),
+                    name,
                     path: PathBuf::from(&project.path),
+                    layout: session_layout(&project),
                 });
             }
 
             Ok((false, actions))
         }
+        Event::OpenProjectInTab => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to open in tab");
+                return Ok((false, vec![]));
+            };
+
+            let (name, path) = (project.name.clone(), PathBuf::from(&project.path));
+            tracing::debug!(tab_name = %name, "opening project in new tab");
+
+            let mut actions = save_state_actions(state);
+            actions.push(Action::OpenTab { name, path });
+            Ok((false, actions))
+        }
         Event::SearchMode => {
             use super::modes::{InputMode, SearchFocus};
             tracing::debug!("entering search mode");
             state.input_mode = InputMode::Search(SearchFocus::Typing);
             state.search_query = String::new();
+            state.cursor_pos = 0;
             Ok((true, vec![]))
         }
         Event::FocusSearchBar => {
@@ -240,41 +818,128 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
             tracing::debug!(query = %state.search_query, "exiting search mode");
             state.input_mode = InputMode::Normal;
             state.search_query = String::new();
+            state.cursor_pos = 0;
             state.apply_search_filter();
             Ok((true, vec![]))
         }
         Event::Char(c) => {
             use super::modes::InputMode;
 
+            if state.input_mode.edits_session_name() {
+                state.rename_buffer.push(*c);
+                return Ok((true, vec![]));
+            }
+
             if !matches!(state.input_mode, InputMode::Search(_)) {
                 return Ok((false, vec![]));
             }
 
-            state.search_query.push(*c);
+            state.insert_at_cursor(*c);
+            state.search_history.stop_browsing();
 
             tracing::trace!(query = %state.search_query, char = %c, "search query updated");
 
             state.apply_search_filter();
 
-            Ok((true, vec![]))
+            Ok((true, page_actions(state)))
         }
         Event::Backspace => {
             use super::modes::InputMode;
+
+            if state.input_mode.edits_session_name() {
+                state.rename_buffer.pop();
+                return Ok((true, vec![]));
+            }
+
+            if !matches!(state.input_mode, InputMode::Search(_)) {
+                return Ok((false, vec![]));
+            }
+
+            state.delete_before_cursor();
+            state.search_history.stop_browsing();
+
+            state.apply_search_filter();
+
+            Ok((true, vec![]))
+        }
+        Event::DeleteWord | Event::ClearQuery => {
+            use super::modes::InputMode;
+
             if !matches!(state.input_mode, InputMode::Search(_)) {
                 return Ok((false, vec![]));
             }
 
-            state.search_query.pop();
+            let changed = if matches!(event, Event::DeleteWord) {
+                state.delete_word_before_cursor()
+            } else {
+                let had_query = !state.search_query.is_empty();
+                state.search_query.clear();
+                state.cursor_pos = 0;
+                had_query
+            };
+            if !changed {
+                return Ok((false, vec![]));
+            }
 
+            tracing::trace!(query = %state.search_query, "search query cut");
+            state.search_history.stop_browsing();
             state.apply_search_filter();
 
             Ok((true, vec![]))
         }
+        Event::CursorLeft | Event::CursorRight => {
+            use super::modes::InputMode;
+
+            if !matches!(state.input_mode, InputMode::Search(_)) {
+                return Ok((false, vec![]));
+            }
+
+            let cursor = state.search_cursor();
+            state.cursor_pos = if matches!(event, Event::CursorLeft) {
+                cursor.saturating_sub(1)
+            } else {
+                (cursor + 1).min(state.search_query.chars().count())
+            };
+            Ok((state.cursor_pos != cursor, vec![]))
+        }
+        Event::RecallOlderSearch | Event::RecallNewerSearch => {
+            if !state.browses_search_history() {
+                return Ok((false, vec![]));
+            }
+
+            let recalled = if matches!(event, Event::RecallOlderSearch) {
+                state.search_history.older()
+            } else {
+                state.search_history.newer()
+            };
+            let Some(query) = recalled.map(String::from) else {
+                return Ok((false, vec![]));
+            };
+
+            tracing::trace!(query = %query, "search query recalled");
+            state.set_search_query(query);
+            Ok((true, page_actions(state)))
+        }
         Event::Escape => {
             use super::modes::InputMode;
+
+            if state.layout_picker.take().is_some() {
+                tracing::debug!("layout picker closed");
+                return Ok((true, vec![]));
+            }
+
+            if state.input_mode.edits_session_name() {
+                tracing::debug!("session name input cancelled");
+                state.cancel_rename();
+                return Ok((true, vec![]));
+            }
+
             state.input_mode = InputMode::Normal;
+            state.marked_sessions.clear();
+            state.footer_message = None;
 
             state.search_query = String::new();
+            state.cursor_pos = 0;
 
             state.apply_search_filter();
 
@@ -292,6 +957,258 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
             state.apply_search_filter();
             Ok((true, vec![]))
         }
+        Event::ShowAll => {
+            use super::modes::ViewMode;
+            state.view_mode = ViewMode::All;
+            state.apply_search_filter();
+            Ok((true, vec![]))
+        }
+        Event::TogglePath => {
+            state.show_path = !state.show_path;
+            tracing::debug!(show_path = state.show_path, "toggled path column");
+            Ok((true, vec![]))
+        }
+        Event::ToggleExpandPath => {
+            state.expand_selected_path = !state.expand_selected_path;
+            tracing::debug!(expand_selected_path = state.expand_selected_path, "toggled path expansion");
+            Ok((true, vec![]))
+        }
+        Event::ToggleSearchScope => {
+            state.search_scope = state.search_scope.toggled();
+            tracing::debug!(search_scope = ?state.search_scope, "toggled search scope");
+            state.apply_search_filter();
+            Ok((true, vec![]))
+        }
+        Event::CycleSortMode => {
+            state.sort_mode = state.sort_mode.next();
+            tracing::debug!(sort_mode = ?state.sort_mode, "cycled sort mode");
+            state.apply_search_filter();
+            Ok((true, vec![]))
+        }
+        Event::EditLayout => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to edit layout");
+                return Ok((false, vec![]));
+            };
+
+            let path = layout::find_layout_for_project(
+                Path::new(layout::LAYOUTS_SUBDIR),
+                &project.name,
+                project.layout.as_deref(),
+            );
+            tracing::debug!(layout_path = ?path, "editing project layout");
+            Ok((false, vec![Action::EditLayout {
+                name: project.name.clone(),
+                path,
+            }]))
+        }
+        Event::OpenInEditor => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to open in editor");
+                return Ok((false, vec![]));
+            };
+
+            tracing::debug!(project_path = %project.path, "opening project in editor");
+            Ok((false, vec![Action::OpenInEditor {
+                path: PathBuf::from(&project.path),
+            }]))
+        }
+        Event::CopySwitchCommand => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to copy command for");
+                return Ok((false, vec![]));
+            };
+
+            let text = switch_command(project, state.active_session(project));
+            tracing::debug!(command = %text, "copying switch command");
+            Ok((false, vec![Action::CopyToClipboard { text }]))
+        }
+        Event::DeleteProject => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to delete");
+                return Ok((false, vec![]));
+            };
+
+            tracing::debug!(project_path = %project.path, "deleting project");
+            Ok((false, vec![Action::PostToWorker(WorkerMessage::delete_project(project.path.clone()))]))
+        }
+        Event::TogglePin => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to pin");
+                return Ok((false, vec![]));
+            };
+
+            let path = project.path.clone();
+            tracing::debug!(project_path = %path, pinned = !project.pinned, "toggling pin");
+
+            // Keep the cursor on the project once the re-sorted list arrives
+            state.pending_selection = Some(path.clone());
+            Ok((false, vec![Action::PostToWorker(WorkerMessage::toggle_pin(path))]))
+        }
+        Event::ResetFrecency => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to reset");
+                return Ok((false, vec![]));
+            };
+
+            let path = project.path.clone();
+            tracing::debug!(project_path = %path, "resetting frecency");
+
+            // Keep the cursor on the project once the re-sorted list arrives
+            state.pending_selection = Some(path.clone());
+            Ok((false, vec![Action::PostToWorker(WorkerMessage::reset_frecency(Some(path)))]))
+        }
+        Event::ResetAllFrecency => {
+            if !state.allow_reset_all {
+                tracing::debug!("reset all frecency disabled");
+                state.footer_notice = Some("Set allow_reset_all to reset all frecency".to_string());
+                return Ok((true, vec![]));
+            }
+
+            tracing::debug!("resetting frecency of all projects");
+            Ok((false, vec![Action::PostToWorker(WorkerMessage::reset_frecency(None))]))
+        }
+        Event::OpenLayoutPicker => {
+            let Some(project) = state.selected_project() else {
+                tracing::debug!("no project selected to pick a layout for");
+                return Ok((false, vec![]));
+            };
+
+            let layouts = layout::list_layouts(&layout::layouts_dir());
+            tracing::debug!(project_path = %project.path, layout_count = layouts.len(), "opening layout picker");
+
+            state.layout_picker = Some(LayoutPicker::new(
+                project.path.clone(),
+                project.name.clone(),
+                layouts,
+                project.layout.as_deref(),
+            ));
+            Ok((true, vec![]))
+        }
+        Event::UpdateProjectLayout { path, layout } => {
+            state.layout_picker = None;
+
+            let Some(project) = state.projects.iter_mut().find(|project| &project.path == path) else {
+                tracing::debug!(project_path = %path, "layout picked for unknown project");
+                return Ok((true, vec![]));
+            };
+
+            tracing::debug!(project_path = %path, layout = ?layout, "project layout picked");
+            project.layout.clone_from(layout);
+            state.apply_search_filter();
+
+            Ok((true, vec![Action::PostToWorker(WorkerMessage::update_project_layout(
+                path.clone(),
+                layout.clone(),
+            ))]))
+        }
+        Event::StartRename => {
+            use super::modes::ViewMode;
+
+            if state.view_mode != ViewMode::Sessions {
+                return Ok((false, vec![]));
+            }
+
+            let Some(name) = state
+                .selected_project()
+                .and_then(|project| state.active_session(project))
+                .map(str::to_string)
+            else {
+                tracing::debug!("no session selected to rename");
+                return Ok((false, vec![]));
+            };
+
+            if state.current_session.as_deref() != Some(name.as_str()) {
+                tracing::debug!(session_name = %name, "only the current session can be renamed");
+                state.footer_notice = Some("Only the current session can be renamed".to_string());
+                return Ok((true, vec![]));
+            }
+
+            tracing::debug!(session_name = %name, "renaming session");
+            state.start_rename(&name);
+            Ok((true, vec![]))
+        }
+        Event::ConfirmRename => {
+            let Some(old_name) = state.rename_target.clone() else {
+                return Ok((false, vec![]));
+            };
+            let typed = state.rename_buffer.trim();
+            let new_name = sanitize_session_name(typed);
+
+            if typed.is_empty() || new_name == old_name {
+                tracing::debug!("session name unchanged, cancelling rename");
+                state.cancel_rename();
+                return Ok((true, vec![]));
+            }
+
+            if state.active_sessions.contains(&new_name) {
+                tracing::debug!(new_name = %new_name, "rename rejected, session exists");
+                state.footer_notice = Some(format!("Session '{new_name}' already exists"));
+                return Ok((true, vec![]));
+            }
+
+            let path = state
+                .projects
+                .iter()
+                .find(|project| is_session_of(&old_name, &project.name))
+                .map(|project| project.path.clone());
+            state.apply_session_rename(&old_name, &new_name);
+
+            let mut actions = vec![Action::RenameSession {
+                old_name,
+                new_name: new_name.clone(),
+            }];
+            if let Some(path) = path {
+                actions.push(Action::PostToWorker(WorkerMessage::rename_project(path, new_name)));
+            }
+            Ok((true, actions))
+        }
+        Event::StartNewSession => {
+            let Some(project) = state.selected_project().cloned() else {
+                tracing::debug!("no project selected to name a session for");
+                return Ok((false, vec![]));
+            };
+
+            if state.has_session(&project) {
+                tracing::debug!(session_name = %project.name, "project already has a session");
+                state.footer_notice = Some(format!("'{}' already has a session", project.name));
+                return Ok((true, vec![]));
+            }
+
+            tracing::debug!(project_path = %project.path, "naming new session");
+            state.start_new_session(&project);
+            Ok((true, vec![]))
+        }
+        Event::ConfirmNewSession => {
+            let Some(project) = state
+                .new_session_path
+                .as_ref()
+                .and_then(|path| state.projects.iter().find(|project| &project.path == path))
+                .cloned()
+            else {
+                state.cancel_rename();
+                return Ok((true, vec![]));
+            };
+
+            let typed = state.rename_buffer.trim();
+            let name = sanitize_session_name(if typed.is_empty() { &project.name } else { typed });
+
+            if state.active_sessions.contains(&name) {
+                tracing::debug!(session_name = %name, "new session rejected, session exists");
+                state.footer_notice = Some(format!("Session '{name}' already exists"));
+                return Ok((true, vec![]));
+            }
+
+            tracing::debug!(session_name = %name, project_path = %project.path, "creating named session");
+            state.cancel_rename();
+            let mut actions = save_state_actions(state);
+            actions.push(Action::CreateSession {
+                name,
+                layout: session_layout(&project),
+                path: PathBuf::from(project.path),
+            });
+            Ok((false, actions))
+        }
         Event::KillSession => {
             use super::modes::ViewMode;
 
@@ -299,19 +1216,63 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
                 return Ok((false, vec![]));
             }
 
-            state.selected_project().map_or_else(|| {
+            let active_sessions = &state.active_sessions;
+            state.marked_sessions.retain(|name| active_sessions.contains(name));
+
+            if !state.marked_sessions.is_empty() {
+                let names = state.kill_targets();
+                if state.pending_kill.is_none() {
+                    if let Some(name) = names.iter().find(|name| state.needs_kill_confirmation(name)) {
+                        tracing::debug!(sessions = names.len(), "bulk kill requires confirmation");
+                        state.pending_kill = Some(name.clone());
+                        return Ok((true, vec![]));
+                    }
+                }
+
+                tracing::debug!(sessions = ?names, "killing marked sessions");
+                state.cancel_pending_kill();
+                state.marked_sessions.clear();
+                return Ok((true, vec![Action::KillSessions { names }]));
+            }
+
+            let Some(name) = state.selected_session().map(str::to_string) else {
                 tracing::debug!("no session selected to kill");
-                Ok((false, vec![]))
-            }, |project| {
-                tracing::debug!(session_name = %project.name, "killing session");
-                Ok((false, vec![Action::KillSession {
-                    name: project.name.clone()
-                }]))
-            })
-        }
-        Event::SessionUpdate { active_sessions, current_session } => {
+                return Ok((false, vec![]));
+            };
+
+            if state.needs_kill_confirmation(&name) {
+                tracing::debug!(session_name = %name, "kill requires confirmation");
+                state.pending_kill = Some(name);
+                return Ok((true, vec![]));
+            }
+
+            tracing::debug!(session_name = %name, "killing session");
+            let confirmed = state.cancel_pending_kill();
+            Ok((confirmed, vec![Action::KillSession { name }]))
+        }
+        Event::ToggleMark => {
+            use super::modes::ViewMode;
+
+            if state.view_mode != ViewMode::Sessions {
+                return Ok((false, vec![]));
+            }
+
+            let Some(name) = state.selected_session().map(str::to_string) else {
+                tracing::debug!("no session selected to mark");
+                return Ok((false, vec![]));
+            };
+
+            if !state.marked_sessions.remove(&name) {
+                state.marked_sessions.insert(name);
+            }
+            Ok((true, vec![]))
+        }
+        Event::SessionUpdate { active_sessions, current_session, session_panes, session_cwds } => {
             let mut actions = vec![];
 
+            state.sessions_received = true;
+            state.session_panes.clone_from(session_panes);
+
             let added_count = active_sessions.difference(&state.active_sessions).count();
             let removed_count = state.active_sessions.difference(active_sessions).count();
             let current_changed = &state.current_session != current_session;
@@ -328,13 +1289,19 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
             if added_count > 0 || removed_count > 0 || current_changed {
                 state.active_sessions.clone_from(active_sessions);
                 state.current_session.clone_from(current_session);
+                state.marked_sessions.retain(|name| active_sessions.contains(name));
 
                 let session_names: Vec<String> = active_sessions.iter().cloned().collect();
+                let session_cwds = session_cwds
+                    .iter()
+                    .map(|(name, cwd)| (name.clone(), cwd.to_string_lossy().into_owned()))
+                    .collect();
                 actions.push(Action::PostToWorker(
-                    WorkerMessage::sync_sessions(session_names)
+                    WorkerMessage::sync_sessions(session_names, session_cwds)
                 ));
 
                 state.apply_search_filter();
+                state.restore_selection();
                 Ok((true, actions))
             } else {
                 tracing::debug!("sessions unchanged, skipping sync and render");
@@ -347,31 +1314,14 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
                 "projects scan completed"
             );
 
-            // Extract project directories by stripping marker suffixes
-            // (/.git or /.zessionizer) from the paths returned by find
-            let projects: Vec<(String, String)> = git_directories
-                .iter()
-                .map(|marker_path| {
-                    let without_host = marker_path.strip_prefix("/host").unwrap_or(marker_path);
-                    let project_path = without_host
-                        .strip_suffix("/.git")
-                        .or_else(|| without_host.strip_suffix("/.zessionizer"))
-                        .unwrap_or(without_host);
-
-                    let project_name = project_path
-                        .split('/')
-                        .next_back()
-                        .unwrap_or("unknown");
-
-                    tracing::debug!(
-                        project_name = %project_name,
-                        project_path = %project_path,
-                        "discovered project"
-                    );
-
-                    (project_path.to_string(), project_name.to_string())
-                })
-                .collect();
+            let mut projects = scanner::projects_from_scan(git_directories, &scanner::PROJECT_MARKERS);
+            projects.retain(|(path, _name)| {
+                let excluded = scanner::is_excluded(path, &state.path_exclude);
+                if excluded {
+                    tracing::debug!(project_path = %path, "excluding project by path");
+                }
+                !excluded
+            });
 
             let mut actions = vec![];
 
@@ -400,10 +1350,16 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
                         Ok((false, vec![]))
                     } else {
                         let old_filtered = state.filtered_projects.clone();
+                        if state.pending_selection.is_none() {
+                            state.pending_selection = state.selected_project().map(|p| p.path.clone());
+                        }
                         state.projects.clone_from(projects);
+                        state.projects_total = None;
+                        state.page_pending = false;
                         state.apply_search_filter();
+                        let restored = state.restore_selection();
 
-                        if state.filtered_projects == old_filtered {
+                        if state.filtered_projects == old_filtered && !restored {
                             tracing::debug!("filtered projects unchanged after reload, skipping render");
                             Ok((false, vec![]))
                         } else {
@@ -411,9 +1367,46 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
                         }
                     }
                 }
-                WorkerResponse::FrecencyUpdated { path: _ } | WorkerResponse::SessionsSynced { count: _ } => {
+                WorkerResponse::ProjectsPage { offset, total, projects } => {
+                    let changed = state.apply_projects_page(*offset, *total, projects);
+                    Ok((changed, page_actions(state)))
+                }
+                WorkerResponse::ProjectsHash { hash, changed } => {
+                    if state.needs_projects(*hash) {
+                        tracing::debug!(changed = changed, "project hash differs, fetching full list");
+                        Ok((false, vec![Action::PostToWorker(WorkerMessage::fetch_projects())]))
+                    } else {
+                        tracing::debug!(changed = changed, "project hash matches, skipping fetch");
+                        Ok((false, vec![]))
+                    }
+                }
+                WorkerResponse::Configured
+                | WorkerResponse::FrecencyUpdated { path: _ }
+                | WorkerResponse::ProjectRenamed { path: _, name: _ }
+                | WorkerResponse::ProjectLayoutUpdated { path: _, layout: _ }
+                | WorkerResponse::SessionsSynced { count: _ }
+                | WorkerResponse::SelectionSaved { path: _ }
+                | WorkerResponse::UiStateSaved => {
                     Ok((false, vec![]))
                 }
+                WorkerResponse::ProjectsExported { json } => {
+                    tracing::debug!(bytes = json.len(), "projects exported");
+                    Ok((false, vec![]))
+                }
+                WorkerResponse::ProjectDeleted { path } => {
+                    Ok((state.remove_project(path), vec![]))
+                }
+                WorkerResponse::SessionsLoaded { names } => {
+                    Ok((state.seed_sessions(names), vec![]))
+                }
+                WorkerResponse::SelectionLoaded { path } => {
+                    state.pending_selection.clone_from(path);
+                    Ok((state.restore_selection(), vec![]))
+                }
+                WorkerResponse::UiStateLoaded { view_mode, query, search_history } => {
+                    state.search_history = SearchHistory::from_entries(search_history.iter().cloned());
+                    Ok((restore_ui_state(state, view_mode.as_deref(), query), vec![]))
+                }
                 WorkerResponse::ProjectsBatchAdded { count, projects } => {
                     tracing::debug!(count = count, "projects batch added successfully");
                     if &state.projects == projects {
@@ -422,9 +1415,12 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
                     } else {
                         let old_filtered = state.filtered_projects.clone();
                         state.projects.clone_from(projects);
+                        state.projects_total = None;
+                        state.page_pending = false;
                         state.apply_search_filter();
+                        let restored = state.restore_selection();
 
-                        if state.filtered_projects == old_filtered {
+                        if state.filtered_projects == old_filtered && !restored {
                             tracing::debug!("filtered projects unchanged after batch add, skipping render");
                             Ok((false, vec![]))
                         } else {
@@ -432,6 +1428,13 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
                         }
                     }
                 }
+                WorkerResponse::ScanSummary { discovered, added, updated } => {
+                    tracing::debug!(discovered = discovered, added = added, updated = updated, "scan stored");
+                    state.footer_notice = Some(format!(
+                        "Scanned {discovered} projects: {added} new, {updated} updated"
+                    ));
+                    Ok((true, vec![Action::ExpireFooterNotice { secs: SCAN_SUMMARY_SECS }]))
+                }
                 WorkerResponse::Error { message } => {
                     tracing::error!("Worker error: {}", message);
                     Ok((true, vec![]))
@@ -440,3 +1443,84 @@ pub fn handle_event(state: &mut AppState, event: &Event) -> Result<(bool, Vec<Ac
         }
     }
 }
+
+/// Returns the action loading the next page of projects, if more are needed
+/// (see [`AppState::next_page_request`]).
+fn page_actions(state: &mut AppState) -> Vec<Action> {
+    state.next_page_request().map(Action::PostToWorker).into_iter().collect()
+}
+
+/// Records the search query in the history and returns the worker messages
+/// persisting the view state and, if enabled, the current selection.
+///
+/// The selection is skipped when `remember_selection` is off or nothing is
+/// selected.
+fn save_state_actions(state: &mut AppState) -> Vec<Action> {
+    state.search_history.push(&state.search_query);
+
+    let mut actions = vec![Action::PostToWorker(WorkerMessage::save_ui_state(
+        state.view_mode.name().to_string(),
+        state.search_query.clone(),
+        state.search_history.entries().map(String::from).collect(),
+    ))];
+
+    if state.remember_selection {
+        actions.extend(
+            state
+                .selected_project()
+                .map(|project| Action::PostToWorker(WorkerMessage::save_selection(project.path.clone()))),
+        );
+    }
+
+    actions
+}
+
+/// Restores the view mode and search query saved when the plugin was last
+/// closed.
+///
+/// A saved query reopens search mode so it stays visible and editable.
+/// Unknown view mode names keep the current view.
+///
+/// # Returns
+///
+/// `true` if the view changed and needs re-rendering.
+fn restore_ui_state(state: &mut AppState, view_mode: Option<&str>, query: &str) -> bool {
+    use super::modes::{InputMode, SearchFocus, ViewMode};
+
+    let view_mode = view_mode.and_then(ViewMode::parse).unwrap_or(state.view_mode);
+    if view_mode == state.view_mode && query.is_empty() {
+        return false;
+    }
+
+    tracing::debug!(view_mode = ?view_mode, query = %query, "restoring UI state");
+    state.view_mode = view_mode;
+    if !query.is_empty() {
+        state.input_mode = InputMode::Search(SearchFocus::Typing);
+        state.search_query = query.to_string();
+        state.cursor_pos = query.chars().count();
+    }
+    state.apply_search_filter();
+    state.restore_selection();
+    true
+}
+
+/// Builds the CLI command equivalent to selecting a project.
+///
+/// Active sessions are attached directly; inactive projects `cd` into the
+/// host path first and create the session under its sanitized name.
+fn switch_command(project: &Project, session: Option<&str>) -> String {
+    session.map_or_else(
+        || {
+            let name = shell_quote(&sanitize_session_name(&project.name));
+            let path = shell_quote(&strip_host_prefix(&project.path));
+            format!("cd {path} && zellij attach --create {name}")
+        },
+        |session| format!("zellij attach {}", shell_quote(session)),
+    )
+}
+
+/// Returns the layout to create `project`'s session with: the picked one, or
+/// `<name>.kdl` if it exists in the Zellij layout directory.
+fn session_layout(project: &Project) -> Option<String> {
+    layout::discover_layout(&layout::layouts_dir(), &project.name, project.layout.as_deref())
+}
@@ -0,0 +1,165 @@
+//! Idle timeout bookkeeping for auto-closing the plugin.
+//!
+//! Zellij timers cannot be cancelled, so every key press schedules a fresh
+//! timeout and the timer that fires decides whether it is still the latest
+//! one. [`IdleTimer`] tracks this with a generation counter, keeping the
+//! decision free of Zellij calls so it can be reasoned about in isolation.
+//!
+//! Other timers (e.g. footer notices) share Zellij's `Timer` event, which does
+//! not say which `set_timeout` it belongs to. Every timeout is therefore
+//! scheduled through [`IdleTimer`], which keeps them in deadline order and
+//! routes each fired `Timer` to the earliest one, whatever the lengths of the
+//! idle window and the notices.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::app::idle::{Expired, IdleTimer};
+//!
+//! let mut timer = IdleTimer::new(30);
+//! assert_eq!(timer.record_activity(0.0), Some(30.0));
+//! assert_eq!(timer.record_activity(5.0), Some(30.0));
+//! assert_eq!(timer.on_timer(), None); // superseded by the second key press
+//! assert_eq!(timer.on_timer(), Some(Expired::Idle)); // idle since, so close
+//! ```
+
+use std::collections::VecDeque;
+
+/// What a fired timer means for the plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expired {
+    /// No activity was recorded for the whole idle window; the plugin should
+    /// close.
+    Idle,
+
+    /// The latest footer notice has been shown for its full duration.
+    Notice,
+}
+
+/// Owner of a scheduled timeout, tagged with the generation it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Owner {
+    Idle(u64),
+    Notice(u64),
+}
+
+/// Tracks outstanding timeouts and decides when one has expired.
+#[derive(Debug, Clone, Default)]
+pub struct IdleTimer {
+    /// Timeout window in seconds. `0` disables the idle timeout.
+    timeout_secs: u32,
+
+    /// Incremented on every recorded activity.
+    generation: u64,
+
+    /// Incremented on every scheduled footer notice.
+    notice_generation: u64,
+
+    /// Scheduled timeouts with their deadlines, in firing order.
+    pending: VecDeque<(f64, Owner)>,
+}
+
+impl IdleTimer {
+    /// Creates an idle timer with the given window. `0` disables it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::app::idle::IdleTimer;
+    ///
+    /// let mut timer = IdleTimer::new(0);
+    /// assert!(!timer.is_enabled());
+    /// assert_eq!(timer.record_activity(0.0), None);
+    /// assert_eq!(timer.on_timer(), None);
+    /// ```
+    #[must_use]
+    pub const fn new(timeout_secs: u32) -> Self {
+        Self {
+            timeout_secs,
+            generation: 0,
+            notice_generation: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Returns whether the idle timeout is enabled.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.timeout_secs > 0
+    }
+
+    /// Records user activity at `now` (in seconds), superseding all
+    /// outstanding idle timeouts.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(secs)` - a timeout of `secs` seconds must be scheduled
+    /// - `None` if the idle timeout is disabled
+    pub fn record_activity(&mut self, now: f64) -> Option<f64> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let secs = f64::from(self.timeout_secs);
+        self.generation = self.generation.wrapping_add(1);
+        self.schedule(now + secs, Owner::Idle(self.generation));
+        Some(secs)
+    }
+
+    /// Records a footer notice shown at `now` for `secs` seconds, replacing
+    /// any earlier notice.
+    ///
+    /// # Returns
+    ///
+    /// The timeout to schedule, in seconds.
+    pub fn schedule_notice(&mut self, now: f64, secs: f64) -> f64 {
+        self.notice_generation = self.notice_generation.wrapping_add(1);
+        self.schedule(now + secs, Owner::Notice(self.notice_generation));
+        secs
+    }
+
+    /// Handles a fired timeout.
+    ///
+    /// Zellij fires timeouts in deadline order, so the fired one is the
+    /// earliest pending timeout. Idle timeouts superseded by later activity
+    /// and notices replaced by later ones are ignored.
+    ///
+    /// # Returns
+    ///
+    /// What expired, or `None` if the timeout is stale.
+    ///
+    /// # Example
+    ///
+    /// A 3-second notice firing while a 2-second idle window is pending does
+    /// not close the plugin:
+    ///
+    /// ```rust
+    /// use zessionizer::app::idle::{Expired, IdleTimer};
+    ///
+    /// let mut timer = IdleTimer::new(2);
+    /// assert_eq!(timer.schedule_notice(0.0, 3.0), 3.0);
+    /// timer.record_activity(1.5);
+    ///
+    /// assert_eq!(timer.on_timer(), Some(Expired::Notice)); // at 3.0
+    /// assert_eq!(timer.on_timer(), Some(Expired::Idle)); // at 3.5
+    /// assert_eq!(timer.on_timer(), None);
+    ///
+    /// // A replaced notice does not clear its successor early.
+    /// timer.schedule_notice(10.0, 3.0);
+    /// timer.schedule_notice(11.0, 3.0);
+    /// assert_eq!(timer.on_timer(), None);
+    /// assert_eq!(timer.on_timer(), Some(Expired::Notice));
+    /// ```
+    pub fn on_timer(&mut self) -> Option<Expired> {
+        match self.pending.pop_front()?.1 {
+            Owner::Idle(generation) => (generation == self.generation).then_some(Expired::Idle),
+            Owner::Notice(generation) => (generation == self.notice_generation).then_some(Expired::Notice),
+        }
+    }
+
+    /// Queues a timeout after those with the same or earlier deadlines.
+    fn schedule(&mut self, deadline: f64, owner: Owner) {
+        let index = self.pending.partition_point(|&(pending, _)| pending <= deadline);
+        self.pending.insert(index, (deadline, owner));
+    }
+}
@@ -0,0 +1,150 @@
+//! Remappable single-key commands.
+//!
+//! Normal mode commands bound to a character key can be remapped with
+//! `key_<command>` configuration options (e.g. `key_down "e"`), or disabled
+//! with `"none"`. Arrow keys, Enter, Esc, Tab, and Ctrl combinations are fixed.
+//!
+//! A key bound to one command is taken away from any other command that used
+//! it, so remapping `key_down` to `s` unbinds the Sessions view key.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::app::keys::KeyBindings;
+//! use zessionizer::Event;
+//!
+//! let mut bindings = KeyBindings::default();
+//! assert_eq!(bindings.event_for('j'), Some(Event::KeyDown));
+//!
+//! assert!(bindings.set("down", Some('e')));
+//! assert_eq!(bindings.event_for('e'), Some(Event::KeyDown));
+//! assert_eq!(bindings.event_for('j'), None);
+//!
+//! assert!(bindings.set("quit", None));
+//! assert_eq!(bindings.event_for('q'), None);
+//! assert!(!bindings.set("fly", Some('f')));
+//! ```
+
+use super::handler::Event;
+
+/// Remappable commands: name (the option is `key_<name>`), default key, and
+/// the event sent.
+const COMMANDS: [(&str, char, Event); 24] = [
+    ("down", 'j', Event::KeyDown),
+    ("up", 'k', Event::KeyUp),
+    ("first", 'g', Event::SelectFirst),
+    ("last", 'G', Event::SelectLast),
+    ("quit", 'q', Event::CloseFocus),
+    ("search", '/', Event::SearchMode),
+    ("projects", 'n', Event::ShowProjects),
+    ("sessions", 's', Event::ShowSessions),
+    ("all", 'a', Event::ShowAll),
+    ("toggle_path", 'P', Event::TogglePath),
+    ("expand_path", 'x', Event::ToggleExpandPath),
+    ("sort", 'o', Event::CycleSortMode),
+    ("pin", 'p', Event::TogglePin),
+    ("reset_frecency", 'f', Event::ResetFrecency),
+    ("reset_all_frecency", 'F', Event::ResetAllFrecency),
+    ("delete", 'd', Event::DeleteProject),
+    ("rename", 'r', Event::StartRename),
+    ("new_session", 'N', Event::StartNewSession),
+    ("kill", 'K', Event::KillSession),
+    ("mark", ' ', Event::ToggleMark),
+    ("layout_picker", 'l', Event::OpenLayoutPicker),
+    ("edit_layout", 'L', Event::EditLayout),
+    ("open_editor", 'e', Event::OpenInEditor),
+    ("copy_command", 'y', Event::CopySwitchCommand),
+];
+
+/// Keys bound to the remappable commands, `None` for disabled ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    /// Key per command, in the order of `COMMANDS`.
+    keys: [Option<char>; COMMANDS.len()],
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            keys: COMMANDS.map(|(_, key, _)| Some(key)),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Returns the names of all remappable commands.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        COMMANDS.iter().map(|(name, _, _)| *name)
+    }
+
+    /// Returns the key bound to the command `name`, if any.
+    #[must_use]
+    pub fn key(&self, name: &str) -> Option<char> {
+        Self::index(name).and_then(|index| self.keys[index])
+    }
+
+    /// Binds the command `name` to `key`, or disables it with `None`.
+    ///
+    /// The key is removed from any other command it was bound to.
+    ///
+    /// # Returns
+    ///
+    /// `false` if there is no command called `name`.
+    pub fn set(&mut self, name: &str, key: Option<char>) -> bool {
+        let Some(index) = Self::index(name) else {
+            return false;
+        };
+
+        if key.is_some() {
+            for bound in self.keys.iter_mut().filter(|bound| **bound == key) {
+                *bound = None;
+            }
+        }
+        self.keys[index] = key;
+        true
+    }
+
+    /// Returns the event of the command bound to `key`, if any.
+    #[must_use]
+    pub fn event_for(&self, key: char) -> Option<Event> {
+        self.keys
+            .iter()
+            .position(|bound| *bound == Some(key))
+            .map(|index| COMMANDS[index].2.clone())
+    }
+
+    /// Parses a key option value: a single character, or `"none"` (or an
+    /// empty value) to disable the command.
+    ///
+    /// # Returns
+    ///
+    /// `Some(binding)` for valid values, `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::app::keys::KeyBindings;
+    ///
+    /// assert_eq!(KeyBindings::parse_key("e"), Some(Some('e')));
+    /// assert_eq!(KeyBindings::parse_key("none"), Some(None));
+    /// assert_eq!(KeyBindings::parse_key(""), Some(None));
+    /// assert_eq!(KeyBindings::parse_key("ctrl+e"), None);
+    /// ```
+    #[must_use]
+    pub fn parse_key(value: &str) -> Option<Option<char>> {
+        if value.is_empty() || value.eq_ignore_ascii_case("none") {
+            return Some(None);
+        }
+
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(key), None) => Some(Some(key)),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the command `name` in `COMMANDS`.
+    fn index(name: &str) -> Option<usize> {
+        COMMANDS.iter().position(|(command, _, _)| *command == name)
+    }
+}
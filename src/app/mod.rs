@@ -18,7 +18,14 @@
 //!
 //! - [`actions`]: Side effect commands emitted by the event handler
 //! - [`handler`]: Event processing logic and state transition coordinator
+//! - [`idle`]: Idle timeout bookkeeping for auto-close
+//! - [`keys`]: Remappable single-key commands
 //! - [`modes`]: Input and view mode state machine types
+//! - [`picker`]: Layout picker overlay state
+//! - [`scan_batch`]: Per-cycle accumulation of scan command results
+//! - [`search_history`]: Recently used search queries
+//! - [`session_cwd`]: Session working directories read from pane titles
+//! - [`session_name`]: Zellij session names derived from project names
 //! - [`state`]: Central application state container and view model computation
 //!
 //! # Example
@@ -34,10 +41,21 @@
 
 pub mod actions;
 pub mod handler;
+pub mod idle;
+pub mod keys;
 pub mod modes;
+pub mod picker;
+pub mod scan_batch;
+pub mod search_history;
+pub mod session_cwd;
+pub mod session_name;
 pub mod state;
 
 pub use actions::Action;
 pub use handler::{handle_event, Event};
-pub use modes::{InputMode, SearchFocus, ViewMode};
+pub use modes::{
+    EnterAction, EnterActions, InputMode, LayoutDensity, PathDisplay, SearchFocus, SearchScope,
+    SortMode, ViewMode,
+};
+pub use picker::LayoutPicker;
 pub use state::AppState;
@@ -6,13 +6,35 @@
 //!
 //! # State Machine
 //!
-//! The application operates in one of two primary input modes:
+//! The application operates in one of four input modes:
 //! - **Normal**: Default navigation and command mode
 //! - **Search**: Active search with typing or result navigation focus
+//! - **Rename**: Editing a new name for the current session
+//! - **`NewSession`**: Naming the session created for a project
 //!
 //! View modes control which projects are visible:
 //! - **Sessions**: Projects with active Zellij sessions
 //! - **`ProjectsWithoutSessions`**: All projects without active sessions
+//! - **All**: Every known project, with or without an active session
+//!
+//! Search scopes control which fields a query matches:
+//! - **Name**: Project name only
+//! - **`NameAndPath`**: Project name or path
+//!
+//! Sort modes control the order of the visible projects:
+//! - **Frecency**: Frequency and recency combined (storage order)
+//! - **Alphabetical**: Project name
+//! - **`RecentlyAccessed`**: Most recently accessed first
+//! - **`MostFrequent`**: Highest access count first
+//!
+//! Path displays control how the PATH column shows project paths:
+//! - **Absolute**: The stored path
+//! - **`RelativeToScanPath`**: Relative to the longest matching scan path
+//! - **`HomeTilde`**: Home directory paths with a `~/` prefix
+//!
+//! Enter actions control what selecting a project does in each view mode:
+//! - **Session**: Switch to or create the project's session
+//! - **Tab**: Open a new tab in the current session at the project's path
 //!
 //! # Example
 //!
@@ -49,7 +71,8 @@ pub enum InputMode {
     /// Default navigation and command mode.
     ///
     /// Available keybindings: j/k (navigate), / (search), enter (select),
-    /// K (kill session), n (new project view), s (sessions view), q (quit).
+    /// K (kill session), n (new project view), s (sessions view), a (all view),
+    /// q (quit).
     Normal,
 
     /// Active search mode with focus state.
@@ -57,6 +80,25 @@ pub enum InputMode {
     /// Contains a [`SearchFocus`] variant indicating whether the user is typing
     /// or navigating results. Footer displays search-specific keybindings.
     Search(SearchFocus),
+
+    /// Editing the new name of a session.
+    ///
+    /// Typed characters edit the name, Enter applies it, and Esc cancels.
+    /// The input is drawn with the search bar.
+    Rename,
+
+    /// Editing the name of a session about to be created for a project.
+    ///
+    /// Behaves like [`InputMode::Rename`], but Enter creates the session.
+    NewSession,
+}
+
+impl InputMode {
+    /// Returns whether typed characters edit a session name.
+    #[must_use]
+    pub const fn edits_session_name(self) -> bool {
+        matches!(self, Self::Rename | Self::NewSession)
+    }
 }
 
 /// View filtering mode determining which projects are displayed.
@@ -72,6 +114,366 @@ pub enum ViewMode {
 
     /// Shows all projects without active sessions.
     ///
-    /// Header displays "All Projects". Available actions: create session.
+    /// Header displays "Projects". Available actions: create session.
     ProjectsWithoutSessions,
+
+    /// Shows every project regardless of session state.
+    ///
+    /// Header displays "All Projects". Available actions: switch or create
+    /// depending on whether the project has an active session.
+    All,
+}
+
+impl ViewMode {
+    /// Name used in configuration and the persisted UI state.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Sessions => "sessions",
+            Self::ProjectsWithoutSessions => "projects",
+            Self::All => "all",
+        }
+    }
+
+    /// Parses a view mode name as returned by [`ViewMode::name`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::ViewMode;
+    ///
+    /// assert_eq!(ViewMode::parse("projects"), Some(ViewMode::ProjectsWithoutSessions));
+    /// assert_eq!(ViewMode::parse(ViewMode::All.name()), Some(ViewMode::All));
+    /// assert_eq!(ViewMode::parse("tabs"), None);
+    /// ```
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "sessions" => Some(Self::Sessions),
+            "projects" => Some(Self::ProjectsWithoutSessions),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
+
+/// Which project fields search queries are matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchScope {
+    /// Match against the project name only.
+    #[default]
+    Name,
+
+    /// Match against the project name or its path.
+    NameAndPath,
+
+    /// Match against the project path only.
+    Path,
+}
+
+impl SearchScope {
+    /// Returns the next scope in the cycle.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Name => Self::NameAndPath,
+            Self::NameAndPath => Self::Path,
+            Self::Path => Self::Name,
+        }
+    }
+
+    /// Short label shown in the footer.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::NameAndPath => "name+path",
+            Self::Path => "path",
+        }
+    }
+
+    /// Parses a scope name (`"name"`, `"path"`, or `"both"`), case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(SearchScope)` if the name is recognized
+    /// - `None` otherwise
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "name" => Some(Self::Name),
+            "path" => Some(Self::Path),
+            "both" => Some(Self::NameAndPath),
+            _ => None,
+        }
+    }
+
+    /// Whether queries are matched against the project name.
+    #[must_use]
+    pub const fn matches_name(self) -> bool {
+        matches!(self, Self::Name | Self::NameAndPath)
+    }
+
+    /// Whether queries are matched against the project path.
+    #[must_use]
+    pub const fn matches_path(self) -> bool {
+        matches!(self, Self::Path | Self::NameAndPath)
+    }
+}
+
+/// Order of the visible projects.
+///
+/// Pinned projects stay above unpinned ones in every mode.
+///
+/// # Example
+///
+/// Modes cycle in a fixed order, each labelled in the header:
+///
+/// ```rust
+/// use zessionizer::SortMode;
+///
+/// let mode = SortMode::default().next();
+/// assert_eq!(mode, SortMode::Alphabetical);
+/// assert_eq!(mode.label(), "alpha");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    /// Frecency order, as sorted by the worker.
+    #[default]
+    Frecency,
+
+    /// Case-insensitive project name order.
+    Alphabetical,
+
+    /// Most recently accessed first.
+    RecentlyAccessed,
+
+    /// Highest access count first, ties kept in frecency order.
+    MostFrequent,
+}
+
+impl SortMode {
+    /// Returns the next mode in the cycle.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Frecency => Self::Alphabetical,
+            Self::Alphabetical => Self::RecentlyAccessed,
+            Self::RecentlyAccessed => Self::MostFrequent,
+            Self::MostFrequent => Self::Frecency,
+        }
+    }
+
+    /// Short label shown in the header.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Frecency => "frecency",
+            Self::Alphabetical => "alpha",
+            Self::RecentlyAccessed => "recent",
+            Self::MostFrequent => "frequent",
+        }
+    }
+}
+
+/// How the PATH column shows project paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathDisplay {
+    /// The path as stored: absolute, or relative to the home directory for
+    /// projects found under `~` scan paths.
+    #[default]
+    Absolute,
+
+    /// Relative to the longest scan path containing the project.
+    RelativeToScanPath,
+
+    /// Paths under the home directory prefixed with `~/`.
+    HomeTilde,
+}
+
+impl PathDisplay {
+    /// Parses a display name (`"absolute"`, `"relative_to_scan_path"`, or
+    /// `"home_tilde"`), case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(PathDisplay)` if the name is recognized
+    /// - `None` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::PathDisplay;
+    ///
+    /// assert_eq!(PathDisplay::parse("home_tilde"), Some(PathDisplay::HomeTilde));
+    /// assert_eq!(PathDisplay::parse(" Relative_To_Scan_Path "), Some(PathDisplay::RelativeToScanPath));
+    /// assert_eq!(PathDisplay::parse("short"), None);
+    /// ```
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "absolute" => Some(Self::Absolute),
+            "relative_to_scan_path" => Some(Self::RelativeToScanPath),
+            "home_tilde" => Some(Self::HomeTilde),
+            _ => None,
+        }
+    }
+}
+
+/// How much vertical space the UI chrome takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDensity {
+    /// Blank top line and borders around the list.
+    #[default]
+    Comfortable,
+
+    /// No blank top line or borders, leaving more rows for the list.
+    Compact,
+}
+
+impl LayoutDensity {
+    /// Parses a density name (`"comfortable"` or `"compact"`),
+    /// case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(LayoutDensity)` if the name is recognized
+    /// - `None` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::LayoutDensity;
+    ///
+    /// assert_eq!(LayoutDensity::parse("compact"), Some(LayoutDensity::Compact));
+    /// assert_eq!(LayoutDensity::parse(" Comfortable "), Some(LayoutDensity::Comfortable));
+    /// assert_eq!(LayoutDensity::parse("dense"), None);
+    /// ```
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "comfortable" => Some(Self::Comfortable),
+            "compact" => Some(Self::Compact),
+            _ => None,
+        }
+    }
+
+    /// Rows taken by everything but the list: blank line, header, borders,
+    /// table headers, and footer, plus the 3-line search bar if `searching`.
+    #[must_use]
+    pub const fn chrome_rows(self, searching: bool) -> usize {
+        let base = match self {
+            Self::Comfortable => 6,
+            Self::Compact => 3,
+        };
+        if searching { base + 3 } else { base }
+    }
+}
+
+/// What selecting a project with Enter does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnterAction {
+    /// Switches to the project's session, creating it if not running.
+    #[default]
+    Session,
+
+    /// Opens a new tab in the current session with the project as cwd.
+    Tab,
+}
+
+impl EnterAction {
+    /// Parses an action name (`"session"` or `"tab"`), case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(EnterAction)` if the name is recognized
+    /// - `None` otherwise
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "session" => Some(Self::Session),
+            "tab" => Some(Self::Tab),
+            _ => None,
+        }
+    }
+}
+
+/// Enter key action for each view mode.
+///
+/// Parsed from the `enter_action` configuration, e.g.
+/// `"projects=tab,sessions=session"`. A bare action name (`"tab"`) applies to
+/// every view mode. Unrecognized entries are ignored, leaving the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnterActions {
+    /// Action in [`ViewMode::Sessions`].
+    pub sessions: EnterAction,
+
+    /// Action in [`ViewMode::ProjectsWithoutSessions`].
+    pub projects: EnterAction,
+
+    /// Action in [`ViewMode::All`].
+    pub all: EnterAction,
+}
+
+impl EnterActions {
+    /// Parses a comma-separated list of `view=action` entries.
+    ///
+    /// View names are `sessions`, `projects`, and `all`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{EnterAction, EnterActions, ViewMode};
+    ///
+    /// let actions = EnterActions::parse("projects=tab");
+    /// assert_eq!(actions.for_view(ViewMode::ProjectsWithoutSessions), EnterAction::Tab);
+    /// assert_eq!(actions.for_view(ViewMode::Sessions), EnterAction::Session);
+    ///
+    /// // An entry without a view applies to all; later entries win.
+    /// let actions = EnterActions::parse("tab, sessions=session, bogus, all=nope");
+    /// assert_eq!(actions.for_view(ViewMode::Sessions), EnterAction::Session);
+    /// assert_eq!(actions.for_view(ViewMode::ProjectsWithoutSessions), EnterAction::Tab);
+    /// assert_eq!(actions.for_view(ViewMode::All), EnterAction::Tab);
+    /// ```
+    #[must_use]
+    pub fn parse(spec: &str) -> Self {
+        let mut actions = Self::default();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (view, action) = entry.split_once('=').map_or((None, entry), |(v, a)| (Some(v.trim()), a));
+
+            let Some(action) = EnterAction::parse(action) else {
+                tracing::debug!(entry = %entry, "ignoring unknown enter action");
+                continue;
+            };
+
+            match view {
+                None => {
+                    actions = Self {
+                        sessions: action,
+                        projects: action,
+                        all: action,
+                    };
+                }
+                Some("sessions") => actions.sessions = action,
+                Some("projects") => actions.projects = action,
+                Some("all") => actions.all = action,
+                Some(other) => {
+                    tracing::debug!(view = %other, "ignoring enter action for unknown view");
+                }
+            }
+        }
+
+        actions
+    }
+
+    /// Returns the configured action for a view mode.
+    #[must_use]
+    pub const fn for_view(&self, view_mode: ViewMode) -> EnterAction {
+        match view_mode {
+            ViewMode::Sessions => self.sessions,
+            ViewMode::ProjectsWithoutSessions => self.projects,
+            ViewMode::All => self.all,
+        }
+    }
 }
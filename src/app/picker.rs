@@ -0,0 +1,105 @@
+//! Layout picker overlay state.
+//!
+//! The picker lists the layouts in the Zellij layout directory for one
+//! project and lets the user choose the layout its session is created with.
+//! It replaces the project table while open, independent of the current
+//! [`ViewMode`](super::modes::ViewMode).
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::app::LayoutPicker;
+//!
+//! let mut picker = LayoutPicker::new(
+//!     "/home/user/code/api".to_string(),
+//!     "api".to_string(),
+//!     vec!["compact".to_string(), "dev".to_string()],
+//!     Some("dev"),
+//! );
+//! assert_eq!(picker.selected_layout(), Some("dev"));
+//!
+//! picker.move_up();
+//! picker.move_up();
+//! assert_eq!(picker.selected_layout(), None); // "(none)" clears the choice
+//! ```
+
+/// Label of the entry that clears a project's stored layout.
+pub const NO_LAYOUT_LABEL: &str = "(none)";
+
+/// Open layout picker for a single project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutPicker {
+    /// Path of the project the layout is chosen for.
+    pub project_path: String,
+
+    /// Name of the project, shown in the header.
+    pub project_name: String,
+
+    /// Available layout names, without the `.kdl` extension.
+    pub layouts: Vec<String>,
+
+    /// Selected entry. `0` is the "(none)" entry, `i` is `layouts[i - 1]`.
+    pub selected: usize,
+}
+
+impl LayoutPicker {
+    /// Creates a picker with the project's current layout pre-selected.
+    ///
+    /// Selects the "(none)" entry when `current` is `None` or not among
+    /// `layouts`.
+    #[must_use]
+    pub fn new(project_path: String, project_name: String, layouts: Vec<String>, current: Option<&str>) -> Self {
+        let selected = current
+            .and_then(|current| layouts.iter().position(|layout| layout == current))
+            .map_or(0, |index| index + 1);
+
+        Self {
+            project_path,
+            project_name,
+            layouts,
+            selected,
+        }
+    }
+
+    /// Returns the number of entries, including "(none)".
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layouts.len() + 1
+    }
+
+    /// Always `false`: the "(none)" entry is always present.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the label of the entry at `index`.
+    #[must_use]
+    pub fn label(&self, index: usize) -> &str {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.layouts.get(index))
+            .map_or(NO_LAYOUT_LABEL, String::as_str)
+    }
+
+    /// Returns the selected layout, or `None` for the "(none)" entry.
+    #[must_use]
+    pub fn selected_layout(&self) -> Option<&str> {
+        self.selected
+            .checked_sub(1)
+            .and_then(|index| self.layouts.get(index))
+            .map(String::as_str)
+    }
+
+    /// Moves the selection down, stopping at the last entry.
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Moves the selection up, stopping at the first entry.
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
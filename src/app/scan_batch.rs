@@ -0,0 +1,104 @@
+//! Accumulation of command-based scan results into one batch per scan cycle.
+//!
+//! A scan cycle runs one command per scan target, and each reports back
+//! separately. [`ScanBatch`] collects their markers and releases them once
+//! every command of the cycle has returned, so the worker stores a cycle's
+//! projects with a single `AddProjectsBatch` instead of one per scan path.
+//!
+//! Commands are tagged with the cycle's generation. Abandoning a cycle (e.g.
+//! when switching scan backends) bumps the generation, so late results from
+//! the old cycle are ignored rather than counted against the new one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::app::scan_batch::ScanBatch;
+//!
+//! let mut batch = ScanBatch::default();
+//! let generation = batch.start(3);
+//!
+//! assert_eq!(batch.record(generation, Some(vec!["a/.git".to_string()])), None);
+//! assert_eq!(batch.record(generation, None), None); // failed scan path
+//! assert_eq!(
+//!     batch.record(generation, Some(vec!["b/.git".to_string()])),
+//!     Some(vec!["a/.git".to_string(), "b/.git".to_string()])
+//! );
+//! assert!(!batch.is_pending());
+//! ```
+
+/// Collects scan results until all commands of the current cycle returned.
+#[derive(Debug, Clone, Default)]
+pub struct ScanBatch {
+    /// Generation of the current cycle, used to tag its commands.
+    generation: u64,
+
+    /// Commands of the current cycle that have not reported yet.
+    pending: usize,
+
+    /// Markers reported so far in the current cycle.
+    markers: Vec<String>,
+
+    /// Whether any command of the current cycle succeeded.
+    succeeded: bool,
+}
+
+impl ScanBatch {
+    /// Registers `count` newly started scan commands.
+    ///
+    /// Scans started while a cycle is still pending join that cycle, so
+    /// overlapping rescans are flushed together.
+    ///
+    /// # Returns
+    ///
+    /// The generation to tag the commands with.
+    pub fn start(&mut self, count: usize) -> u64 {
+        self.pending += count;
+        self.generation
+    }
+
+    /// Abandons the current cycle, discarding its markers.
+    ///
+    /// Results still arriving for it are ignored by [`ScanBatch::record`].
+    pub fn reset(&mut self) {
+        self.generation += 1;
+        self.pending = 0;
+        self.markers.clear();
+        self.succeeded = false;
+    }
+
+    /// Returns whether scan commands are still outstanding.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        self.pending > 0
+    }
+
+    /// Records one command's result: its markers, or `None` if it failed.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(markers)` once the last command of the cycle has reported and
+    ///   at least one of them succeeded
+    /// - `None` while commands are outstanding, when every command failed, or
+    ///   for results of an abandoned cycle
+    pub fn record(&mut self, generation: u64, markers: Option<Vec<String>>) -> Option<Vec<String>> {
+        if generation != self.generation || self.pending == 0 {
+            tracing::debug!(generation = generation, current = self.generation, "ignoring stale scan result");
+            return None;
+        }
+
+        if let Some(markers) = markers {
+            self.markers.extend(markers);
+            self.succeeded = true;
+        }
+        self.pending -= 1;
+
+        if self.pending > 0 {
+            tracing::debug!(pending = self.pending, "scan result buffered");
+            return None;
+        }
+
+        let succeeded = std::mem::take(&mut self.succeeded);
+        let markers = std::mem::take(&mut self.markers);
+        succeeded.then_some(markers)
+    }
+}
@@ -0,0 +1,157 @@
+//! Recently used search queries.
+//!
+//! Queries are recorded when a search leads somewhere (a project is opened or
+//! the plugin closes with a query typed) and can be recalled with Up/Down in
+//! an empty search box. The history is persisted with the UI state, so it
+//! survives plugin reloads.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::app::search_history::SearchHistory;
+//!
+//! let mut history = SearchHistory::default();
+//! history.push("api");
+//! history.push("web");
+//! history.push("web");
+//! history.push("  ");
+//!
+//! assert_eq!(history.entries().collect::<Vec<_>>(), ["web", "api"]);
+//!
+//! assert_eq!(history.older(), Some("web"));
+//! assert_eq!(history.older(), Some("api"));
+//! assert_eq!(history.older(), Some("api")); // stays at the oldest
+//! assert_eq!(history.newer(), Some("web"));
+//! assert_eq!(history.newer(), Some("")); // back to the empty search box
+//! assert!(!history.is_browsing());
+//! assert_eq!(history.newer(), None);
+//! ```
+
+use std::collections::VecDeque;
+
+/// Maximum number of queries kept.
+pub const SEARCH_HISTORY_SIZE: usize = 20;
+
+/// Bounded list of recent search queries, newest first, with a recall cursor.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchHistory {
+    /// Queries, newest first.
+    entries: VecDeque<String>,
+    /// Index of the recalled entry, `None` when not browsing.
+    cursor: Option<usize>,
+}
+
+impl SearchHistory {
+    /// Builds a history from saved queries, newest first.
+    ///
+    /// Blank entries and consecutive duplicates are dropped, and only the
+    /// newest [`SEARCH_HISTORY_SIZE`] queries are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::app::search_history::{SearchHistory, SEARCH_HISTORY_SIZE};
+    ///
+    /// let saved: Vec<String> = (0..30).map(|i| format!("q{i}")).collect();
+    /// let history = SearchHistory::from_entries(saved);
+    /// assert_eq!(history.entries().count(), SEARCH_HISTORY_SIZE);
+    /// assert_eq!(history.entries().next(), Some("q0"));
+    ///
+    /// let history = SearchHistory::from_entries(["a", "a", "", "b"].map(String::from));
+    /// assert_eq!(history.entries().collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn from_entries(entries: impl IntoIterator<Item = String>) -> Self {
+        let mut history = Self::default();
+        for entry in entries {
+            if history.entries.len() == SEARCH_HISTORY_SIZE {
+                break;
+            }
+            let entry = entry.trim();
+            if !entry.is_empty() && history.entries.back().map(String::as_str) != Some(entry) {
+                history.entries.push_back(entry.to_string());
+            }
+        }
+        history
+    }
+
+    /// Records a query as the newest entry and stops browsing.
+    ///
+    /// Blank queries and repeats of the newest entry are ignored. The oldest
+    /// entry is dropped once [`SEARCH_HISTORY_SIZE`] is exceeded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::app::search_history::{SearchHistory, SEARCH_HISTORY_SIZE};
+    ///
+    /// let mut history = SearchHistory::default();
+    /// for i in 0..=SEARCH_HISTORY_SIZE {
+    ///     history.push(&format!("q{i}"));
+    /// }
+    /// history.push("q5");
+    ///
+    /// let entries: Vec<_> = history.entries().collect();
+    /// assert_eq!(entries.len(), SEARCH_HISTORY_SIZE);
+    /// assert_eq!(entries[0], "q5");
+    /// assert_eq!(entries[1], format!("q{SEARCH_HISTORY_SIZE}"));
+    /// assert!(!entries.contains(&"q0"));
+    /// ```
+    pub fn push(&mut self, query: &str) {
+        self.cursor = None;
+
+        let query = query.trim();
+        if query.is_empty() || self.entries.front().map(String::as_str) == Some(query) {
+            return;
+        }
+
+        self.entries.push_front(query.to_string());
+        self.entries.truncate(SEARCH_HISTORY_SIZE);
+    }
+
+    /// Recalls the next older query, staying at the oldest one.
+    ///
+    /// Returns `None` if the history is empty.
+    pub fn older(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let index = self.cursor.map_or(0, |index| (index + 1).min(self.entries.len() - 1));
+        self.cursor = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Recalls the next newer query.
+    ///
+    /// Moving past the newest query stops browsing and returns an empty
+    /// query. Returns `None` if not browsing.
+    pub fn newer(&mut self) -> Option<&str> {
+        match self.cursor? {
+            0 => {
+                self.cursor = None;
+                Some("")
+            }
+            index => {
+                self.cursor = Some(index - 1);
+                self.entries.get(index - 1).map(String::as_str)
+            }
+        }
+    }
+
+    /// Returns whether a recalled query is shown.
+    #[must_use]
+    pub const fn is_browsing(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// Stops browsing, keeping the current query as typed.
+    pub fn stop_browsing(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Returns the queries, newest first.
+    pub fn entries(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(String::as_str)
+    }
+}
@@ -0,0 +1,135 @@
+//! Working directories of Zellij sessions.
+//!
+//! `SessionInfo` carries no working directory, but shells commonly set the
+//! terminal title to it (`user@host: ~/code/api`). [`session_cwds`] reads the
+//! focused terminal pane's title in each session and keeps those that look
+//! like a path, so sessions can be linked to the project they run in even
+//! when their names differ.
+//!
+//! Home-relative titles (`~/code/api`) become relative paths (`code/api`),
+//! matching how projects found below the home directory are stored.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use zellij_tile::prelude::{PaneInfo, SessionInfo};
+
+/// Maps session names to working directories read from their pane titles.
+///
+/// The focused terminal pane of the active tab is preferred; otherwise the
+/// first terminal pane (by tab position) whose title is a path is used.
+/// Sessions without such a pane are left out.
+///
+/// # Example
+///
+/// ```rust
+/// use std::path::PathBuf;
+/// use zellij_tile::prelude::{PaneInfo, PaneManifest, SessionInfo, TabInfo};
+/// use zessionizer::app::session_cwd::session_cwds;
+///
+/// let pane = |title: &str, is_focused| PaneInfo {
+///     title: title.to_string(),
+///     is_focused,
+///     ..Default::default()
+/// };
+/// let tab = |position, active| TabInfo { position, active, ..Default::default() };
+///
+/// let api = SessionInfo {
+///     name: "api".to_string(),
+///     tabs: vec![tab(0, false), tab(1, true)],
+///     panes: PaneManifest {
+///         panes: [
+///             (0, vec![pane("/tmp", true)]),
+///             (1, vec![pane("me@box: ~/code/api", false), pane("me@box: ~/code/api/src", true)]),
+///         ]
+///         .into(),
+///     },
+///     ..Default::default()
+/// };
+/// let web = SessionInfo {
+///     name: "web".to_string(),
+///     tabs: vec![tab(0, true)],
+///     panes: PaneManifest {
+///         panes: [(0, vec![pane("Pane #1", true), pane("/srv/web", false)])].into(),
+///     },
+///     ..Default::default()
+/// };
+/// let scratch = SessionInfo {
+///     name: "scratch".to_string(),
+///     tabs: vec![tab(0, true)],
+///     panes: PaneManifest { panes: [(0, vec![pane("htop", true)])].into() },
+///     ..Default::default()
+/// };
+///
+/// let cwds = session_cwds(&[api, web, scratch]);
+/// assert_eq!(cwds.get("api"), Some(&PathBuf::from("code/api/src")));
+/// assert_eq!(cwds.get("web"), Some(&PathBuf::from("/srv/web")));
+/// assert_eq!(cwds.get("scratch"), None);
+/// ```
+#[must_use]
+pub fn session_cwds(session_infos: &[SessionInfo]) -> HashMap<String, PathBuf> {
+    session_infos
+        .iter()
+        .filter_map(|session| session_cwd(session).map(|cwd| (session.name.clone(), cwd)))
+        .collect()
+}
+
+/// Returns the working directory of one session, if a pane title reveals it.
+fn session_cwd(session: &SessionInfo) -> Option<PathBuf> {
+    let terminal_panes = |position: usize| {
+        session
+            .panes
+            .panes
+            .get(&position)
+            .into_iter()
+            .flatten()
+            .filter(|pane: &&PaneInfo| !pane.is_plugin && !pane.is_suppressed)
+    };
+
+    let active_tab = session.tabs.iter().find(|tab| tab.active).map(|tab| tab.position);
+    let focused = active_tab
+        .into_iter()
+        .flat_map(terminal_panes)
+        .filter(|pane| pane.is_focused)
+        .find_map(|pane| cwd_from_title(&pane.title));
+
+    focused.or_else(|| {
+        let mut positions: Vec<usize> = session.panes.panes.keys().copied().collect();
+        positions.sort_unstable();
+        positions
+            .into_iter()
+            .flat_map(terminal_panes)
+            .find_map(|pane| cwd_from_title(&pane.title))
+    })
+}
+
+/// Extracts a directory from a terminal title.
+///
+/// Accepts absolute paths and `~/`-relative paths, optionally after a
+/// `user@host:` prefix. Other titles (commands, `Pane #1`) yield `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::path::PathBuf;
+/// use zessionizer::app::session_cwd::cwd_from_title;
+///
+/// assert_eq!(cwd_from_title("/code/api"), Some(PathBuf::from("/code/api")));
+/// assert_eq!(cwd_from_title("me@box: ~/code/api"), Some(PathBuf::from("code/api")));
+/// assert_eq!(cwd_from_title("me@box:/srv/web/"), Some(PathBuf::from("/srv/web/")));
+/// assert_eq!(cwd_from_title("~"), None);
+/// assert_eq!(cwd_from_title("Pane #1"), None);
+/// assert_eq!(cwd_from_title("vim notes.md"), None);
+/// ```
+#[must_use]
+pub fn cwd_from_title(title: &str) -> Option<PathBuf> {
+    let title = title.trim();
+    let path = title.rsplit_once(':').map_or(title, |(_, path)| path).trim();
+
+    if path.starts_with('/') {
+        Some(PathBuf::from(path))
+    } else {
+        path.strip_prefix("~/")
+            .filter(|relative| !relative.is_empty())
+            .map(PathBuf::from)
+    }
+}
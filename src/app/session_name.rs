@@ -0,0 +1,87 @@
+//! Zellij session names derived from project names.
+//!
+//! Project names come from directory names and may contain characters that
+//! Zellij rejects or that break `zellij attach` on the command line (dots,
+//! slashes, spaces). [`sanitize_session_name`] maps a project name to a safe
+//! session identifier; the project itself keeps its original name for display
+//! and storage.
+//!
+//! Sessions created before names were sanitized still carry the raw project
+//! name, so [`find_session`] accepts either form.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::collections::HashSet;
+//! use zessionizer::app::session_name::{find_session, sanitize_session_name};
+//!
+//! assert_eq!(sanitize_session_name("my.project v2"), "my-project-v2");
+//! assert_eq!(sanitize_session_name("api"), "api");
+//!
+//! let active: HashSet<String> = ["my-project-v2".to_string(), "legacy.app".to_string()].into();
+//! assert_eq!(find_session(&active, "my.project v2"), Some("my-project-v2"));
+//! assert_eq!(find_session(&active, "legacy.app"), Some("legacy.app"));
+//! assert_eq!(find_session(&active, "web"), None);
+//! ```
+
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+/// Session name used when a project name has no usable characters.
+const FALLBACK_SESSION_NAME: &str = "session";
+
+/// Maps a project name to a valid Zellij session name.
+///
+/// Letters, digits, `-`, and `_` are kept. Runs of any other characters become
+/// a single `-`, and leading or trailing `-` are trimmed. Names without any
+/// usable characters become `"session"`.
+///
+/// # Example
+///
+/// ```rust
+/// use zessionizer::app::session_name::sanitize_session_name;
+///
+/// assert_eq!(sanitize_session_name("  docs/site.io  "), "docs-site-io");
+/// assert_eq!(sanitize_session_name("my_repo"), "my_repo");
+/// assert_eq!(sanitize_session_name("..."), "session");
+/// ```
+#[must_use]
+pub fn sanitize_session_name(name: &str) -> String {
+    let mut sanitized = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '-' || c == '_' {
+            sanitized.push(c);
+        } else if !sanitized.is_empty() && !sanitized.ends_with('-') {
+            sanitized.push('-');
+        }
+    }
+
+    let sanitized = sanitized.trim_end_matches('-');
+    if sanitized.is_empty() {
+        FALLBACK_SESSION_NAME.to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+/// Returns whether `session` is the session of the project called `project_name`.
+///
+/// Matches the sanitized project name as well as the raw one.
+#[must_use]
+pub fn is_session_of(session: &str, project_name: &str) -> bool {
+    session == project_name || session == sanitize_session_name(project_name)
+}
+
+/// Returns the active session of the project called `project_name`, if any.
+///
+/// A session with the raw project name takes precedence over the sanitized one.
+#[must_use]
+pub fn find_session<'a, S: BuildHasher>(
+    active_sessions: &'a HashSet<String, S>,
+    project_name: &str,
+) -> Option<&'a str> {
+    active_sessions
+        .get(project_name)
+        .or_else(|| active_sessions.get(&sanitize_session_name(project_name)))
+        .map(String::as_str)
+}
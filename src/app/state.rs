@@ -40,18 +40,35 @@
 //! let viewmodel = state.compute_viewmodel(24, 80);
 //! ```
 
+use crate::domain::project::tag_query;
 use crate::domain::Project;
+use crate::infrastructure::scanner::{canonical_project_path, resolve_scan_root};
 use crate::ui::theme::Theme;
-use super::modes::{InputMode, ViewMode};
-use std::collections::HashSet;
+use crate::ui::viewmodel::EmptyStateKind;
+use crate::worker::WorkerMessage;
+use super::modes::{EnterActions, InputMode, LayoutDensity, PathDisplay, SearchScope, SortMode, ViewMode};
+use super::picker::LayoutPicker;
+use super::search_history::SearchHistory;
+use super::session_name::{find_session, is_session_of};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use fuzzy_matcher::skim::SkimMatcherV2;
 
+/// Default cap on highlight ranges per display item.
+pub const DEFAULT_MAX_HIGHLIGHT_RANGES: usize = 32;
+
+/// Fewest rows the UI is drawn in; smaller panes show a "too small" notice.
+pub const MIN_RENDER_ROWS: usize = 5;
+
+/// Fewest columns the UI is drawn in; narrower panes show a "too small" notice.
+pub const MIN_RENDER_COLS: usize = 20;
+
 /// Central application state container.
 ///
 /// Holds all transient UI state including project lists, filters, selection,
 /// and mode information. Mutated by the event handler in response to user input
 /// and system events. View models are computed on-demand from state snapshots.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct AppState {
     /// Master list of all projects loaded from storage.
     ///
@@ -79,14 +96,25 @@ pub struct AppState {
 
     /// Current search query string.
     ///
-    /// Accumulated by `Char` events, reduced by `Backspace` events, cleared
-    /// by `ExitSearch` and `Escape` events. Tokenized for filtering.
+    /// Edited at the cursor by `Char` and `Backspace` events, cleared by
+    /// `ExitSearch` and `Escape` events. Tokenized for filtering.
     pub search_query: String,
 
+    /// Cursor position in `search_query`, as a character index.
+    ///
+    /// Moved by `CursorLeft` and `CursorRight`. Read through
+    /// [`Self::search_cursor`], which clamps it to the query length.
+    pub cursor_pos: usize,
+
+    /// Recently used search queries, recalled with Up/Down.
+    ///
+    /// Recorded whenever the UI state is saved and persisted alongside it.
+    pub search_history: SearchHistory,
+
     /// Current view filtering mode.
     ///
     /// Determines which projects are visible before search filtering. Changed
-    /// by `ShowProjects` and `ShowSessions` events.
+    /// by `ShowProjects`, `ShowSessions`, and `ShowAll` events.
     pub view_mode: ViewMode,
 
     /// Color scheme for UI rendering.
@@ -106,6 +134,198 @@ pub struct AppState {
     /// Updated by `SessionUpdate` events. Used to filter out the current session
     /// from the Sessions view.
     pub current_session: Option<String>,
+
+    /// Path segments excluding scanned projects (see `scanner::is_excluded`).
+    pub path_exclude: Vec<String>,
+
+    /// Whether projects with active sessions are grouped above the rest.
+    ///
+    /// Applied after filtering in `apply_search_filter()`. Frecency order is
+    /// preserved within each group. Only affects views mixing both kinds.
+    pub sessions_first: bool,
+
+    /// Number of terminal panes in each active session.
+    ///
+    /// Updated by `SessionUpdate` events. Used to decide whether killing a
+    /// session needs confirmation.
+    pub session_panes: HashMap<String, usize>,
+
+    /// Pane count above which killing a session asks for confirmation.
+    ///
+    /// `None` kills immediately, regardless of pane count.
+    pub kill_confirm_panes: Option<usize>,
+
+    /// Session awaiting a second kill press to confirm.
+    ///
+    /// For a bulk kill, the first marked session needing confirmation.
+    pub pending_kill: Option<String>,
+
+    /// Sessions marked with Space for a bulk kill (Sessions view).
+    ///
+    /// Pruned to active sessions by `SessionUpdate` events, cleared by
+    /// `Escape` and after the marked sessions are killed.
+    pub marked_sessions: HashSet<String>,
+
+    /// Session being renamed while in [`InputMode::Rename`].
+    pub rename_target: Option<String>,
+
+    /// Project path to create a session for while in [`InputMode::NewSession`].
+    pub new_session_path: Option<String>,
+
+    /// Session name typed so far while renaming or naming a new session.
+    pub rename_buffer: String,
+
+    /// Warning shown in the footer until dismissed with Esc or the first key
+    /// press that triggers an action, e.g. invalid configuration values.
+    pub footer_message: Option<String>,
+
+    /// Transient status shown in the footer until it expires or a key is
+    /// pressed, e.g. the summary of the last scan or a rejected session name.
+    /// Ranks above `footer_message`.
+    pub footer_notice: Option<String>,
+
+    /// Open layout picker, drawn in place of the project table.
+    pub layout_picker: Option<LayoutPicker>,
+
+    /// Whether the current session is moved to the top of the Sessions view.
+    ///
+    /// Applied after filtering; the entry stays selectable and marked.
+    pub current_session_first: bool,
+
+    /// Whether the current session is excluded from the Sessions view.
+    pub hide_current_session: bool,
+
+    /// Maximum number of highlight ranges emitted per display item.
+    ///
+    /// Bounds the ANSI overhead of rendering pathological fuzzy matches. When
+    /// exceeded, only the longest contiguous runs are kept.
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![Project::new("/code/x".to_string(), "ab-c-d-efg".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query("abcdefg".to_string());
+    /// let ranges = |state: &AppState| state.compute_viewmodel(24, 80).display_items[0].highlight_ranges.clone();
+    /// assert_eq!(ranges(&state), [(0, 2), (3, 4), (5, 6), (7, 10)]);
+    ///
+    /// state.max_highlight_ranges = 2;
+    /// assert_eq!(ranges(&state), [(0, 2), (7, 10)]);
+    ///
+    /// state.max_highlight_ranges = 3;
+    /// assert_eq!(ranges(&state), [(0, 2), (3, 4), (7, 10)]);
+    ///
+    /// state.max_highlight_ranges = 0;
+    /// assert!(ranges(&state).is_empty());
+    /// ```
+    pub max_highlight_ranges: usize,
+
+    /// Whether the selected project is persisted on close and restored on load.
+    pub remember_selection: bool,
+
+    /// Project path waiting to be selected once projects arrive.
+    ///
+    /// Set from the stored selection on load and consumed by
+    /// `restore_selection()` on the first reload that contains projects.
+    pub pending_selection: Option<String>,
+
+    /// Whether stored sessions are marked active until live data arrives.
+    pub persist_sessions: bool,
+
+    /// Whether a `SessionUpdate` from Zellij has been received.
+    ///
+    /// Once set, stored sessions are no longer used to seed `active_sessions`.
+    pub sessions_received: bool,
+
+    /// Whether the selected row shows its full path instead of a truncated one.
+    ///
+    /// Toggled at runtime by `ToggleExpandPath`.
+    pub expand_selected_path: bool,
+
+    /// Fields matched by the search query.
+    ///
+    /// Cycled at runtime by `ToggleSearchScope`.
+    pub search_scope: SearchScope,
+
+    /// Order of the visible projects.
+    ///
+    /// Cycled at runtime by `CycleSortMode`.
+    pub sort_mode: SortMode,
+
+    /// Frecency decay half-life in hours, used to score exported projects.
+    pub frecency_half_life_hours: f64,
+
+    /// Whether the PATH column is shown.
+    ///
+    /// Toggled at runtime by `TogglePath`. When hidden, the NAME column widens
+    /// to use the freed space.
+    pub show_path: bool,
+
+    /// How the PATH column shows project paths.
+    pub path_display: PathDisplay,
+
+    /// Whether the blank top line and list borders are drawn.
+    pub layout_density: LayoutDensity,
+
+    /// Whether `ResetAllFrecency` may reset every project's frecency data.
+    pub allow_reset_all: bool,
+
+    /// Configured scan paths, used by [`PathDisplay::RelativeToScanPath`].
+    pub scan_paths: Vec<String>,
+
+    /// Action performed by `SelectProject` in each view mode.
+    pub enter_actions: EnterActions,
+
+    /// Whether the footer ends with `[visible/total]` project counts.
+    pub footer_show_counts: bool,
+
+    /// Whether moving past either end of the list wraps to the other end.
+    ///
+    /// When `false`, the selection stops at the first and last project.
+    pub wrap_navigation: bool,
+
+    /// Terminal height at the last render, used to size page navigation.
+    pub viewport_rows: usize,
+
+    /// Text drawn between the NAME and PATH columns.
+    ///
+    /// Its width is subtracted from the space available for paths, so paths
+    /// still end before the VISITS and TIME columns.
+    ///
+    /// ```rust
+    /// use zessionizer::ui::viewmodel::{path_column, TIME_COLUMN_WIDTH, VISITS_COLUMN_WIDTH};
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let path = "/srv/projects/clients/acme/services/billing-api";
+    /// let projects = vec![Project::new(path.to_string(), "billing".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// for separator in ["", " │ ", "  ┃  "] {
+    ///     state.column_separator = separator.to_string();
+    ///     let vm = state.compute_viewmodel(24, 90);
+    ///     assert_eq!(vm.column_separator, separator);
+    ///
+    ///     // The path starts right after the separator and leaves the 2-column
+    ///     // margin before VISITS and TIME.
+    ///     let path_end = path_column(separator) + vm.display_items[0].path.chars().count();
+    ///     assert_eq!(path_end + 2, 90 - VISITS_COLUMN_WIDTH - TIME_COLUMN_WIDTH);
+    /// }
+    /// ```
+    pub column_separator: String,
+
+    /// Number of projects loaded per page, `0` to load all projects at once.
+    pub projects_page_size: usize,
+
+    /// Number of stored projects while only some pages are loaded.
+    ///
+    /// `None` once every project is in `projects`.
+    pub projects_total: Option<usize>,
+
+    /// Whether a page request is awaiting its response.
+    pub page_pending: bool,
 }
 
 impl AppState {
@@ -140,16 +360,56 @@ impl AppState {
             selected_index: 0,
             input_mode: InputMode::Normal,
             search_query: String::new(),
+            cursor_pos: 0,
+            search_history: SearchHistory::default(),
             view_mode: ViewMode::Sessions,
             theme,
             active_sessions: HashSet::new(),
             current_session: None,
+            session_panes: HashMap::new(),
+            kill_confirm_panes: None,
+            pending_kill: None,
+            marked_sessions: HashSet::new(),
+            rename_target: None,
+            new_session_path: None,
+            rename_buffer: String::new(),
+            footer_message: None,
+            footer_notice: None,
+            layout_picker: None,
+            path_exclude: Vec::new(),
+            sessions_first: false,
+            current_session_first: false,
+            hide_current_session: false,
+            max_highlight_ranges: DEFAULT_MAX_HIGHLIGHT_RANGES,
+            remember_selection: true,
+            pending_selection: None,
+            persist_sessions: true,
+            sessions_received: false,
+            expand_selected_path: false,
+            search_scope: SearchScope::default(),
+            sort_mode: SortMode::default(),
+            frecency_half_life_hours: crate::storage::DEFAULT_HALF_LIFE_HOURS,
+            show_path: true,
+            path_display: PathDisplay::default(),
+            layout_density: LayoutDensity::default(),
+            allow_reset_all: false,
+            scan_paths: Vec::new(),
+            enter_actions: EnterActions::default(),
+            footer_show_counts: false,
+            wrap_navigation: true,
+            viewport_rows: 0,
+            column_separator: String::new(),
+            projects_page_size: 0,
+            projects_total: None,
+            page_pending: false,
         }
     }
 
-    /// Moves selection cursor down by one position, wrapping to top if at end.
+    /// Moves selection cursor down by one position.
     ///
-    /// Called by `KeyDown` event handler. No-op if filtered projects list is empty.
+    /// At the end, wraps to the top if `wrap_navigation` is enabled and stays
+    /// put otherwise. Called by `KeyDown` event handler. No-op if filtered
+    /// projects list is empty.
     ///
     /// # Example
     ///
@@ -159,16 +419,45 @@ impl AppState {
     /// # let mut state = AppState::new(vec![], Theme::default());
     /// state.move_selection_down();
     /// ```
+    ///
+    /// Wrapping and clamping at the last project:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// state.selected_index = 1;
+    ///
+    /// state.move_selection_down();
+    /// assert_eq!(state.selected_index, 0);
+    ///
+    /// state.wrap_navigation = false;
+    /// state.selected_index = 1;
+    /// state.move_selection_down();
+    /// assert_eq!(state.selected_index, 1);
+    /// ```
     pub fn move_selection_down(&mut self) {
         if self.filtered_projects.is_empty() {
             return;
         }
-        self.selected_index = (self.selected_index + 1) % self.filtered_projects.len();
+        if self.selected_index + 1 < self.filtered_projects.len() {
+            self.selected_index += 1;
+        } else if self.wrap_navigation {
+            self.selected_index = 0;
+        }
     }
 
-    /// Moves selection cursor up by one position, wrapping to bottom if at start.
+    /// Moves selection cursor up by one position.
     ///
-    /// Called by `KeyUp` event handler. No-op if filtered projects list is empty.
+    /// At the start, wraps to the bottom if `wrap_navigation` is enabled and
+    /// stays put otherwise. Called by `KeyUp` event handler. No-op if filtered
+    /// projects list is empty.
     ///
     /// # Example
     ///
@@ -178,40 +467,840 @@ impl AppState {
     /// # let mut state = AppState::new(vec![], Theme::default());
     /// state.move_selection_up();
     /// ```
+    ///
+    /// Wrapping and clamping at the first project:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// state.move_selection_up();
+    /// assert_eq!(state.selected_index, 1);
+    ///
+    /// state.wrap_navigation = false;
+    /// state.selected_index = 0;
+    /// state.move_selection_up();
+    /// assert_eq!(state.selected_index, 0);
+    /// ```
     pub fn move_selection_up(&mut self) {
         if self.filtered_projects.is_empty() {
             return;
         }
-        if self.selected_index == 0 {
+        if self.selected_index > 0 {
+            self.selected_index -= 1;
+        } else if self.wrap_navigation {
             self.selected_index = self.filtered_projects.len() - 1;
+        }
+    }
+
+    /// Returns the number of list rows visible at the last render.
+    ///
+    /// At least 1, so page navigation always moves before the first render.
+    #[must_use]
+    pub const fn page_size(&self) -> usize {
+        let rows = self.calculate_available_rows(self.viewport_rows);
+        if rows == 0 {
+            1
         } else {
-            self.selected_index -= 1;
+            rows
+        }
+    }
+
+    /// Moves selection cursor down by `page_size` positions.
+    ///
+    /// Stops at the last project. Only a page down from the last project
+    /// wraps to the top, and only if `wrap_navigation` is enabled. No-op if
+    /// filtered projects list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = (0..100)
+    ///     .map(|i| Project::new(format!("/code/p{i}"), format!("p{i}")))
+    ///     .collect();
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// state.viewport_rows = 26; // 20 list rows below the chrome
+    /// assert_eq!(state.page_size(), 20);
+    ///
+    /// handle_event(&mut state, &Event::PageDown).unwrap();
+    /// assert_eq!(state.selected_index, 20);
+    ///
+    /// state.selected_index = 90;
+    /// handle_event(&mut state, &Event::PageDown).unwrap();
+    /// assert_eq!(state.selected_index, 99);
+    /// handle_event(&mut state, &Event::PageDown).unwrap();
+    /// assert_eq!(state.selected_index, 0);
+    ///
+    /// state.wrap_navigation = false;
+    /// state.selected_index = 99;
+    /// handle_event(&mut state, &Event::PageDown).unwrap();
+    /// assert_eq!(state.selected_index, 99);
+    /// ```
+    pub fn page_down(&mut self, page_size: usize) {
+        let Some(last) = self.filtered_projects.len().checked_sub(1) else {
+            return;
+        };
+        self.selected_index = if self.selected_index == last && self.wrap_navigation {
+            0
+        } else {
+            (self.selected_index + page_size).min(last)
+        };
+    }
+
+    /// Moves selection cursor up by `page_size` positions.
+    ///
+    /// Stops at the first project. Only a page up from the first project
+    /// wraps to the bottom, and only if `wrap_navigation` is enabled. No-op if
+    /// filtered projects list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = (0..100)
+    ///     .map(|i| Project::new(format!("/code/p{i}"), format!("p{i}")))
+    ///     .collect();
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// state.viewport_rows = 26;
+    ///
+    /// state.selected_index = 50;
+    /// handle_event(&mut state, &Event::PageUp).unwrap();
+    /// assert_eq!(state.selected_index, 30);
+    ///
+    /// state.selected_index = 10;
+    /// handle_event(&mut state, &Event::PageUp).unwrap();
+    /// assert_eq!(state.selected_index, 0);
+    /// handle_event(&mut state, &Event::PageUp).unwrap();
+    /// assert_eq!(state.selected_index, 99);
+    ///
+    /// state.wrap_navigation = false;
+    /// state.selected_index = 0;
+    /// handle_event(&mut state, &Event::PageUp).unwrap();
+    /// assert_eq!(state.selected_index, 0);
+    /// ```
+    pub fn page_up(&mut self, page_size: usize) {
+        if self.filtered_projects.is_empty() {
+            return;
+        }
+        self.selected_index = if self.selected_index == 0 && self.wrap_navigation {
+            self.filtered_projects.len() - 1
+        } else {
+            self.selected_index.saturating_sub(page_size)
+        };
+    }
+
+    /// Iterates over the projects currently visible, in display order.
+    ///
+    /// Reflects the active view mode and search query exactly as the UI would
+    /// show them, without building a view model or copying projects.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    ///     Project::new("/code/apps".to_string(), "apps".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query("ap".to_string());
+    ///
+    /// let names: Vec<&str> = state.filtered().map(|p| p.name.as_str()).collect();
+    /// assert_eq!(names, ["api", "apps"]);
+    /// ```
+    pub fn filtered(&self) -> impl Iterator<Item = &Project> {
+        self.filtered_projects.iter()
+    }
+
+    /// Returns a reference to the currently selected project, if any.
+    ///
+    /// Returns `None` if the filtered projects list is empty or the selected index
+    /// is out of bounds (which should never occur due to clamping).
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&Project)` if a project is selected
+    /// - `None` if no projects are visible
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use crate::app::AppState;
+    /// # use crate::ui::theme::Theme;
+    /// # let mut state = AppState::new(vec![], Theme::default());
+    /// if let Some(project) = state.selected_project() {
+    ///     println!("Selected: {}", project.name);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn selected_project(&self) -> Option<&Project> {
+        self.filtered_projects.get(self.selected_index)
+    }
+
+    /// Returns the name of the active session of `project`, if any.
+    ///
+    /// See [`find_session`] for how project and session names are matched.
+    #[must_use]
+    pub fn active_session(&self, project: &Project) -> Option<&str> {
+        find_session(&self.active_sessions, &project.name)
+    }
+
+    /// Returns whether `project` has an active session.
+    #[must_use]
+    pub fn has_session(&self, project: &Project) -> bool {
+        self.active_session(project).is_some()
+    }
+
+    /// Returns whether `project` is the session the plugin runs in.
+    #[must_use]
+    pub fn is_current_session(&self, project: &Project) -> bool {
+        self.current_session
+            .as_deref()
+            .is_some_and(|current| is_session_of(current, &project.name))
+    }
+
+    /// Replaces the search query and re-applies filtering.
+    ///
+    /// Does not change the input mode, so embedders can filter the list
+    /// without opening the search bar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    ///
+    /// state.set_search_query("web".to_string());
+    /// assert_eq!(state.filtered_projects.len(), 1);
+    /// assert_eq!(state.selected_project().unwrap().name, "web");
+    /// ```
+    pub fn set_search_query(&mut self, query: String) {
+        self.cursor_pos = query.chars().count();
+        self.search_query = query;
+        self.apply_search_filter();
+    }
+
+    /// Returns the cursor position in the search query, as a character
+    /// index no greater than the query length.
+    #[must_use]
+    pub fn search_cursor(&self) -> usize {
+        self.cursor_pos.min(self.search_query.chars().count())
+    }
+
+    /// Inserts `c` into the search query at the cursor and moves the cursor
+    /// past it.
+    pub fn insert_at_cursor(&mut self, c: char) {
+        let cursor = self.search_cursor();
+        let at = self.cursor_byte_offset(cursor);
+        self.search_query.insert(at, c);
+        self.cursor_pos = cursor + 1;
+    }
+
+    /// Deletes the character before the cursor from the search query.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the cursor is at the start of the query.
+    pub fn delete_before_cursor(&mut self) -> bool {
+        let cursor = self.search_cursor();
+        if cursor == 0 {
+            return false;
+        }
+
+        let at = self.cursor_byte_offset(cursor - 1);
+        self.search_query.remove(at);
+        self.cursor_pos = cursor - 1;
+        true
+    }
+
+    /// Deletes the word before the cursor from the search query, along with
+    /// any whitespace between it and the cursor.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the cursor is at the start of the query.
+    pub fn delete_word_before_cursor(&mut self) -> bool {
+        let cursor = self.search_cursor();
+        let before: Vec<char> = self.search_query.chars().take(cursor).collect();
+        let word_end = before.iter().rposition(|c| !c.is_whitespace()).map_or(0, |index| index + 1);
+        let word_start = before[..word_end]
+            .iter()
+            .rposition(|c| c.is_whitespace())
+            .map_or(0, |index| index + 1);
+        if word_start == cursor {
+            return false;
+        }
+
+        let start = self.cursor_byte_offset(word_start);
+        let end = self.cursor_byte_offset(cursor);
+        self.search_query.replace_range(start..end, "");
+        self.cursor_pos = word_start;
+        true
+    }
+
+    /// Returns the byte offset of the character index `cursor` in the
+    /// search query.
+    fn cursor_byte_offset(&self, cursor: usize) -> usize {
+        self.search_query
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.search_query.len(), |(offset, _)| offset)
+    }
+
+    /// Returns whether Up/Down recall search history instead of typing.
+    ///
+    /// History is browsed while typing into an empty search box, and keeps
+    /// being browsed as long as a recalled query is shown unedited.
+    #[must_use]
+    pub fn browses_search_history(&self) -> bool {
+        use crate::app::modes::SearchFocus;
+
+        self.input_mode == InputMode::Search(SearchFocus::Typing)
+            && (self.search_query.is_empty() || self.search_history.is_browsing())
+    }
+
+    /// Selects the visible project named `name`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the project is in `filtered_projects` and is now selected;
+    /// `false` leaves the selection unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query(String::new());
+    ///
+    /// assert!(state.select_by_name("web"));
+    /// assert_eq!(state.selected_project().unwrap().name, "web");
+    ///
+    /// assert!(!state.select_by_name("missing"));
+    /// assert_eq!(state.selected_project().unwrap().name, "web");
+    /// ```
+    pub fn select_by_name(&mut self, name: &str) -> bool {
+        let Some(index) = self.filtered_projects.iter().position(|p| p.name == name) else {
+            tracing::debug!(project_name = %name, "project to select not visible");
+            return false;
+        };
+
+        self.selected_index = index;
+        true
+    }
+
+    /// Returns whether a worker-reported project hash differs from the current
+    /// project list, meaning the full list must be fetched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::domain::project::content_hash;
+    /// use zessionizer::{AppState, Project, Theme};
+    ///
+    /// let state = AppState::new(vec![], Theme::default());
+    /// assert!(!state.needs_projects(content_hash(&[])));
+    ///
+    /// let projects = vec![Project::new("/code/api".to_string(), "api".to_string())];
+    /// assert!(state.needs_projects(content_hash(&projects)));
+    /// let state = AppState::new(projects.clone(), Theme::default());
+    /// assert!(!state.needs_projects(content_hash(&projects)));
+    /// ```
+    ///
+    /// A hash-only response fetches the full list only when it differs:
+    ///
+    /// ```rust
+    /// use zessionizer::domain::project::content_hash;
+    /// use zessionizer::worker::{WorkerMessage, WorkerResponse};
+    /// use zessionizer::{handle_event, Action, AppState, Event, Project, Theme};
+    ///
+    /// let projects = vec![Project::new("/code/api".to_string(), "api".to_string())];
+    /// let mut state = AppState::new(projects.clone(), Theme::default());
+    /// let hash_response = |hash| Event::WorkerResponse(WorkerResponse::ProjectsHash { hash, changed: true });
+    ///
+    /// let (_, actions) = handle_event(&mut state, &hash_response(content_hash(&projects))).unwrap();
+    /// assert!(actions.is_empty());
+    ///
+    /// let (_, actions) = handle_event(&mut state, &hash_response(content_hash(&[]))).unwrap();
+    /// assert!(matches!(actions.as_slice(), [Action::PostToWorker(WorkerMessage::FetchProjects { .. })]));
+    /// ```
+    #[must_use]
+    pub fn needs_projects(&self, hash: u64) -> bool {
+        crate::domain::project::content_hash(&self.projects) != hash
+    }
+
+    /// Moves the selection to the pending project path, if one is waiting.
+    ///
+    /// Looks up the path in `filtered_projects`, so the restored selection is
+    /// correct even if frecency ordering changed since the path was saved. The
+    /// pending path is consumed once visible projects exist, whether or not it
+    /// was found, so later reloads never move the cursor unexpectedly.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `selected_index` changed.
+    pub fn restore_selection(&mut self) -> bool {
+        if self.filtered_projects.is_empty() {
+            return false;
+        }
+
+        let Some(path) = self.pending_selection.take() else {
+            return false;
+        };
+
+        match self.filtered_projects.iter().position(|p| p.path == path) {
+            Some(index) if index != self.selected_index => {
+                tracing::debug!(path = %path, index = index, "restored previous selection");
+                self.selected_index = index;
+                true
+            }
+            Some(_) => false,
+            None => {
+                tracing::debug!(path = %path, "previous selection not visible, keeping cursor");
+                false
+            }
+        }
+    }
+
+    /// Removes a project from the master list and re-applies filters.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a project with `path` was present.
+    pub fn remove_project(&mut self, path: &str) -> bool {
+        let before = self.projects.len();
+        self.projects.retain(|project| project.path != path);
+        if self.projects.len() == before {
+            return false;
+        }
+
+        tracing::debug!(path = %path, "project removed from list");
+        self.apply_search_filter();
+        true
+    }
+
+    /// Returns the message loading projects when the plugin starts: the first
+    /// page if `projects_page_size` is set, otherwise the whole list.
+    pub fn initial_projects_request(&mut self) -> WorkerMessage {
+        if self.projects_page_size == 0 {
+            return WorkerMessage::load_projects(false);
+        }
+
+        self.page_pending = true;
+        WorkerMessage::load_projects_page(0, self.projects_page_size)
+    }
+
+    /// Returns the message loading more projects, if the loaded ones run short.
+    ///
+    /// The next page is requested once the selection comes within half a page
+    /// of the end of the list. A search needs every project to match against,
+    /// so with a non-empty query all remaining projects are requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::worker::{WorkerMessage, WorkerResponse};
+    /// use zessionizer::{handle_event, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let page = |names: &[&str]| -> Vec<Project> {
+    ///     names.iter().map(|n| Project::new(format!("/code/{n}"), n.to_string())).collect()
+    /// };
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.projects_page_size = 4;
+    /// assert!(matches!(
+    ///     state.initial_projects_request(),
+    ///     WorkerMessage::LoadProjectsPage { offset: 0, limit: 4, .. }
+    /// ));
+    ///
+    /// let first = WorkerResponse::ProjectsPage { offset: 0, total: 6, projects: page(&["a", "b", "c", "d"]) };
+    /// handle_event(&mut state, &Event::WorkerResponse(first)).unwrap();
+    /// assert_eq!(state.projects_total, Some(6));
+    /// assert_eq!(state.next_page_request(), None);
+    ///
+    /// // Scrolling near the end asks for the next page, once.
+    /// let (_, actions) = handle_event(&mut state, &Event::KeyDown).unwrap();
+    /// assert!(actions.is_empty());
+    /// let (_, actions) = handle_event(&mut state, &Event::KeyDown).unwrap();
+    /// assert!(matches!(
+    ///     actions.as_slice(),
+    ///     [zessionizer::Action::PostToWorker(WorkerMessage::LoadProjectsPage { offset: 4, limit: 4, .. })]
+    /// ));
+    /// assert_eq!(state.next_page_request(), None);
+    ///
+    /// let last = WorkerResponse::ProjectsPage { offset: 4, total: 6, projects: page(&["e", "f"]) };
+    /// handle_event(&mut state, &Event::WorkerResponse(last)).unwrap();
+    /// assert_eq!(state.projects.len(), 6);
+    /// assert_eq!(state.projects_total, None);
+    /// assert_eq!(state.selected_index, 2);
+    /// ```
+    pub fn next_page_request(&mut self) -> Option<WorkerMessage> {
+        let total = self.projects_total?;
+        if self.page_pending || self.projects_page_size == 0 {
+            return None;
+        }
+
+        let loaded = self.projects.len();
+        let remaining = total.saturating_sub(loaded);
+        let limit = if remaining == 0 {
+            return None;
+        } else if !self.search_query.trim().is_empty() {
+            remaining
+        } else if self.selected_index + self.projects_page_size / 2 >= self.filtered_projects.len() {
+            self.projects_page_size
+        } else {
+            return None;
+        };
+
+        tracing::debug!(offset = loaded, limit = limit, total = total, "requesting next project page");
+        self.page_pending = true;
+        Some(WorkerMessage::load_projects_page(loaded, limit))
+    }
+
+    /// Adds a page of projects loaded from `offset` to the master list.
+    ///
+    /// The first page replaces the list, keeping the selected project; later
+    /// pages append the projects not loaded yet.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the visible projects or the selection changed.
+    pub fn apply_projects_page(&mut self, offset: usize, total: usize, page: &[Project]) -> bool {
+        self.page_pending = false;
+        let old_filtered = self.filtered_projects.clone();
+
+        if offset == 0 {
+            if self.pending_selection.is_none() {
+                self.pending_selection = self.selected_project().map(|p| p.path.clone());
+            }
+            self.projects = page.to_vec();
+        } else {
+            let loaded: HashSet<String> = self.projects.iter().map(|p| p.path.clone()).collect();
+            self.projects
+                .extend(page.iter().filter(|project| !loaded.contains(&project.path)).cloned());
+        }
+
+        self.projects_total = (total > self.projects.len()).then_some(total);
+        tracing::debug!(offset = offset, loaded = self.projects.len(), total = total, "project page applied");
+
+        self.apply_search_filter();
+        let restored = self.restore_selection();
+        restored || self.filtered_projects != old_filtered
+    }
+
+    /// Returns whether killing `session` must be confirmed first.
+    ///
+    /// Confirmation is needed when a threshold is configured, the session has
+    /// more panes than the threshold (or an unknown pane count), and the
+    /// session is not already awaiting confirmation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// state.session_panes.insert("api".to_string(), 3);
+    /// state.session_panes.insert("scratch".to_string(), 1);
+    /// assert!(!state.needs_kill_confirmation("api"));
+    ///
+    /// state.kill_confirm_panes = Some(1);
+    /// assert!(state.needs_kill_confirmation("api"));
+    /// assert!(!state.needs_kill_confirmation("scratch"));
+    /// assert!(state.needs_kill_confirmation("unknown"));
+    ///
+    /// state.kill_confirm_panes = Some(3);
+    /// assert!(!state.needs_kill_confirmation("api"));
+    ///
+    /// state.kill_confirm_panes = Some(1);
+    ///
+    /// state.pending_kill = Some("api".to_string());
+    /// assert!(!state.needs_kill_confirmation("api"));
+    /// ```
+    #[must_use]
+    pub fn needs_kill_confirmation(&self, session: &str) -> bool {
+        let Some(threshold) = self.kill_confirm_panes else {
+            return false;
+        };
+
+        if self.pending_kill.as_deref() == Some(session) {
+            return false;
+        }
+
+        self.session_panes
+            .get(session)
+            .map_or(true, |&panes| panes > threshold)
+    }
+
+    /// Returns `path` as shown in the PATH column, before truncation.
+    ///
+    /// Stored paths are absolute, or relative to the home directory for
+    /// projects found under `~` scan paths. Paths matching no scan path, or
+    /// equal to one, are shown as stored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, PathDisplay, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// state.scan_paths = ["~", "~/Projects", "~/Projects/work", "/opt/src"].map(String::from).to_vec();
+    ///
+    /// assert_eq!(state.display_path("Projects/work/api"), "Projects/work/api");
+    ///
+    /// state.path_display = PathDisplay::HomeTilde;
+    /// assert_eq!(state.display_path("Projects/work/api"), "~/Projects/work/api");
+    /// assert_eq!(state.display_path("./notes"), "~/notes");
+    /// assert_eq!(state.display_path("/opt/src/web"), "/opt/src/web");
+    ///
+    /// state.path_display = PathDisplay::RelativeToScanPath;
+    /// assert_eq!(state.display_path("Projects/work/api"), "api");
+    /// assert_eq!(state.display_path("Projects/cli"), "cli");
+    /// assert_eq!(state.display_path("./notes"), "notes");
+    /// assert_eq!(state.display_path("/opt/src/web"), "web");
+    /// assert_eq!(state.display_path("/srv/app"), "/srv/app");
+    /// assert_eq!(state.display_path("Projects"), "Projects");
+    /// ```
+    #[must_use]
+    pub fn display_path(&self, path: &str) -> String {
+        match self.path_display {
+            PathDisplay::Absolute => path.to_string(),
+            PathDisplay::HomeTilde if path.starts_with('/') => path.to_string(),
+            PathDisplay::HomeTilde => match path.strip_prefix("./").unwrap_or(path) {
+                "." => "~".to_string(),
+                relative => format!("~/{relative}"),
+            },
+            PathDisplay::RelativeToScanPath => self
+                .scan_paths
+                .iter()
+                .map(|scan_path| canonical_project_path(resolve_scan_root(scan_path)))
+                .filter_map(|root| Self::strip_scan_root(path, &root).map(|rest| (root.len(), rest)))
+                .max_by_key(|(root_len, _)| *root_len)
+                .map_or_else(|| path.to_string(), |(_, rest)| rest.to_string()),
+        }
+    }
+
+    /// Returns `path` relative to the scan root `root`, or `None` if it lies
+    /// outside it.
+    ///
+    /// The home root (`.`) contains every relative path.
+    fn strip_scan_root<'a>(path: &'a str, root: &str) -> Option<&'a str> {
+        if path.starts_with('/') != root.starts_with('/') {
+            return None;
+        }
+
+        let path = path.strip_prefix("./").unwrap_or(path);
+        if root == "." {
+            return Some(path).filter(|rest| *rest != ".");
+        }
+
+        let root = root.strip_prefix("./").unwrap_or(root);
+        path.strip_prefix(root)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .filter(|rest| !rest.is_empty())
+    }
+
+    /// Serializes the loaded projects with their frecency scores.
+    ///
+    /// Projects keep their list order; see [`ProjectsExport`](crate::storage::ProjectsExport)
+    /// for the format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::storage::ProjectsExport;
+    /// use zessionizer::{AppState, Project, Theme};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let state = AppState::new(projects, Theme::default());
+    ///
+    /// let export = ProjectsExport::from_json(&state.export_projects_json()).unwrap();
+    /// let paths: Vec<_> = export.projects.iter().map(|project| project.path.as_str()).collect();
+    /// assert_eq!(paths, ["/code/api", "/code/web"]);
+    /// assert!(export.projects.iter().all(|project| project.frecency > 0.0));
+    /// ```
+    #[must_use]
+    pub fn export_projects_json(&self) -> String {
+        crate::storage::ProjectsExport::from_projects(
+            &self.projects,
+            chrono::Utc::now().timestamp(),
+            self.frecency_half_life_hours,
+        )
+        .to_json()
+    }
+
+    /// Returns the active session of the selected project, if any.
+    #[must_use]
+    pub fn selected_session(&self) -> Option<&str> {
+        self.selected_project().and_then(|project| self.active_session(project))
+    }
+
+    /// Returns the sessions a kill applies to.
+    ///
+    /// The marked sessions that are still active, sorted by name, or the
+    /// selected row's session when none are marked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.active_sessions.extend(["api".to_string(), "web".to_string()]);
+    /// state.apply_search_filter();
+    /// state.select_by_name("web");
+    /// assert_eq!(state.kill_targets(), ["web"]);
+    ///
+    /// state.marked_sessions.extend(["web".to_string(), "api".to_string(), "gone".to_string()]);
+    /// assert_eq!(state.kill_targets(), ["api", "web"]);
+    /// ```
+    #[must_use]
+    pub fn kill_targets(&self) -> Vec<String> {
+        let mut marked: Vec<String> = self
+            .marked_sessions
+            .iter()
+            .filter(|name| self.active_sessions.contains(*name))
+            .cloned()
+            .collect();
+
+        if marked.is_empty() {
+            return self.selected_session().map(str::to_string).into_iter().collect();
+        }
+
+        marked.sort();
+        marked
+    }
+
+    /// Clears a pending kill confirmation.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a kill was awaiting confirmation.
+    pub fn cancel_pending_kill(&mut self) -> bool {
+        self.pending_kill.take().is_some()
+    }
+
+    /// Starts renaming `session`, pre-filling the input with its current name.
+    pub fn start_rename(&mut self, session: &str) {
+        self.rename_target = Some(session.to_string());
+        self.rename_buffer = session.to_string();
+        self.input_mode = InputMode::Rename;
+    }
+
+    /// Starts naming a new session for `project`, pre-filling the input with
+    /// the project name.
+    pub fn start_new_session(&mut self, project: &Project) {
+        self.new_session_path = Some(project.path.clone());
+        self.rename_buffer.clone_from(&project.name);
+        self.input_mode = InputMode::NewSession;
+    }
+
+    /// Leaves rename or new session mode without acting.
+    pub fn cancel_rename(&mut self) {
+        self.rename_target = None;
+        self.new_session_path = None;
+        self.rename_buffer.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Applies a session rename to the local state and leaves rename mode.
+    ///
+    /// Updates the active session set, current session, pane counts, and the
+    /// names of projects matching `old_name`, so the list reflects the rename
+    /// before Zellij reports it.
+    pub fn apply_session_rename(&mut self, old_name: &str, new_name: &str) {
+        if self.active_sessions.remove(old_name) {
+            self.active_sessions.insert(new_name.to_string());
+        }
+        if self.current_session.as_deref() == Some(old_name) {
+            self.current_session = Some(new_name.to_string());
+        }
+        if let Some(panes) = self.session_panes.remove(old_name) {
+            self.session_panes.insert(new_name.to_string(), panes);
+        }
+        if self.marked_sessions.remove(old_name) {
+            self.marked_sessions.insert(new_name.to_string());
         }
+        for project in self.projects.iter_mut().filter(|p| is_session_of(old_name, &p.name)) {
+            project.name = new_name.to_string();
+        }
+
+        tracing::debug!(old_name = %old_name, new_name = %new_name, "session renamed locally");
+        self.cancel_rename();
+        self.apply_search_filter();
     }
 
-    /// Returns a reference to the currently selected project, if any.
+    /// Optimistically marks stored sessions as active.
     ///
-    /// Returns `None` if the filtered projects list is empty or the selected index
-    /// is out of bounds (which should never occur due to clamping).
+    /// Avoids showing every session as inactive between startup and the first
+    /// `SessionUpdate`. Ignored once a real update has arrived, since live data
+    /// always wins; the first update then reconciles the seeded set.
     ///
     /// # Returns
     ///
-    /// - `Some(&Project)` if a project is selected
-    /// - `None` if no projects are visible
+    /// `true` if `active_sessions` changed.
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use crate::app::AppState;
-    /// # use crate::ui::theme::Theme;
-    /// # let mut state = AppState::new(vec![], Theme::default());
-    /// if let Some(project) = state.selected_project() {
-    ///     println!("Selected: {}", project.name);
-    /// }
+    /// use zessionizer::{AppState, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// assert!(state.seed_sessions(&["api".to_string()]));
+    /// assert!(state.active_sessions.contains("api"));
     /// ```
-    #[must_use]
-    pub fn selected_project(&self) -> Option<&Project> {
-        self.filtered_projects.get(self.selected_index)
+    pub fn seed_sessions(&mut self, names: &[String]) -> bool {
+        if self.sessions_received {
+            tracing::debug!("live sessions already received, ignoring stored sessions");
+            return false;
+        }
+
+        let seeded: HashSet<String> = names.iter().cloned().collect();
+        if seeded == self.active_sessions {
+            return false;
+        }
+
+        tracing::debug!(count = seeded.len(), "seeding active sessions from storage");
+        self.active_sessions = seeded;
+        self.apply_search_filter();
+        self.restore_selection();
+        true
     }
 
     /// Applies view mode and search filters to the master project list.
@@ -222,10 +1311,19 @@ impl AppState {
     ///
     /// # Filtering Algorithm
     ///
-    /// 1. **View Mode Filter**: Include only projects with/without active sessions
+    /// 1. **View Mode Filter**: Include only projects with/without active sessions,
+    ///    leaving out the current session in the Sessions view if `hide_current_session`
     /// 2. **Search Query Tokenization**: Split query by whitespace, lowercase
-    /// 3. **Token Matching**: Require all tokens to appear in project name (substring)
-    /// 4. **Index Clamping**: Adjust selection to remain within bounds
+    /// 3. **Tag Matching**: Require every `#tag` token to prefix one of the project's
+    ///    tags (see [`Project::has_tag_prefix`])
+    /// 4. **Token Matching**: Require every other token to fuzzy-match a field in
+    ///    `search_scope` (the name, the path, or either)
+    /// 5. **Sorting**: Order by `sort_mode` (see [`Self::sort_filtered`]); with
+    ///    search tokens, stable-sort by match score first, summing each token's
+    ///    best score across fields, so `sort_mode` only breaks ties
+    /// 6. **Session Grouping**: Stable-partition active sessions first if `sessions_first`
+    /// 7. **Current Session**: Move it to the top of the Sessions view if `current_session_first`
+    /// 8. **Index Clamping**: Adjust selection to remain within bounds
     ///
     /// # Tracing
     ///
@@ -240,6 +1338,87 @@ impl AppState {
     /// state.search_query = "my-proj".to_string();
     /// state.apply_search_filter();
     /// ```
+    ///
+    /// Path-only queries need a scope that includes the path:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, SearchScope, Theme, ViewMode};
+    ///
+    /// let projects = vec![Project::new("/home/user/code/backend/svc".to_string(), "svc".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    ///
+    /// state.set_search_query("code/backend".to_string());
+    /// assert!(state.filtered_projects.is_empty());
+    ///
+    /// for scope in [SearchScope::Path, SearchScope::NameAndPath] {
+    ///     state.search_scope = scope;
+    ///     state.apply_search_filter();
+    ///     assert_eq!(state.filtered_projects.len(), 1);
+    /// }
+    /// ```
+    ///
+    /// The current session can be hidden from the Sessions view, keeping the
+    /// selection in bounds:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::Sessions;
+    /// state.active_sessions = ["api", "web"].into_iter().map(String::from).collect();
+    /// state.current_session = Some("web".to_string());
+    /// let names = |state: &AppState| -> Vec<String> {
+    ///     state.filtered_projects.iter().map(|p| p.name.clone()).collect()
+    /// };
+    ///
+    /// state.apply_search_filter();
+    /// assert_eq!(names(&state), ["api", "web"]);
+    /// state.selected_index = 1;
+    ///
+    /// state.hide_current_session = true;
+    /// state.apply_search_filter();
+    /// assert_eq!(names(&state), ["api"]);
+    /// assert_eq!(state.selected_index, 0);
+    ///
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// assert_eq!(names(&state), ["api", "web"]);
+    /// ```
+    ///
+    /// Search results are ranked by match quality, tight matches first:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/p-r-o-j-random".to_string(), "p-r-o-j-random".to_string()),
+    ///     Project::new("/code/project".to_string(), "project".to_string()),
+    ///     Project::new("/code/notes".to_string(), "notes".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// let names = |state: &AppState| -> Vec<String> {
+    ///     state.filtered_projects.iter().map(|p| p.name.clone()).collect()
+    /// };
+    ///
+    /// state.set_search_query("proj".to_string());
+    /// assert_eq!(names(&state), ["project", "p-r-o-j-random"]);
+    ///
+    /// // Without a query, the frecency order is kept.
+    /// state.set_search_query(String::new());
+    /// assert_eq!(names(&state), ["p-r-o-j-random", "project", "notes"]);
+    /// ```
+    ///
+    /// `#tag` tokens filter by the tags from `.zessionizer` marker files,
+    /// matching tag prefixes case-insensitively, and combine with text tokens.
+    ///
+    /// With `sessions_first`, active sessions lead the All view, each group
+    /// keeping its frecency order (the worker's order).
     pub fn apply_search_filter(&mut self) {
         use fuzzy_matcher::FuzzyMatcher;
 
@@ -249,14 +1428,11 @@ impl AppState {
             view_mode = ?self.view_mode
         ).entered();
 
-        let tokens: Vec<String> = if self.search_query.is_empty() {
-            vec![]
-        } else {
-            self.search_query
-                .split_whitespace()
-                .map(str::to_lowercase)
-                .collect()
-        };
+        let (tag_tokens, tokens): (Vec<String>, Vec<String>) = self
+            .search_query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .partition(|token| tag_query(token).is_some());
 
         let matcher = if tokens.is_empty() {
             None
@@ -264,23 +1440,70 @@ impl AppState {
             Some(SkimMatcherV2::default())
         };
 
-        let filtered_iter = self.projects.iter().filter(|project| {
+        let filtered_iter = self.projects.iter().filter_map(|project| {
             let passes_view_mode = match self.view_mode {
-                ViewMode::Sessions => self.active_sessions.contains(&project.name),
-                ViewMode::ProjectsWithoutSessions => !self.active_sessions.contains(&project.name),
+                ViewMode::Sessions => {
+                    self.has_session(project) && !(self.hide_current_session && self.is_current_session(project))
+                }
+                ViewMode::ProjectsWithoutSessions => !self.has_session(project),
+                ViewMode::All => true,
             };
 
             if !passes_view_mode {
-                return false;
+                return None;
             }
 
-            matcher.as_ref().map_or(true, |m| {
-                let name_lower = project.name.to_lowercase();
-                tokens.iter().all(|token| m.fuzzy_match(&name_lower, token).is_some())
-            })
+            if !tag_tokens
+                .iter()
+                .filter_map(|token| tag_query(token))
+                .all(|tag| project.has_tag_prefix(tag))
+            {
+                return None;
+            }
+
+            let Some(m) = matcher.as_ref() else {
+                return Some((project, 0));
+            };
+            let name_lower = self.search_scope.matches_name().then(|| project.name.to_lowercase());
+            let path_lower = self.search_scope.matches_path().then(|| project.path.to_lowercase());
+            let score = tokens.iter().try_fold(0, |total: i64, token| {
+                [&name_lower, &path_lower]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|field| m.fuzzy_match(field, token))
+                    .max()
+                    .map(|best| total.saturating_add(best))
+            })?;
+            Some((project, score))
         });
 
-        self.filtered_projects = filtered_iter.cloned().collect();
+        let scored_projects: Vec<(&Project, i64)> = filtered_iter.collect();
+        let match_scores: HashMap<String, i64> = if matcher.is_some() {
+            scored_projects.iter().map(|(project, score)| (project.path.clone(), *score)).collect()
+        } else {
+            HashMap::new()
+        };
+        self.filtered_projects = scored_projects.into_iter().map(|(project, _)| project.clone()).collect();
+        self.sort_filtered();
+
+        if !match_scores.is_empty() {
+            self.filtered_projects.sort_by_key(|project| {
+                std::cmp::Reverse(match_scores.get(&project.path).copied().unwrap_or(0))
+            });
+        }
+
+        if self.sessions_first {
+            let active_sessions = &self.active_sessions;
+            self.filtered_projects
+                .sort_by_key(|project| find_session(active_sessions, &project.name).is_none());
+        }
+
+        if self.current_session_first && self.view_mode == ViewMode::Sessions {
+            if let Some(current) = &self.current_session {
+                self.filtered_projects
+                    .sort_by_key(|project| !is_session_of(current, &project.name));
+            }
+        }
 
         if self.filtered_projects.is_empty() {
             self.selected_index = 0;
@@ -294,6 +1517,29 @@ impl AppState {
         );
     }
 
+    /// Sorts `filtered_projects` by the current sort mode.
+    ///
+    /// Projects arrive in frecency order from the worker (see
+    /// [`sort_by_frecency`](crate::storage::sort_by_frecency)), so frecency mode
+    /// keeps that order. Other modes sort stably, keeping pinned projects first
+    /// and frecency order among ties.
+    fn sort_filtered(&mut self) {
+        use std::cmp::Reverse;
+
+        match self.sort_mode {
+            SortMode::Frecency => {}
+            SortMode::Alphabetical => self
+                .filtered_projects
+                .sort_by_cached_key(|p| (!p.pinned, p.name.to_lowercase())),
+            SortMode::RecentlyAccessed => self
+                .filtered_projects
+                .sort_by_key(|p| (!p.pinned, Reverse(p.last_accessed))),
+            SortMode::MostFrequent => self
+                .filtered_projects
+                .sort_by_key(|p| (!p.pinned, Reverse(p.access_count))),
+        }
+    }
+
     /// Computes a renderable UI view model from current state and terminal dimensions.
     ///
     /// Transforms application state into a structured representation optimized for
@@ -317,24 +1563,269 @@ impl AppState {
     /// 3. Adjust window if near start/end to maximize visible items
     /// 4. Compute relative selection index within visible window
     ///
+    /// If the dimensions leave no room for list rows (see [`Self::can_render`]),
+    /// a degenerate view model without display items is returned. When there
+    /// is room but nothing to list, `empty_state` explains why.
+    ///
     /// # Example
     ///
+    /// Zero and tiny dimensions yield a degenerate view model instead of
+    /// panicking:
+    ///
     /// ```rust
-    /// # use crate::app::AppState;
-    /// # use crate::ui::theme::Theme;
-    /// # let state = AppState::new(vec![], Theme::default());
+    /// use zessionizer::{AppState, Theme};
+    ///
+    /// let state = AppState::new(vec![], Theme::default());
+    /// assert!(state.compute_viewmodel(0, 0).empty_state.is_none());
+    /// assert!(state.compute_viewmodel(24, 80).empty_state.is_some());
+    /// ```
+    ///
+    /// Empty states depend on the search query, the project list and the view:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let message = |state: &AppState| state.compute_viewmodel(24, 80).empty_state.unwrap().message;
+    ///
+    /// let state = AppState::new(vec![], Theme::default());
+    /// assert_eq!(message(&state), "No projects found — check scan_paths");
+    ///
+    /// let projects = vec![Project::new("/code/api".to_string(), "api".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.apply_search_filter();
+    /// assert_eq!(message(&state), "No active sessions");
+    ///
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query("zzz".to_string());
+    /// assert_eq!(message(&state), "No matches for 'zzz'");
+    ///
+    /// state.set_search_query(String::new());
+    /// assert!(state.compute_viewmodel(24, 80).empty_state.is_none());
+    /// ```
+    ///
+    /// Each empty state carries its kind and suggests a different action:
+    ///
+    /// ```rust
+    /// use zessionizer::ui::EmptyStateKind;
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let empty = |state: &AppState| {
+    ///     let empty = state.compute_viewmodel(24, 80).empty_state.unwrap();
+    ///     (empty.kind, empty.subtitle)
+    /// };
+    ///
+    /// // No projects at all, even while searching: scanning is the fix.
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// state.set_search_query("api".to_string());
+    /// let (kind, subtitle) = empty(&state);
+    /// assert_eq!(kind, EmptyStateKind::NoProjects);
+    /// assert!(subtitle.contains("scan"));
+    ///
+    /// // Projects, but none in the Sessions view.
+    /// let projects = vec![Project::new("/code/api".to_string(), "api".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::Sessions;
+    /// state.apply_search_filter();
+    /// let (kind, subtitle) = empty(&state);
+    /// assert_eq!(kind, EmptyStateKind::NoViewMatches);
+    /// assert_eq!(subtitle, "Press 'n' to browse projects");
+    ///
+    /// // Projects in view, but the query matches none of them.
+    /// state.view_mode = ViewMode::All;
+    /// state.set_search_query("zzz".to_string());
+    /// let (kind, subtitle) = empty(&state);
+    /// assert_eq!(kind, EmptyStateKind::NoSearchMatches);
+    /// assert!(subtitle.contains("Change the query"));
+    /// ```
+    ///
+    /// Display items carry the visit count and last-accessed time; narrow
+    /// terminals drop the VISITS and TIME columns:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let mut project = Project::new("/code/api".to_string(), "api".to_string());
+    /// project.last_accessed -= 3 * 60 * 60;
+    /// let mut state = AppState::new(vec![project], Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
     /// let viewmodel = state.compute_viewmodel(24, 80);
+    /// assert!(viewmodel.show_time);
+    /// assert_eq!(viewmodel.display_items[0].time_ago, "3h ago");
+    /// assert_eq!(viewmodel.display_items[0].access_count, 1);
+    /// assert!(!state.compute_viewmodel(24, 50).show_time);
+    /// ```
+    ///
+    /// Lists longer than the window carry scroll hints:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = (0..30)
+    ///     .map(|i| Project::new(format!("/code/p{i}"), format!("p{i}")))
+    ///     .collect();
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// // 24 rows leave 18 for the list.
+    /// let info = state.compute_viewmodel(24, 80).scroll_info.unwrap();
+    /// assert_eq!(info.above, None);
+    /// assert_eq!(info.below.as_deref(), Some("↓ 12 more"));
+    ///
+    /// state.selected_index = 15;
+    /// let info = state.compute_viewmodel(24, 80).scroll_info.unwrap();
+    /// assert_eq!(info.above.as_deref(), Some("↑ 6 more"));
+    /// assert_eq!(info.below.as_deref(), Some("↓ 6 more"));
+    ///
+    /// assert!(state.compute_viewmodel(40, 80).scroll_info.is_none());
+    /// ```
+    ///
+    /// The compact layout drops the blank top line and the two borders, giving
+    /// the list three more rows at the same height:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, InputMode, LayoutDensity, Project, SearchFocus, Theme, ViewMode};
+    ///
+    /// let projects = (0..30)
+    ///     .map(|i| Project::new(format!("/code/p{i}"), format!("p{i}")))
+    ///     .collect();
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// let list_rows = |state: &AppState| state.compute_viewmodel(24, 80).display_items.len();
+    ///
+    /// assert_eq!(list_rows(&state), 18);
+    /// state.input_mode = InputMode::Search(SearchFocus::Typing);
+    /// assert_eq!(list_rows(&state), 15);
+    ///
+    /// state.layout_density = LayoutDensity::Compact;
+    /// assert_eq!(list_rows(&state), 18);
+    /// state.input_mode = InputMode::Normal;
+    /// assert_eq!(list_rows(&state), 21);
+    /// ```
+    ///
+    /// With a search scope including paths, path matches are highlighted too,
+    /// offset to the truncated path shown:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, SearchScope, Theme, ViewMode};
+    ///
+    /// let path = "/srv/projects/clients/acme/backend/api-gateway";
+    /// let projects = vec![Project::new(path.to_string(), "gw".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.search_scope = SearchScope::Path;
+    /// state.set_search_query("srv gateway".to_string());
+    /// let highlighted = |path: &str, (start, end): (usize, usize)| -> String {
+    ///     path.chars().skip(start).take(end - start).collect()
+    /// };
+    ///
+    /// let item = &state.compute_viewmodel(24, 120).display_items[0];
+    /// assert_eq!(item.path, path);
+    /// assert_eq!(item.path_highlight_ranges, [(1, 4), (39, 46)]);
+    /// assert!(item.highlight_ranges.is_empty());
+    ///
+    /// // 80 columns leave 23 for the path: "..." and its last 20 characters.
+    /// // "srv" is cut off and "gateway" moves left by the 26 characters
+    /// // dropped, less the 3 of "...".
+    /// let item = &state.compute_viewmodel(24, 80).display_items[0];
+    /// assert_eq!(item.path, ".../backend/api-gateway");
+    /// assert_eq!(item.path_highlight_ranges, [(16, 23)]);
+    /// assert_eq!(highlighted(&item.path, item.path_highlight_ranges[0]), "gateway");
+    /// ```
+    ///
+    /// Long names are cut at character boundaries, so multibyte names never
+    /// panic.
+    ///
+    /// Highlight ranges index characters, not bytes, so they line up with
+    /// names containing multibyte characters:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![Project::new("/code/cafe".to_string(), "café-app".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// let highlighted = |state: &AppState| -> Vec<String> {
+    ///     let item = &state.compute_viewmodel(24, 80).display_items[0];
+    ///     item.highlight_ranges
+    ///         .iter()
+    ///         .map(|&(start, end)| item.name.chars().skip(start).take(end - start).collect())
+    ///         .collect()
+    /// };
+    ///
+    /// state.set_search_query("caf".to_string());
+    /// assert_eq!(highlighted(&state), ["caf"]);
+    ///
+    /// // "app" starts at character 5, byte 6.
+    /// state.set_search_query("app".to_string());
+    /// assert_eq!(state.compute_viewmodel(24, 80).display_items[0].highlight_ranges, [(5, 8)]);
+    /// assert_eq!(highlighted(&state), ["app"]);
+    /// ```
+    ///
+    /// Session names are highlighted too, in any input mode, with the same
+    /// matching as the filter.
+    ///
+    /// Paths are cut by character from the left, keeping the trailing
+    /// segments, and tiny widths fall back to an ellipsis:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Project, Theme, ViewMode};
+    ///
+    /// let path = "/srv/josé/répertoires/éléments/données/projet-été";
+    /// let projects = vec![Project::new(path.to_string(), "projet".to_string())];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    /// // Below 60 columns the path gets what the 37-wide NAME column and a
+    /// // 2-column margin leave.
+    /// let shown = |width: usize| state.compute_viewmodel(24, 39 + width).display_items[0].path.clone();
+    ///
+    /// assert_eq!(shown(13), "...projet-été");
+    /// assert_eq!(shown(5), "...té");
+    /// assert_eq!(shown(4), "...é");
+    /// assert_eq!(shown(3), "…");
+    /// assert_eq!(shown(1), "…");
+    /// assert_eq!(shown(0), "");
     /// ```
+    ///
+    /// With `footer_show_counts`, the footer carries `[visible/total]` counts,
+    /// also while a footer message replaces the keybindings.
     #[must_use]
     pub fn compute_viewmodel(&self, rows: usize, cols: usize) -> crate::ui::viewmodel::UIViewModel {
-        if self.projects.is_empty() || self.filtered_projects.is_empty() {
+        if let Some(picker) = &self.layout_picker {
+            return self.compute_layout_picker_viewmodel(picker, rows);
+        }
+
+        let empty_kind = if self.projects.is_empty() {
+            Some(EmptyStateKind::NoProjects)
+        } else if self.filtered_projects.is_empty() && self.search_query.trim().is_empty() {
+            Some(EmptyStateKind::NoViewMatches)
+        } else if self.filtered_projects.is_empty() {
+            Some(EmptyStateKind::NoSearchMatches)
+        } else {
+            None
+        };
+
+        if empty_kind.is_some() || !self.can_render(rows, cols) {
+            let empty_state = empty_kind
+                .filter(|_| self.can_render(rows, cols))
+                .map(|kind| self.compute_empty_state(kind));
+
             return crate::ui::viewmodel::UIViewModel {
                 display_items: vec![],
                 selected_index: 0,
                 header: self.compute_header(),
                 footer: self.compute_footer(),
-                empty_state: None,
+                empty_state,
                 search_bar: self.compute_search_bar(),
+                show_path: self.show_path,
+                show_time: Self::shows_time_column(cols),
+                column_separator: self.column_separator.clone(),
+                scroll_info: None,
+                layout_density: self.layout_density,
             };
         }
 
@@ -348,10 +1839,10 @@ impl AppState {
             visible_start = visible_end.saturating_sub(available_rows);
         }
 
-        let matcher = if matches!(self.input_mode, InputMode::Search(_)) && !self.search_query.is_empty() {
-            Some(SkimMatcherV2::default())
-        } else {
+        let matcher = if self.search_query.trim().is_empty() {
             None
+        } else {
+            Some(SkimMatcherV2::default())
         };
 
         let display_items: Vec<crate::ui::viewmodel::DisplayItem> = self.filtered_projects[visible_start..visible_end]
@@ -372,13 +1863,172 @@ impl AppState {
             footer: self.compute_footer(),
             empty_state: None,
             search_bar: self.compute_search_bar(),
+            show_path: self.show_path,
+            show_time: Self::shows_time_column(cols),
+            column_separator: self.column_separator.clone(),
+            scroll_info: crate::ui::viewmodel::ScrollInfo::new(
+                visible_start,
+                visible_end,
+                self.filtered_projects.len(),
+            ),
+            layout_density: self.layout_density,
+        }
+    }
+
+    /// Applies an event and returns its actions with the resulting view model.
+    ///
+    /// Combines [`handle_event`](crate::app::handle_event) and
+    /// [`Self::compute_viewmodel`] for driving the state through a sequence of
+    /// events, as the plugin does between key presses and renders. Errors from
+    /// the handler are logged and yield no actions, like in the plugin.
+    ///
+    /// # Example
+    ///
+    /// Searching for a project and opening it:
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use zessionizer::{Action, AppState, Event, Project, Theme, ViewMode};
+    ///
+    /// let projects = vec![
+    ///     Project::new("/code/api".to_string(), "api".to_string()),
+    ///     Project::new("/code/web".to_string(), "web".to_string()),
+    /// ];
+    /// let mut state = AppState::new(projects, Theme::default());
+    /// state.view_mode = ViewMode::All;
+    /// state.apply_search_filter();
+    ///
+    /// state.step(&Event::SearchMode, 24, 80);
+    /// for c in "we".chars() {
+    ///     state.step(&Event::Char(c), 24, 80);
+    /// }
+    /// let (_, viewmodel) = state.step(&Event::FocusResults, 24, 80);
+    /// assert_eq!(viewmodel.display_items.len(), 1);
+    /// assert_eq!(viewmodel.display_items[0].name, "web");
+    ///
+    /// let (actions, _) = state.step(&Event::SelectProject, 24, 80);
+    /// assert_eq!(
+    ///     actions.last(),
+    ///     Some(&Action::CreateSession {
+    ///         name: "web".to_string(),
+    ///         path: PathBuf::from("/code/web"),
+    ///         layout: None,
+    ///     })
+    /// );
+    /// ```
+    pub fn step(
+        &mut self,
+        event: &crate::app::Event,
+        rows: usize,
+        cols: usize,
+    ) -> (Vec<crate::app::Action>, crate::ui::viewmodel::UIViewModel) {
+        let (_, actions) = crate::app::handle_event(self, event).unwrap_or_else(|e| {
+            tracing::debug!(error = %e, "error handling event");
+            (false, vec![])
+        });
+        (actions, self.compute_viewmodel(rows, cols))
+    }
+
+    /// Computes the message shown in place of an empty project list.
+    ///
+    /// Without projects the subtitle points at scanning, for a search without
+    /// results at changing the query, and otherwise at switching views.
+    fn compute_empty_state(&self, kind: EmptyStateKind) -> crate::ui::viewmodel::EmptyState {
+        let (message, subtitle) = match kind {
+            EmptyStateKind::NoProjects => (
+                "No projects found — check scan_paths".to_string(),
+                "Projects appear here once a scan finds them",
+            ),
+            EmptyStateKind::NoSearchMatches => (
+                format!("No matches for '{}'", self.search_query.trim()),
+                "Change the query, or press ESC to exit search",
+            ),
+            EmptyStateKind::NoViewMatches => match self.view_mode {
+                ViewMode::Sessions if self.hide_current_session && self.current_session.is_some() => {
+                    ("No other active sessions".to_string(), "Press 'n' to browse projects")
+                }
+                ViewMode::Sessions => ("No active sessions".to_string(), "Press 'n' to browse projects"),
+                ViewMode::ProjectsWithoutSessions => {
+                    ("Every project has an active session".to_string(), "Press 's' to show sessions")
+                }
+                ViewMode::All => ("No projects to show".to_string(), "Press 'n' or 's' to switch views"),
+            },
+        };
+
+        crate::ui::viewmodel::EmptyState {
+            kind,
+            message,
+            subtitle: subtitle.to_string(),
+        }
+    }
+
+    /// Computes the view model for the layout picker overlay.
+    ///
+    /// Lists the picker entries in place of projects, marking the project's
+    /// stored layout like the current session.
+    fn compute_layout_picker_viewmodel(&self, picker: &LayoutPicker, rows: usize) -> crate::ui::viewmodel::UIViewModel {
+        let available_rows = self.calculate_available_rows(rows);
+        let visible_start = picker
+            .selected
+            .saturating_sub(available_rows / 2)
+            .min(picker.len().saturating_sub(available_rows));
+        let visible_end = (visible_start + available_rows).min(picker.len());
+
+        let stored = self
+            .projects
+            .iter()
+            .find(|project| project.path == picker.project_path)
+            .and_then(|project| project.layout.as_deref());
+
+        let display_items = (visible_start..visible_end)
+            .map(|index| crate::ui::viewmodel::DisplayItem {
+                name: picker.label(index).to_string(),
+                path: String::new(),
+                is_selected: index == picker.selected,
+                is_current_session: index > 0 && stored == Some(picker.label(index)),
+                is_pinned: false,
+                is_marked: false,
+                highlight_ranges: vec![],
+                path_highlight_ranges: vec![],
+                path_expanded: false,
+                time_ago: String::new(),
+                access_count: 0,
+            })
+            .collect();
+
+        let footer = self.footer_message.as_ref().map_or_else(
+            || "j/k: navigate  Enter: use layout  ESC: cancel".to_string(),
+            Clone::clone,
+        );
+
+        crate::ui::viewmodel::UIViewModel {
+            display_items,
+            selected_index: picker.selected - visible_start,
+            header: crate::ui::viewmodel::HeaderInfo {
+                title: format!(" Layout for {} ", picker.project_name),
+            },
+            footer: crate::ui::viewmodel::FooterInfo {
+                keybindings: footer,
+                counts: None,
+            },
+            empty_state: None,
+            search_bar: None,
+            show_path: false,
+            show_time: false,
+            column_separator: String::new(),
+            scroll_info: crate::ui::viewmodel::ScrollInfo::new(visible_start, visible_end, picker.len()),
+            layout_density: self.layout_density,
         }
     }
 
     /// Computes a display item for a single project within the visible window.
     ///
     /// Handles name truncation, path formatting with prefix stripping, fuzzy match
-    /// highlighting, and selection state marking.
+    /// highlighting (of the path too when the search scope includes it, offset
+    /// to its truncated form), and selection state marking. When `show_path` is off, the
+    /// path is left empty and the name may use the full terminal width. The
+    /// VISITS and TIME columns' width is reserved on terminals wide enough to
+    /// show them.
     ///
     /// # Parameters
     ///
@@ -392,87 +2042,195 @@ impl AppState {
     /// A [`DisplayItem`](crate::ui::viewmodel::DisplayItem) with formatted fields
     /// and highlight ranges.
     fn compute_display_item(&self, project: &Project, absolute_idx: usize, cols: usize, matcher: Option<&SkimMatcherV2>) -> crate::ui::viewmodel::DisplayItem {
-        const NAME_COLUMN_WIDTH: usize = 37;
+        use crate::ui::viewmodel::NAME_COLUMN_WIDTH;
         const SAFETY_MARGIN: usize = 2;
 
         let is_selected = absolute_idx == self.selected_index;
-        let is_current_session = self.current_session.as_ref().is_some_and(|current| current == &project.name);
-        let max_path_width = cols.saturating_sub(NAME_COLUMN_WIDTH + SAFETY_MARGIN);
+        let is_current_session = self.is_current_session(project);
+        let is_marked = self.view_mode == ViewMode::Sessions
+            && self
+                .active_session(project)
+                .is_some_and(|session| self.marked_sessions.contains(session));
+        let time_width = if Self::shows_time_column(cols) {
+            crate::ui::viewmodel::VISITS_COLUMN_WIDTH + crate::ui::viewmodel::TIME_COLUMN_WIDTH
+        } else {
+            0
+        };
+        let max_path_width =
+            cols.saturating_sub(crate::ui::viewmodel::path_column(&self.column_separator) + time_width + SAFETY_MARGIN);
+        let max_name_width = if self.show_path {
+            NAME_COLUMN_WIDTH - SAFETY_MARGIN
+        } else {
+            cols.saturating_sub(time_width + SAFETY_MARGIN).max(4)
+        };
 
-        let name = if project.name.len() > 35 {
-            format!("{}...", &project.name[..32])
+        let name = if project.name.chars().count() > max_name_width {
+            let kept: String = project.name.chars().take(max_name_width - 3).collect();
+            format!("{kept}...")
         } else {
             project.name.clone()
         };
 
-        let path = Self::format_display_path(&project.path, max_path_width);
+        let path_expanded = is_selected && self.expand_selected_path && self.show_path;
+        let (path, path_highlight_ranges) = if self.show_path {
+            let full_path = if path_expanded {
+                project.path.clone()
+            } else {
+                self.display_path(&project.path)
+            };
+            let path = if path_expanded {
+                full_path.clone()
+            } else {
+                Self::format_display_path(&full_path, max_path_width)
+            };
+            let ranges = matcher
+                .filter(|_| self.search_scope.matches_path())
+                .map_or_else(Vec::new, |m| {
+                    let ranges = self.compute_highlight_ranges(&full_path, m);
+                    Self::shift_truncated_ranges(ranges, full_path.chars().count(), path.chars().count())
+                });
+            (path, ranges)
+        } else {
+            (String::new(), Vec::new())
+        };
 
-        let highlight_ranges = matcher.map_or_else(Vec::new, |m| self.compute_highlight_ranges(&project.name, m));
+        let highlight_ranges = matcher
+            .filter(|_| self.search_scope.matches_name())
+            .map_or_else(Vec::new, |m| self.compute_highlight_ranges(&project.name, m));
 
         crate::ui::viewmodel::DisplayItem {
             name,
             path,
             is_selected,
             is_current_session,
+            is_pinned: project.pinned,
+            is_marked,
             highlight_ranges,
+            path_highlight_ranges,
+            path_expanded,
+            time_ago: project.time_ago(),
+            access_count: project.access_count,
         }
     }
 
+    /// Returns whether a terminal `cols` wide has room for the VISITS and TIME
+    /// columns.
+    const fn shows_time_column(cols: usize) -> bool {
+        cols >= crate::ui::viewmodel::TIME_COLUMN_MIN_COLS
+    }
+
     /// Computes character index ranges to highlight for fuzzy match visualization.
     ///
-    /// Uses the Skim fuzzy matcher to find matching character positions, then
-    /// coalesces consecutive indices into ranges for efficient highlighting.
+    /// Matches the same way as `apply_search_filter()`: the query is split into
+    /// lowercase tokens and each token is fuzzy-matched against the lowercased
+    /// text. Matched positions from all tokens are merged, then coalesced into
+    /// ranges. Applies in every view mode whenever a query is present.
     ///
     /// # Parameters
     ///
-    /// * `text` - Text to search within (typically project name)
+    /// * `text` - Text to search within (project name or displayed path)
     /// * `matcher` - Fuzzy matcher instance
     ///
     /// # Returns
     ///
-    /// A vector of `(start, end)` byte index ranges (exclusive end) representing
-    /// contiguous highlighted segments.
+    /// A vector of `(start, end)` character index ranges (exclusive end) into
+    /// `text`, representing contiguous highlighted segments. These are the
+    /// indices [`render_highlighted_text`](crate::ui::helpers::render_highlighted_text)
+    /// expects.
     ///
     /// # Algorithm
     ///
-    /// 1. Get fuzzy match indices from matcher
-    /// 2. Iterate through indices, tracking consecutive runs
-    /// 3. Emit a range when a gap is detected or at end
-    /// 4. Return accumulated ranges
+    /// 1. Collect fuzzy match indices for every query token
+    /// 2. Map them from the lowercased text back to characters of `text`, since
+    ///    lowercasing can change the character count (e.g. `İ` → `i̇`)
+    /// 3. Iterate through sorted indices, tracking consecutive runs
+    /// 4. Emit a range when a gap is detected or at end
+    /// 5. Cap to `max_highlight_ranges` via [`Self::cap_highlight_ranges`]
     fn compute_highlight_ranges(&self, text: &str, matcher: &SkimMatcherV2) -> Vec<(usize, usize)> {
         use fuzzy_matcher::FuzzyMatcher;
 
-        if let Some((_score, indices)) = matcher.fuzzy_indices(text, &self.search_query) {
-            let mut ranges = Vec::new();
-            let mut start = None;
-            let mut prev = None;
-
-            for &idx in &indices {
-                match (start, prev) {
-                    (None, _) => {
-                        start = Some(idx);
-                        prev = Some(idx);
-                    }
-                    (Some(_), Some(p)) if idx == p + 1 => {
-                        prev = Some(idx);
-                    }
-                    (Some(s), Some(p)) => {
-                        ranges.push((s, p + 1));
-                        start = Some(idx);
-                        prev = Some(idx);
-                    }
-                    _ => {}
+        let text_lower = text.to_lowercase();
+        let original_index: Vec<usize> = text
+            .chars()
+            .enumerate()
+            .flat_map(|(idx, c)| (0..c.to_lowercase().count()).map(move |_| idx))
+            .collect();
+
+        let indices: BTreeSet<usize> = self
+            .search_query
+            .split_whitespace()
+            .filter(|token| tag_query(token).is_none())
+            .filter_map(|token| matcher.fuzzy_indices(&text_lower, &token.to_lowercase()))
+            .flat_map(|(_score, indices)| indices)
+            .filter_map(|idx| original_index.get(idx).copied())
+            .collect();
+
+        let mut ranges = Vec::new();
+        let mut start = None;
+        let mut prev = None;
+
+        for &idx in &indices {
+            match (start, prev) {
+                (None, _) => {
+                    start = Some(idx);
+                    prev = Some(idx);
                 }
+                (Some(_), Some(p)) if idx == p + 1 => {
+                    prev = Some(idx);
+                }
+                (Some(s), Some(p)) => {
+                    ranges.push((s, p + 1));
+                    start = Some(idx);
+                    prev = Some(idx);
+                }
+                _ => {}
             }
+        }
 
-            if let (Some(s), Some(p)) = (start, prev) {
-                ranges.push((s, p + 1));
-            }
+        if let (Some(s), Some(p)) = (start, prev) {
+            ranges.push((s, p + 1));
+        }
 
-            ranges
-        } else {
-            vec![]
+        Self::cap_highlight_ranges(ranges, self.max_highlight_ranges)
+    }
+
+    /// Maps highlight ranges on a path onto its truncated form from
+    /// [`Self::format_display_path`].
+    ///
+    /// Truncation drops the start of the path and prepends `"..."`, so ranges
+    /// shift by the dropped length minus the prefix. Ranges inside the dropped
+    /// part are removed and ranges straddling it start after the prefix.
+    /// Paths cut to `"…"` or nothing keep no ranges.
+    fn shift_truncated_ranges(ranges: Vec<(usize, usize)>, full_len: usize, shown_len: usize) -> Vec<(usize, usize)> {
+        if shown_len == full_len {
+            return ranges;
         }
+        if shown_len <= 3 {
+            return Vec::new();
+        }
+
+        let dropped = full_len - (shown_len - 3);
+        ranges
+            .into_iter()
+            .filter(|&(_, end)| end > dropped)
+            .map(|(start, end)| (start.max(dropped) - dropped + 3, end - dropped + 3))
+            .collect()
+    }
+
+    /// Limits highlight ranges to the `max` longest runs, kept in text order.
+    ///
+    /// Longer contiguous runs are the strongest match signal, so they are kept
+    /// first (earlier runs win ties). The result is a subset of the sorted,
+    /// non-overlapping input, so it stays sorted and non-overlapping.
+    fn cap_highlight_ranges(mut ranges: Vec<(usize, usize)>, max: usize) -> Vec<(usize, usize)> {
+        if ranges.len() <= max {
+            return ranges;
+        }
+
+        ranges.sort_by(|a, b| (b.1 - b.0).cmp(&(a.1 - a.0)).then(a.0.cmp(&b.0)));
+        ranges.truncate(max);
+        ranges.sort_unstable();
+        ranges
     }
 
     /// Computes header information based on current view mode.
@@ -485,10 +2243,11 @@ impl AppState {
     fn compute_header(&self) -> crate::ui::viewmodel::HeaderInfo {
         let (view_name, count) = match self.view_mode {
             ViewMode::Sessions => ("Active Sessions", self.filtered_projects.len()),
-            ViewMode::ProjectsWithoutSessions => ("All Projects", self.filtered_projects.len()),
+            ViewMode::ProjectsWithoutSessions => ("Projects", self.filtered_projects.len()),
+            ViewMode::All => ("All Projects", self.filtered_projects.len()),
         };
         crate::ui::viewmodel::HeaderInfo {
-            title: format!(" {view_name} ({count}) "),
+            title: format!(" {view_name} ({count}) [{}] ", self.sort_mode.label()),
         }
     }
 
@@ -502,45 +2261,107 @@ impl AppState {
     fn compute_footer(&self) -> crate::ui::viewmodel::FooterInfo {
         use crate::app::modes::SearchFocus;
 
+        let counts = self
+            .footer_show_counts
+            .then(|| format!("[{}/{}]", self.filtered_projects.len(), self.projects.len()));
+
+        if self.pending_kill.is_some() && !self.marked_sessions.is_empty() {
+            return crate::ui::viewmodel::FooterInfo {
+                keybindings: format!(
+                    "Kill {} marked sessions?  K: confirm  any other key: cancel",
+                    self.kill_targets().len()
+                ),
+                counts,
+            };
+        }
+
+        if let Some(name) = &self.pending_kill {
+            let panes = self
+                .session_panes
+                .get(name)
+                .map_or_else(String::new, |count| format!(" ({count} panes)"));
+            return crate::ui::viewmodel::FooterInfo {
+                keybindings: format!("Kill session '{name}'{panes}?  K: confirm  any other key: cancel"),
+                counts,
+            };
+        }
+
+        if let Some(notice) = &self.footer_notice {
+            return crate::ui::viewmodel::FooterInfo {
+                keybindings: notice.clone(),
+                counts,
+            };
+        }
+
+        if let Some(message) = &self.footer_message {
+            return crate::ui::viewmodel::FooterInfo {
+                keybindings: message.clone(),
+                counts,
+            };
+        }
+
         let keybindings = match (self.input_mode, self.view_mode) {
-            (InputMode::Search(SearchFocus::Typing), _) => {
-                "ESC: exit search  Enter: select  Ctrl+n/p: navigate  Type to filter".to_string()
-            }
-            (InputMode::Search(SearchFocus::Navigating), _) => {
-                "ESC: exit search  /: edit query  j/k or Ctrl+n/p: navigate  Enter: select".to_string()
-            }
+            (InputMode::Search(SearchFocus::Typing), _) => format!(
+                "ESC: exit search  Enter: select  Ctrl+n/p: navigate  Tab: scope ({})  Type to filter",
+                self.search_scope.label()
+            ),
+            (InputMode::Search(SearchFocus::Navigating), _) => format!(
+                "ESC: exit search  /: edit query  j/k or Ctrl+n/p: navigate  Tab: scope ({})  Enter: select",
+                self.search_scope.label()
+            ),
+            (InputMode::Rename, _) => "Enter: rename session  ESC: cancel".to_string(),
+            (InputMode::NewSession, _) => "Enter: create session  ESC: cancel".to_string(),
             (InputMode::Normal, ViewMode::Sessions) => {
-                "j/k or Ctrl+n/p: navigate  /: search  n: new  K: kill  Enter: switch  q: quit".to_string()
+                "j/k or Ctrl+n/p: navigate  /: search  n: new  Space: mark  K: kill  r: rename  Enter: switch  q: quit".to_string()
             }
             (InputMode::Normal, ViewMode::ProjectsWithoutSessions) => {
-                "j/k or Ctrl+n/p: navigate  /: search  s: sessions  Enter: create  q: quit".to_string()
+                "j/k or Ctrl+n/p: navigate  /: search  s: sessions  Enter: create  N: create as  q: quit".to_string()
+            }
+            (InputMode::Normal, ViewMode::All) => {
+                "j/k or Ctrl+n/p: navigate  /: search  s: sessions  n: new  Enter: open  q: quit".to_string()
             }
         };
 
-        crate::ui::viewmodel::FooterInfo { keybindings }
+        crate::ui::viewmodel::FooterInfo { keybindings, counts }
     }
 
-    /// Computes search bar state if in search mode.
+    /// Computes search bar state if in search or rename mode.
     ///
-    /// Returns `Some` with current query if search mode is active, `None` otherwise.
+    /// Returns `Some` with the current query (or the session name while
+    /// renaming or naming a new session), `None` otherwise. The cursor is
+    /// shown while typing, at the end of session names.
     ///
     /// # Returns
     ///
     /// An optional [`SearchBarInfo`](crate::ui::viewmodel::SearchBarInfo) with query text.
     fn compute_search_bar(&self) -> Option<crate::ui::viewmodel::SearchBarInfo> {
-        if matches!(self.input_mode, InputMode::Search(_)) {
-            Some(crate::ui::viewmodel::SearchBarInfo {
+        use crate::app::modes::SearchFocus;
+
+        match self.input_mode {
+            InputMode::Search(focus) => Some(crate::ui::viewmodel::SearchBarInfo {
+                label: "Search".to_string(),
                 query: self.search_query.clone(),
-            })
-        } else {
-            None
+                cursor: (focus == SearchFocus::Typing).then(|| self.search_cursor()),
+            }),
+            InputMode::Rename => Some(crate::ui::viewmodel::SearchBarInfo {
+                label: "Rename".to_string(),
+                query: self.rename_buffer.clone(),
+                cursor: Some(self.rename_buffer.chars().count()),
+            }),
+            InputMode::NewSession => Some(crate::ui::viewmodel::SearchBarInfo {
+                label: "Session name".to_string(),
+                query: self.rename_buffer.clone(),
+                cursor: Some(self.rename_buffer.chars().count()),
+            }),
+            InputMode::Normal => None,
         }
     }
 
     /// Calculates available rows for project list after subtracting UI chrome.
     ///
-    /// Accounts for header (3 rows), footer (2 rows), borders (1 row), and
-    /// search bar (3 rows if active).
+    /// Accounts for the rows [`LayoutDensity::chrome_rows`] reserves: header,
+    /// footer, table headers, and search bar (3 rows if searching or
+    /// renaming), plus the blank top line and borders unless compact.
     ///
     /// # Parameters
     ///
@@ -550,14 +2371,60 @@ impl AppState {
     ///
     /// Number of rows available for project list display.
     const fn calculate_available_rows(&self, total_rows: usize) -> usize {
-        match self.input_mode {
-            InputMode::Normal => {
-                total_rows.saturating_sub(6)
-            }
-            InputMode::Search(_) => {
-                total_rows.saturating_sub(9)
-            }
-        }
+        let searching = !matches!(self.input_mode, InputMode::Normal);
+        total_rows.saturating_sub(self.layout_density.chrome_rows(searching))
+    }
+
+    /// Returns whether the given dimensions can fit the UI chrome plus at least
+    /// one list row, and are at least [`MIN_RENDER_ROWS`] by [`MIN_RENDER_COLS`].
+    ///
+    /// Zellij may render with zero or tiny dimensions during layout transitions
+    /// or in tiny panes; drawing the full UI then would garble the output, so
+    /// the renderer shows a short notice instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, Theme};
+    ///
+    /// let state = AppState::new(vec![], Theme::default());
+    /// assert!(!state.can_render(0, 0));
+    /// assert!(!state.can_render(1, 1));
+    /// assert!(state.can_render(24, 80));
+    /// ```
+    ///
+    /// Search mode needs more rows for the search bar:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, InputMode, SearchFocus, Theme};
+    /// use zessionizer::app::state::{MIN_RENDER_COLS, MIN_RENDER_ROWS};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// assert!(!state.can_render(MIN_RENDER_ROWS - 1, 80));
+    /// assert!(!state.can_render(24, MIN_RENDER_COLS - 1));
+    /// assert!(!state.can_render(24, 0));
+    /// assert!(!state.can_render(6, 80));
+    /// assert!(state.can_render(7, MIN_RENDER_COLS));
+    ///
+    /// state.input_mode = InputMode::Search(SearchFocus::Typing);
+    /// assert!(!state.can_render(7, 80));
+    /// assert!(state.can_render(10, 80));
+    /// ```
+    ///
+    /// Compact layouts fit in fewer rows:
+    ///
+    /// ```rust
+    /// use zessionizer::{AppState, InputMode, LayoutDensity, SearchFocus, Theme};
+    ///
+    /// let mut state = AppState::new(vec![], Theme::default());
+    /// state.layout_density = LayoutDensity::Compact;
+    /// state.input_mode = InputMode::Search(SearchFocus::Typing);
+    /// assert!(!state.can_render(6, 80));
+    /// assert!(state.can_render(7, 80));
+    /// ```
+    #[must_use]
+    pub const fn can_render(&self, rows: usize, cols: usize) -> bool {
+        rows >= MIN_RENDER_ROWS && cols >= MIN_RENDER_COLS && self.calculate_available_rows(rows) > 0
     }
 
     /// Formats a project path for display, stripping prefix and truncating if needed.
@@ -573,12 +2440,23 @@ impl AppState {
     /// # Returns
     ///
     /// A formatted path string, potentially with "..." prefix if truncated.
+    /// Widths are counted in characters, so the trailing segments are kept
+    /// intact for non-ASCII paths. Widths too small for the prefix yield `"…"`
+    /// (or nothing at zero width).
     fn format_display_path(path: &str, max_width: usize) -> String {
-        if path.len() > max_width {
-            let keep_chars = max_width.saturating_sub(3);
-            format!("...{}", &path[path.len() - keep_chars..])
-        } else {
-            path.to_string()
+        let path_len = path.chars().count();
+        if path_len <= max_width {
+            return path.to_string();
+        }
+
+        match max_width {
+            0 => String::new(),
+            1..=3 => "…".to_string(),
+            _ => {
+                let keep_chars = max_width - 3;
+                let tail: String = path.chars().skip(path_len - keep_chars).collect();
+                format!("...{tail}")
+            }
         }
     }
 }
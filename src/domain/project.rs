@@ -28,13 +28,25 @@ const SECONDS_PER_DAY: i64 = 86400;
 /// - `name`: Display name derived from the directory name
 /// - `last_accessed`: Unix timestamp of most recent access
 /// - `created_at`: Unix timestamp when the project was first added
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// - `pinned`: Whether the project always sorts above unpinned projects
+/// - `access_count`: Number of times the project has been opened
+/// - `layout`: Zellij layout name used when creating the project's session
+/// - `tags`: Lowercased tags from the project's `.zessionizer` marker file
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Project {
     pub id: Option<i64>,
     pub path: String,
     pub name: String,
     pub last_accessed: i64,
     pub created_at: i64,
+    #[serde(default)]
+    pub access_count: i32,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub layout: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Project {
@@ -65,9 +77,35 @@ impl Project {
             name,
             last_accessed: now,
             created_at: now,
+            access_count: 1,
+            pinned: false,
+            layout: None,
+            tags: Vec::new(),
         }
     }
 
+    /// Returns whether any of the project's tags starts with `prefix`, ignoring case.
+    ///
+    /// Prefix matching keeps a tag filter useful while it is still being typed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zessionizer::domain::Project;
+    ///
+    /// let mut project = Project::new("/code/api".to_string(), "api".to_string());
+    /// project.tags = vec!["work".to_string(), "rust".to_string()];
+    ///
+    /// assert!(project.has_tag_prefix("work"));
+    /// assert!(project.has_tag_prefix("Ru"));
+    /// assert!(!project.has_tag_prefix("personal"));
+    /// ```
+    #[must_use]
+    pub fn has_tag_prefix(&self, prefix: &str) -> bool {
+        let prefix = prefix.to_lowercase();
+        self.tags.iter().any(|tag| tag.starts_with(&prefix))
+    }
+
     /// Returns a human-readable string describing how long ago the project was accessed.
     ///
     /// The format varies based on the time elapsed:
@@ -112,3 +150,60 @@ impl Project {
         }
     }
 }
+
+/// Prefix marking a search token as a tag filter (e.g. `#work`).
+pub const TAG_QUERY_PREFIX: char = '#';
+
+/// Returns the tag a search token filters by, if it is a tag filter.
+///
+/// Tag filters start with [`TAG_QUERY_PREFIX`]; a lone `#` is searched for as
+/// text.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::domain::project::tag_query;
+///
+/// assert_eq!(tag_query("#work"), Some("work"));
+/// assert_eq!(tag_query("work"), None);
+/// assert_eq!(tag_query("#"), None);
+/// ```
+#[must_use]
+pub fn tag_query(token: &str) -> Option<&str> {
+    token
+        .strip_prefix(TAG_QUERY_PREFIX)
+        .filter(|tag| !tag.is_empty())
+}
+
+/// Computes a content hash of an ordered project list.
+///
+/// Used to detect whether the worker's project list differs from the one held
+/// by the main thread without sending the full list over IPC. Both threads run
+/// the same binary, so the hash is consistent between them.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::domain::project::{content_hash, Project};
+///
+/// let a = Project::new("/a".to_string(), "a".to_string());
+/// let b = Project::new("/b".to_string(), "b".to_string());
+/// let list = vec![a.clone(), b.clone()];
+/// assert_eq!(content_hash(&list), content_hash(&list.clone()));
+/// assert_ne!(content_hash(&list), content_hash(&[]));
+///
+/// // Order and every field count, so reorders and frecency updates are seen.
+/// assert_ne!(content_hash(&list), content_hash(&[b, a.clone()]));
+/// let mut visited = a.clone();
+/// visited.access_count += 1;
+/// assert_ne!(content_hash(&[a]), content_hash(&[visited]));
+/// ```
+#[must_use]
+pub fn content_hash(projects: &[Project]) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    projects.hash(&mut hasher);
+    hasher.finish()
+}
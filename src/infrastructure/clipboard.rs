@@ -0,0 +1,55 @@
+//! Host clipboard access via `run_command`.
+//!
+//! The plugin API has no clipboard call, so text is piped into the first
+//! clipboard tool available on the host (`pbcopy`, `wl-copy`, `xclip`,
+//! `xsel`). Commands are tagged with [`COMMAND_CONTEXT_KEY`] so their results
+//! are not mistaken for scan output.
+
+/// `run_command` context key identifying the command's purpose.
+pub const COMMAND_CONTEXT_KEY: &str = "zessionizer_command";
+
+/// Context value marking clipboard commands.
+pub const CLIPBOARD_CONTEXT: &str = "clipboard";
+
+/// Builds `sh` arguments that copy `text` to the host clipboard.
+///
+/// The text is passed as a positional argument, so it needs no quoting.
+#[must_use]
+pub fn copy_args(text: &str) -> Vec<String> {
+    const SCRIPT: &str = "for tool in pbcopy wl-copy 'xclip -selection clipboard' 'xsel --clipboard --input'; do \
+        set -- \"$1\" $tool; \
+        if command -v \"$2\" >/dev/null 2>&1; then shift; printf '%s' \"$text\" | \"$@\"; exit $?; fi; \
+        done; exit 1";
+
+    vec![
+        "sh".to_string(),
+        "-c".to_string(),
+        format!("text=\"$1\"; {SCRIPT}"),
+        "sh".to_string(),
+        text.to_string(),
+    ]
+}
+
+/// Quotes a value for POSIX shells if it contains anything but safe characters.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::clipboard::shell_quote;
+///
+/// assert_eq!(shell_quote("my-project"), "my-project");
+/// assert_eq!(shell_quote("it's here"), "'it'\\''s here'");
+/// ```
+#[must_use]
+pub fn shell_quote(value: &str) -> String {
+    let is_safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./~+:@%".contains(c));
+
+    if is_safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
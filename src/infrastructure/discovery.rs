@@ -0,0 +1,338 @@
+//! Interchangeable project discovery backends.
+//!
+//! Project markers can be found in three ways:
+//! - [`FindBackend`] runs `find` on the host via `run_command`, which needs the
+//!   `RunCommands` permission and delivers results asynchronously
+//! - [`FdBackend`] runs `fd` the same way; it is faster on large trees and
+//!   skips anything ignored by `.gitignore`
+//! - [`WalkBackend`] walks the host filesystem mounted at `/host` directly,
+//!   which only needs `FullHdAccess` and returns results immediately
+//!
+//! The `auto` backend starts with `find` and switches to the walk backend
+//! when `find` cannot run or the `RunCommands` permission is denied.
+//!
+//! All implement [`ScanBackend`] and report marker paths in the same form
+//! `find` prints them, so [`projects_from_scan`](super::scanner::projects_from_scan)
+//! yields identical projects whichever backend ran.
+
+use super::scanner::{self, ScanTarget};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `run_command` context key carrying a scan command's cycle generation.
+///
+/// See [`ScanBatch`](crate::app::scan_batch::ScanBatch).
+pub const SCAN_CYCLE_CONTEXT_KEY: &str = "zessionizer_scan_cycle";
+
+/// Exit code reported by the shell when a command cannot be found.
+const COMMAND_NOT_FOUND: i32 = 127;
+
+/// Result of starting a scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanOutput {
+    /// Command to run on the host; markers arrive as its standard output.
+    Command(Vec<String>),
+
+    /// Marker paths found synchronously.
+    Markers(Vec<String>),
+}
+
+/// A strategy for discovering project markers under a scan target.
+pub trait ScanBackend {
+    /// Short backend name for logging.
+    fn name(&self) -> &'static str;
+
+    /// Starts a scan of `target`.
+    fn scan(&self, target: &ScanTarget) -> ScanOutput;
+}
+
+/// Discovers projects by running `find` on the host.
+#[derive(Debug, Clone, Default)]
+pub struct FindBackend {
+    exclude_paths: Vec<String>,
+}
+
+impl FindBackend {
+    /// Creates a find backend pruning directories matching `exclude_paths`.
+    #[must_use]
+    pub fn new(exclude_paths: Vec<String>) -> Self {
+        Self { exclude_paths }
+    }
+}
+
+impl ScanBackend for FindBackend {
+    fn name(&self) -> &'static str {
+        "find"
+    }
+
+    fn scan(&self, target: &ScanTarget) -> ScanOutput {
+        ScanOutput::Command(scanner::find_args(target, &self.exclude_paths))
+    }
+}
+
+/// Discovers projects by running `fd` on the host.
+#[derive(Debug, Clone, Default)]
+pub struct FdBackend {
+    exclude_paths: Vec<String>,
+}
+
+impl FdBackend {
+    /// Creates an fd backend excluding directories matching `exclude_paths`.
+    #[must_use]
+    pub fn new(exclude_paths: Vec<String>) -> Self {
+        Self { exclude_paths }
+    }
+}
+
+impl ScanBackend for FdBackend {
+    fn name(&self) -> &'static str {
+        "fd"
+    }
+
+    fn scan(&self, target: &ScanTarget) -> ScanOutput {
+        ScanOutput::Command(scanner::fd_args(target, &self.exclude_paths))
+    }
+}
+
+/// Discovers projects by walking the host filesystem in-process.
+///
+/// Scan roots are read below `host_root` (the sandbox's `/host` mount):
+/// relative roots from `host_root` itself, and absolute roots from the host
+/// path they mirror (`/opt/code` is read from `/host/opt/code`). Markers are
+/// reported under the configured root, as `find` prints them. Symlinks are
+/// not followed and marker directories are not descended into, matching
+/// `find`'s default behavior. Directories matching an `exclude_paths` entry
+/// are skipped, like `find -prune`.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs;
+/// use zessionizer::infrastructure::discovery::{ScanBackend, ScanOutput, WalkBackend};
+/// use zessionizer::infrastructure::scanner::ScanTarget;
+///
+/// let host = tempfile::tempdir().unwrap();
+/// let root = host.path();
+/// fs::create_dir_all(root.join("Projects/api/.git/refs")).unwrap();
+/// fs::create_dir_all(root.join("Projects/web")).unwrap();
+/// fs::write(root.join("Projects/web/.zessionizer"), "").unwrap();
+/// fs::create_dir_all(root.join("Projects/wt")).unwrap();
+/// fs::write(root.join("Projects/wt/.git"), "gitdir: ../api/.git").unwrap();
+/// fs::create_dir_all(root.join("Projects/web/node_modules/dep/.git")).unwrap();
+/// fs::create_dir_all(root.join("Projects/deep/a/b/.git")).unwrap();
+/// fs::create_dir_all(root.join("opt/code/svc/.git")).unwrap();
+///
+/// let backend = WalkBackend::new(root).with_exclude_paths(&["node_modules".to_string()]);
+/// let scan = |dir: &str, depth| {
+///     let ScanOutput::Markers(mut markers) = backend.scan(&ScanTarget { dir: dir.to_string(), depth }) else {
+///         panic!("the walk backend finds markers synchronously");
+///     };
+///     markers.sort();
+///     markers
+/// };
+///
+/// // Excluded directories, marker contents and entries beyond the depth are skipped.
+/// assert_eq!(
+///     scan("Projects", 2),
+///     ["Projects/api/.git", "Projects/web/.zessionizer", "Projects/wt/.git"]
+/// );
+/// assert_eq!(scan("Projects", 4).len(), 4);
+///
+/// // Absolute roots are read below the host root and reported as configured.
+/// assert_eq!(scan("/opt/code", 2), ["/opt/code/svc/.git"]);
+/// assert_eq!(scan("/missing", 2), Vec::<String>::new());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WalkBackend {
+    host_root: PathBuf,
+    exclude_suffixes: Vec<String>,
+}
+
+impl WalkBackend {
+    /// Creates a walk backend reading relative roots from `host_root`.
+    #[must_use]
+    pub fn new(host_root: impl Into<PathBuf>) -> Self {
+        Self {
+            host_root: host_root.into(),
+            exclude_suffixes: Vec::new(),
+        }
+    }
+
+    /// Skips directories matching `exclude_paths` entries.
+    #[must_use]
+    pub fn with_exclude_paths(mut self, exclude_paths: &[String]) -> Self {
+        self.exclude_suffixes = exclude_paths
+            .iter()
+            .filter_map(|entry| scanner::exclude_pattern(entry))
+            .map(|pattern| pattern.trim_start_matches('*').to_string())
+            .collect();
+        self
+    }
+}
+
+impl Default for WalkBackend {
+    fn default() -> Self {
+        Self::new("/host")
+    }
+}
+
+impl ScanBackend for WalkBackend {
+    fn name(&self) -> &'static str {
+        "walk"
+    }
+
+    fn scan(&self, target: &ScanTarget) -> ScanOutput {
+        let dir = Path::new(&target.dir);
+        let fs_dir = self.host_root.join(dir.strip_prefix("/").unwrap_or(dir));
+
+        let mut markers = Vec::new();
+        self.walk_markers(&fs_dir, target.dir.trim_end_matches('/'), target.depth, &mut markers);
+        ScanOutput::Markers(markers)
+    }
+}
+
+/// Selects which discovery backend is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanBackendKind {
+    /// Use `find`, switching to the walk backend if `find` cannot run.
+    #[default]
+    Auto,
+
+    /// Always use `find`.
+    Find,
+
+    /// Use `fd`, switching to `find` if `fd` cannot run.
+    Fd,
+
+    /// Always walk the filesystem; `RunCommands` is not requested.
+    Walk,
+}
+
+impl ScanBackendKind {
+    /// Parses a backend name (`"auto"`, `"find"`, `"fd"` or `"walk"`), case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(ScanBackendKind)` if the name is recognized
+    /// - `None` otherwise
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "find" => Some(Self::Find),
+            "fd" => Some(Self::Fd),
+            "walk" => Some(Self::Walk),
+            _ => None,
+        }
+    }
+
+    /// Returns the backend to start with, pruning `exclude_paths`.
+    #[must_use]
+    pub fn backend(self, exclude_paths: &[String]) -> Box<dyn ScanBackend> {
+        match self {
+            Self::Auto | Self::Find => Box::new(FindBackend::new(exclude_paths.to_vec())),
+            Self::Fd => Box::new(FdBackend::new(exclude_paths.to_vec())),
+            Self::Walk => Box::new(WalkBackend::default().with_exclude_paths(exclude_paths)),
+        }
+    }
+}
+
+/// Returns the backend to switch to when `current` cannot run, if any.
+///
+/// `auto` moves from `find` to the walk backend, and `fd` falls back to
+/// `find`. Explicitly chosen `find` and `walk` backends are kept.
+#[must_use]
+pub fn fallback_backend(
+    kind: ScanBackendKind,
+    current: &dyn ScanBackend,
+    exclude_paths: &[String],
+) -> Option<Box<dyn ScanBackend>> {
+    match (kind, current.name()) {
+        (ScanBackendKind::Auto, "find") => Some(ScanBackendKind::Walk.backend(exclude_paths)),
+        (ScanBackendKind::Fd, "fd") => Some(ScanBackendKind::Find.backend(exclude_paths)),
+        _ => None,
+    }
+}
+
+/// Returns the backend to switch to when the `RunCommands` permission is
+/// denied, if any.
+///
+/// Only `auto` moves to the walk backend, which runs no commands; explicitly
+/// chosen backends are kept.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::discovery::{permission_denied_backend, ScanBackendKind};
+///
+/// let find = ScanBackendKind::Auto.backend(&[]);
+/// let walk = permission_denied_backend(ScanBackendKind::Auto, find.as_ref(), &[]).unwrap();
+/// assert_eq!(walk.name(), "walk");
+/// assert!(permission_denied_backend(ScanBackendKind::Auto, walk.as_ref(), &[]).is_none());
+///
+/// let fd = ScanBackendKind::Fd.backend(&[]);
+/// assert!(permission_denied_backend(ScanBackendKind::Fd, fd.as_ref(), &[]).is_none());
+/// ```
+#[must_use]
+pub fn permission_denied_backend(
+    kind: ScanBackendKind,
+    current: &dyn ScanBackend,
+    exclude_paths: &[String],
+) -> Option<Box<dyn ScanBackend>> {
+    (kind == ScanBackendKind::Auto && current.name() != "walk")
+        .then(|| ScanBackendKind::Walk.backend(exclude_paths))
+}
+
+/// Returns whether a scan command result means the command could not run.
+///
+/// Covers commands that never started (no exit code) and shells reporting
+/// the command as missing.
+#[must_use]
+pub const fn command_unavailable(exit_code: Option<i32>) -> bool {
+    matches!(exit_code, None | Some(COMMAND_NOT_FOUND))
+}
+
+impl WalkBackend {
+    /// Recursively collects marker paths below `fs_dir`, up to `depth` levels.
+    ///
+    /// Paths are reported under `report_dir` instead of `fs_dir`, so results
+    /// match what `find` prints for the same scan root.
+    fn walk_markers(&self, fs_dir: &Path, report_dir: &str, depth: u32, markers: &mut Vec<String>) {
+        if depth == 0 {
+            return;
+        }
+
+        let entries = match fs::read_dir(fs_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::trace!(dir = ?fs_dir, error = %e, "skipping unreadable directory");
+                return;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy();
+            let report_path = format!("{report_dir}/{name}");
+
+            // `.git` is a file in worktrees and submodules
+            let is_marker = name == ".git" || (name == ".zessionizer" && file_type.is_file());
+
+            if is_marker {
+                markers.push(report_path);
+            } else if file_type.is_dir() && !self.is_excluded(&report_path) {
+                self.walk_markers(&entry.path(), &report_path, depth - 1, markers);
+            }
+        }
+    }
+
+    /// Returns whether a directory matches an `exclude_paths` entry.
+    fn is_excluded(&self, report_path: &str) -> bool {
+        self.exclude_suffixes
+            .iter()
+            .any(|suffix| report_path.ends_with(suffix.as_str()))
+    }
+}
@@ -0,0 +1,36 @@
+//! Opening projects in the user's editor.
+//!
+//! The editor runs in a Zellij command pane through `sh`, so the configured
+//! command may carry arguments and `$EDITOR` is read from the host environment.
+
+/// Builds `sh` arguments that open the current directory in an editor.
+///
+/// The command pane is started in the project directory, so the editor is
+/// given `.`. The editor defaults to `$EDITOR`, then `vi`, like
+/// [`edit_layout_args`](super::layout::edit_layout_args).
+///
+/// # Parameters
+///
+/// * `editor` - Editor command, split on whitespace (e.g. `"code --wait"`)
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::editor::open_editor_args;
+///
+/// let args = open_editor_args(Some("hx"));
+/// assert_eq!(args[0], "-c");
+/// assert_eq!(args.last().map(String::as_str), Some("hx"));
+/// assert_eq!(open_editor_args(None).last().map(String::as_str), Some(""));
+/// ```
+#[must_use]
+pub fn open_editor_args(editor: Option<&str>) -> Vec<String> {
+    const SCRIPT: &str = r#"editor="${1:-${EDITOR:-vi}}"; exec $editor ."#;
+
+    vec![
+        "-c".to_string(),
+        SCRIPT.to_string(),
+        "sh".to_string(),
+        editor.unwrap_or_default().to_string(),
+    ]
+}
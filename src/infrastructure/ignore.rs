@@ -0,0 +1,144 @@
+//! Global ignore list for discovered projects.
+//!
+//! `~/.config/zessionizer/ignore` lists glob patterns, one per line, for
+//! project paths that are never added to storage. Unlike `exclude_paths`,
+//! which prunes directories from the scan itself, the ignore list is applied
+//! to scan results before they are stored, so editing it needs no change to
+//! the plugin configuration. Projects stored before a pattern was added are
+//! kept until deleted.
+//!
+//! # Format
+//!
+//! ```text
+//! # Old work, kept for reference
+//! */archive/*
+//! ~/Projects/scratch
+//! ```
+//!
+//! Blank lines and lines starting with `#` are skipped. Patterns are matched
+//! against the whole project path as shown in the list: `*` matches any run
+//! of characters, including `/`, and `?` matches a single character. A
+//! leading `~/` matches projects found below the home directory, whose paths
+//! are relative to it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::infrastructure::ignore::IgnoreList;
+//!
+//! let ignore = IgnoreList::parse("# comment\n*/archive/*\n~/Projects/scratch\n\n/opt/code/legacy\n");
+//!
+//! assert!(ignore.is_ignored("/code/archive/old-api"));
+//! assert!(ignore.is_ignored("Projects/scratch"));
+//! assert!(ignore.is_ignored("./Projects/scratch/"));
+//! assert!(ignore.is_ignored("/host/opt/code/legacy"));
+//! assert!(!ignore.is_ignored("/opt/code/legacy-v2"));
+//! assert!(!ignore.is_ignored("/code/archive"));
+//! ```
+
+use std::path::Path;
+
+/// Ignore file location, as seen through the plugin's `/host` mount.
+pub const IGNORE_FILE: &str = "/host/.config/zessionizer/ignore";
+
+/// Glob patterns of project paths to leave out of storage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreList {
+    patterns: Vec<String>,
+}
+
+impl IgnoreList {
+    /// Parses ignore file contents, skipping blank lines and `#` comments.
+    #[must_use]
+    pub fn parse(contents: &str) -> Self {
+        let patterns = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| normalize(line.strip_prefix("~/").unwrap_or(line)).to_string())
+            .collect();
+
+        Self { patterns }
+    }
+
+    /// Reads the ignore list from `path`.
+    ///
+    /// A missing or unreadable file yields an empty list.
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) => {
+                tracing::debug!(path = ?path, error = %e, "no ignore file");
+                Self::default()
+            }
+        }
+    }
+
+    /// Returns whether `project_path` matches any pattern.
+    ///
+    /// The `/host` sandbox prefix, a leading `./`, and trailing slashes are
+    /// ignored.
+    #[must_use]
+    pub fn is_ignored(&self, project_path: &str) -> bool {
+        let path = project_path.strip_prefix("/host").unwrap_or(project_path);
+        let path = normalize(path);
+        self.patterns.iter().any(|pattern| glob_match(pattern, path))
+    }
+}
+
+/// Drops a leading `./` and trailing slashes.
+fn normalize(path: &str) -> &str {
+    let path = path.strip_prefix("./").unwrap_or(path);
+    if path.len() > 1 {
+        path.trim_end_matches('/')
+    } else {
+        path
+    }
+}
+
+/// Matches `text` against a glob `pattern` with `*` and `?` wildcards.
+///
+/// # Examples
+///
+/// ```rust
+/// use zessionizer::infrastructure::ignore::glob_match;
+///
+/// assert!(glob_match("*/archive/*", "/code/archive/api"));
+/// assert!(glob_match("/code/api-v?", "/code/api-v2"));
+/// assert!(glob_match("/code/*", "/code/nested/api"));
+/// assert!(!glob_match("/code/api", "/code/api2"));
+/// assert!(!glob_match("*/archive/*", "/code/archives/api"));
+/// ```
+#[must_use]
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it currently covers up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, t));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,118 @@
+//! Curated project lists added without scanning.
+//!
+//! The `import_file` option points to a text file listing one project
+//! directory per line. Blank lines and lines starting with `#` are skipped.
+//! Paths take the same forms as `scan_paths`: `~/` paths are relative to the
+//! home directory, other paths are absolute. Names are the last path segment.
+//!
+//! ```text
+//! # work
+//! ~/code/api
+//! /opt/src/web/
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::infrastructure::import::{parse_import_list, projects_from_import};
+//!
+//! let paths = parse_import_list("# work\n~/code/api\n\n  /opt/src/web/  \n~/code/api\n");
+//! assert_eq!(paths, ["~/code/api", "/opt/src/web", "~/code/api"]);
+//!
+//! assert_eq!(projects_from_import(&paths), vec![
+//!     ("code/api".to_string(), "api".to_string()),
+//!     ("/opt/src/web".to_string(), "web".to_string()),
+//! ]);
+//! ```
+
+use std::collections::HashSet;
+
+use super::paths::expand_tilde;
+use super::scanner::{canonical_project_path, resolve_scan_root};
+
+/// Returns the project paths listed in import file contents.
+///
+/// Lines are trimmed, including trailing slashes; blank lines and `#`
+/// comments are dropped.
+#[must_use]
+pub fn parse_import_list(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let trimmed = line.trim_end_matches('/');
+            if trimmed.is_empty() { line } else { trimmed }.to_string()
+        })
+        .collect()
+}
+
+/// Normalizes a listed path the way scans store it and derives the project
+/// name from its last segment.
+///
+/// A `/host` prefix is dropped and `~/` paths become relative to the home
+/// directory. Returns `None` for paths without a name, such as `~` or `/`.
+///
+/// # Examples
+///
+/// ```rust
+/// use zessionizer::infrastructure::import::import_project;
+///
+/// assert_eq!(import_project("~/code/api"), Some(("code/api".to_string(), "api".to_string())));
+/// assert_eq!(import_project("/host/code//web/"), Some(("/code/web".to_string(), "web".to_string())));
+/// assert_eq!(import_project("/hosted/app"), Some(("/hosted/app".to_string(), "app".to_string())));
+/// assert_eq!(import_project("~"), None);
+/// assert_eq!(import_project("/"), None);
+/// ```
+#[must_use]
+pub fn import_project(path: &str) -> Option<(String, String)> {
+    let without_host = path
+        .strip_prefix("/host")
+        .filter(|rest| rest.starts_with('/'))
+        .unwrap_or(path);
+    let project_path = canonical_project_path(resolve_scan_root(without_host));
+
+    let name = project_path
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty() && *name != ".")?
+        .to_string();
+
+    Some((project_path, name))
+}
+
+/// Converts listed paths into `(path, name)` pairs for a batch add.
+///
+/// Paths without a name are skipped, and later duplicates of a path are
+/// dropped.
+#[must_use]
+pub fn projects_from_import(paths: &[String]) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let project = import_project(path);
+            if project.is_none() {
+                tracing::debug!(path = %path, "skipping import path without a project name");
+            }
+            project
+        })
+        .filter(|(path, _)| seen.insert(path.clone()))
+        .collect()
+}
+
+/// Reads the project paths listed in the import file at `path`.
+///
+/// `~` expands to the sandbox home (`/host`). A missing or unreadable file
+/// yields no paths, so a stale option never prevents the plugin from loading.
+#[must_use]
+pub fn read_import_file(path: &str) -> Vec<String> {
+    match std::fs::read_to_string(expand_tilde(path)) {
+        Ok(contents) => parse_import_list(&contents),
+        Err(e) => {
+            tracing::warn!(import_file = %path, error = %e, "failed to read import file");
+            Vec::new()
+        }
+    }
+}
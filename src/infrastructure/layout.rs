@@ -0,0 +1,171 @@
+//! Per-project Zellij layout files.
+//!
+//! A project's layout lives in the Zellij layout directory: the layout picked
+//! for it, or else `<project name>.kdl`, which is discovered automatically.
+//! This module resolves that file, builds the command used to edit it (seeding
+//! a minimal template when it does not exist yet), and lists the layouts for
+//! the layout picker.
+//!
+//! The plugin sees its working directory as `/host`, while panes it opens in
+//! that directory see it as their own, so the layout directory is kept
+//! relative to it and joined onto `/host` where the plugin reads it.
+
+use std::path::{Path, PathBuf};
+
+/// Zellij layout directory, relative to the plugin's working directory.
+pub const LAYOUTS_SUBDIR: &str = ".config/zellij/layouts";
+
+/// Returns the Zellij layout directory as seen through the plugin's `/host`
+/// mount.
+///
+/// # Example
+///
+/// ```rust
+/// use std::path::Path;
+/// use zessionizer::infrastructure::layout::{layouts_dir, LAYOUTS_SUBDIR};
+///
+/// assert_eq!(layouts_dir(), Path::new("/host").join(LAYOUTS_SUBDIR));
+/// ```
+#[must_use]
+pub fn layouts_dir() -> PathBuf {
+    Path::new("/host").join(LAYOUTS_SUBDIR)
+}
+
+/// Lists the layouts in `dir`, sorted by name.
+///
+/// Returns an empty list if the directory is missing or unreadable.
+#[must_use]
+pub fn list_layouts(dir: &Path) -> Vec<String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::debug!(layout_dir = ?dir, error = %e, "failed to read layout directory");
+            return Vec::new();
+        }
+    };
+
+    let paths = entries
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| !file_type.is_dir()))
+        .map(|entry| entry.path());
+
+    layout_names(paths)
+}
+
+/// Extracts sorted, deduplicated layout names from file paths.
+///
+/// Only `.kdl` files count; the name is the file stem, which is what Zellij
+/// expects when a layout is requested by name.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::layout::layout_names;
+/// use std::path::PathBuf;
+///
+/// let names = layout_names([
+///     PathBuf::from("layouts/dev.kdl"),
+///     PathBuf::from("layouts/README.md"),
+///     PathBuf::from("layouts/compact.kdl"),
+///     PathBuf::from("layouts/.kdl"),
+/// ]);
+/// assert_eq!(names, vec!["compact", "dev"]);
+/// ```
+#[must_use]
+pub fn layout_names(paths: impl IntoIterator<Item = PathBuf>) -> Vec<String> {
+    let mut names: Vec<String> = paths
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "kdl"))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+        .filter(|name| !name.is_empty() && !name.starts_with('.'))
+        .collect();
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Returns the layout file path for a project in `layouts_dir`.
+///
+/// This is the picked `layout` if any, else `<project_name>.kdl`. The file may
+/// not exist yet; [`edit_layout_args`] creates it from [`layout_template`] on
+/// first edit.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::layout::find_layout_for_project;
+/// use std::path::{Path, PathBuf};
+///
+/// let dir = Path::new("layouts");
+/// assert_eq!(find_layout_for_project(dir, "api", None), PathBuf::from("layouts/api.kdl"));
+/// assert_eq!(find_layout_for_project(dir, "api", Some("dev")), PathBuf::from("layouts/dev.kdl"));
+/// ```
+#[must_use]
+pub fn find_layout_for_project(layouts_dir: &Path, project_name: &str, layout: Option<&str>) -> PathBuf {
+    layouts_dir.join(format!("{}.kdl", layout.unwrap_or(project_name)))
+}
+
+/// Returns the layout to create a project's sessions with.
+///
+/// A picked `layout` wins; otherwise `<project_name>.kdl` in `layouts_dir` is
+/// used if it exists. `None` means Zellij's default layout.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::layout::discover_layout;
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// assert_eq!(discover_layout(dir.path(), "api", None), None);
+/// assert_eq!(discover_layout(dir.path(), "api", Some("dev")).as_deref(), Some("dev"));
+///
+/// std::fs::write(dir.path().join("api.kdl"), "layout {}").unwrap();
+/// assert_eq!(discover_layout(dir.path(), "api", None).as_deref(), Some("api"));
+/// assert_eq!(discover_layout(dir.path(), "api", Some("dev")).as_deref(), Some("dev"));
+/// ```
+#[must_use]
+pub fn discover_layout(layouts_dir: &Path, project_name: &str, layout: Option<&str>) -> Option<String> {
+    if let Some(layout) = layout {
+        return Some(layout.to_string());
+    }
+
+    find_layout_for_project(layouts_dir, project_name, None)
+        .is_file()
+        .then(|| project_name.to_string())
+}
+
+/// Returns a minimal layout with a single tab named after the project.
+#[must_use]
+pub fn layout_template(project_name: &str) -> String {
+    let tab_name = project_name.replace('"', "");
+    format!(
+        "layout {{\n    tab name=\"{tab_name}\" {{\n        pane\n    }}\n}}\n"
+    )
+}
+
+/// Builds `sh` arguments that open a layout file in an editor.
+///
+/// If the file does not exist, it is first created with `template`, along with
+/// its directory. The editor
+/// defaults to `$EDITOR`, then `vi`. Values are passed as positional shell
+/// arguments rather than interpolated, so paths need no quoting.
+///
+/// # Parameters
+///
+/// * `layout_path` - Layout file to edit
+/// * `template` - Contents written if the file is missing
+/// * `editor` - Editor command, split on whitespace (e.g. `"code --wait"`)
+#[must_use]
+pub fn edit_layout_args(layout_path: &Path, template: &str, editor: Option<&str>) -> Vec<String> {
+    const SCRIPT: &str = r#"[ -e "$1" ] || { mkdir -p "$(dirname "$1")" && printf '%s' "$2" > "$1"; }; editor="${3:-${EDITOR:-vi}}"; exec $editor "$1""#;
+
+    vec![
+        "-c".to_string(),
+        SCRIPT.to_string(),
+        "sh".to_string(),
+        layout_path.to_string_lossy().to_string(),
+        template.to_string(),
+        editor.unwrap_or_default().to_string(),
+    ]
+}
@@ -0,0 +1,93 @@
+//! Project annotations read from `.zessionizer` marker files.
+//!
+//! A marker file may be empty, or carry `key: value` lines describing the
+//! project. Currently only `tags` is recognized; other lines are ignored:
+//!
+//! ```text
+//! tags: work, rust
+//! ```
+//!
+//! Tags are lowercased, a leading `#` is dropped, and duplicates are removed,
+//! so `tags: Work, #rust, work` yields `["work", "rust"]`. Several `tags` lines
+//! add up.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::infrastructure::marker::{read_tags, MARKER_FILE};
+//!
+//! let host = tempfile::tempdir().unwrap();
+//! std::fs::create_dir_all(host.path().join("code/api")).unwrap();
+//! std::fs::write(host.path().join("code/api").join(MARKER_FILE), "tags: work, rust\n").unwrap();
+//!
+//! assert_eq!(read_tags(host.path(), "code/api"), vec!["work", "rust"]);
+//! assert!(read_tags(host.path(), "code/web").is_empty());
+//! ```
+
+use std::path::Path;
+
+/// Name of the marker file that makes a directory a project.
+pub const MARKER_FILE: &str = ".zessionizer";
+
+/// Key of the marker file line listing the project's tags.
+const TAGS_KEY: &str = "tags";
+
+/// Parses the tags listed in marker file contents.
+///
+/// # Examples
+///
+/// ```rust
+/// use zessionizer::infrastructure::marker::parse_tags;
+///
+/// assert_eq!(parse_tags("tags: work, rust"), vec!["work", "rust"]);
+/// assert_eq!(parse_tags("Tags: Work, #rust, work,\ntags: cli"), vec!["work", "rust", "cli"]);
+/// assert_eq!(parse_tags("description: tagless\n\n tags :  ops "), vec!["ops"]);
+/// assert!(parse_tags("").is_empty());
+/// assert!(parse_tags("tags work").is_empty());
+/// ```
+#[must_use]
+pub fn parse_tags(contents: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        if !key.trim().eq_ignore_ascii_case(TAGS_KEY) {
+            continue;
+        }
+
+        for tag in value.split(',') {
+            let tag = tag.trim().trim_start_matches('#').trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+    }
+
+    tags
+}
+
+/// Reads the tags from the marker file of the project at `project_path`.
+///
+/// Relative project paths are resolved against `host_root` (the sandbox's
+/// `/host` mount), like the walk discovery backend does. A missing or
+/// unreadable marker file yields no tags, since `.git` projects usually have
+/// none.
+#[must_use]
+pub fn read_tags(host_root: &Path, project_path: &str) -> Vec<String> {
+    let project_dir = Path::new(project_path);
+    let marker_path = if project_dir.is_absolute() {
+        project_dir.join(MARKER_FILE)
+    } else {
+        host_root.join(project_dir).join(MARKER_FILE)
+    };
+
+    match std::fs::read_to_string(&marker_path) {
+        Ok(contents) => parse_tags(&contents),
+        Err(e) => {
+            tracing::trace!(path = ?marker_path, error = %e, "no marker file to read tags from");
+            Vec::new()
+        }
+    }
+}
@@ -2,8 +2,19 @@
 //!
 //! This module provides utilities for working with the Zellij plugin sandbox
 //! environment, particularly path handling where the host filesystem is mounted
-//! under `/host`.
+//! under `/host`, scan planning and interchangeable discovery backends, the
+//! global ignore list, curated project lists, tags read from `.zessionizer`
+//! marker files, per-project layouts, the editor command, and host clipboard
+//! access.
 
+pub mod clipboard;
+pub mod discovery;
+pub mod editor;
+pub mod ignore;
+pub mod import;
+pub mod layout;
+pub mod marker;
 pub mod paths;
+pub mod scanner;
 
-pub use paths::{expand_tilde, get_data_dir, strip_host_prefix};
+pub use paths::{expand_tilde, get_data_dir, resolve_data_dir, strip_host_prefix};
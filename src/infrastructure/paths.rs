@@ -14,7 +14,8 @@ use std::path::PathBuf;
 ///
 /// This typically resolves to the user's home directory when Zellij is started from
 /// a home directory terminal, making the actual path `~/.local/share/zellij/zessionizer`.
-/// The JSON storage file `projects.json` is located within this directory.
+/// The JSON storage file `projects.json` is located within this directory. The
+/// `data_dir` configuration overrides it (see [`resolve_data_dir`]).
 ///
 /// # Examples
 ///
@@ -29,6 +30,40 @@ pub fn get_data_dir() -> PathBuf {
     PathBuf::from("/host/.local/share/zellij").join("zessionizer")
 }
 
+/// Resolves the data directory, honoring a configured `data_dir` override.
+///
+/// Without an override, returns [`get_data_dir`]. Overrides are expanded with
+/// [`expand_tilde`] and placed under `/host` unless they already are, so
+/// `~/data`, `/home/user/data` (relative to `/host`) and `/host/data` all
+/// resolve inside the sandbox. Empty overrides are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zessionizer::infrastructure::paths::{get_data_dir, resolve_data_dir};
+///
+/// assert_eq!(resolve_data_dir(None), get_data_dir());
+/// assert_eq!(resolve_data_dir(Some("  ")), get_data_dir());
+/// assert_eq!(resolve_data_dir(Some("~/.data/zs")), PathBuf::from("/host/.data/zs"));
+/// assert_eq!(resolve_data_dir(Some("/host/zs")), PathBuf::from("/host/zs"));
+/// assert_eq!(resolve_data_dir(Some("/srv/zs")), PathBuf::from("/host/srv/zs"));
+/// assert_eq!(resolve_data_dir(Some("zs")), PathBuf::from("/host/zs"));
+/// ```
+#[must_use]
+pub fn resolve_data_dir(data_dir: Option<&str>) -> PathBuf {
+    let Some(data_dir) = data_dir.map(str::trim).filter(|dir| !dir.is_empty()) else {
+        return get_data_dir();
+    };
+
+    let expanded = expand_tilde(data_dir);
+    if expanded == "/host" || expanded.starts_with("/host/") {
+        PathBuf::from(expanded)
+    } else {
+        PathBuf::from("/host").join(expanded.trim_start_matches('/'))
+    }
+}
+
 /// Expands tilde paths to use the `/host` prefix for Zellij sandbox.
 ///
 /// In the Zellij sandbox environment, the host's home directory (`~`) maps to `/host`.
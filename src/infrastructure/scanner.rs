@@ -0,0 +1,574 @@
+//! Filesystem scan planning for project discovery.
+//!
+//! This module decides which directories need to be scanned for project markers
+//! (`.git` entries and `.zessionizer` files) and builds the `find` or `fd`
+//! arguments for each scan. Scan roots come from the `scan_paths` configuration and are
+//! resolved relative to the plugin's working directory, which maps to `/host`
+//! inside the Zellij sandbox.
+//!
+//! # Scoped Rescans
+//!
+//! Filesystem events carry the changed paths. Instead of re-scanning every
+//! configured root, [`scoped_scan_targets`] maps each changed path to the
+//! smallest directory that can contain a new marker, keeping the configured
+//! depth limit relative to the original scan root.
+//!
+//! # Parsing Results
+//!
+//! [`parse_scan_output`] splits raw scanner output into marker paths, and
+//! [`projects_from_scan`] turns those into `(path, name)` project tuples,
+//! shared by the event handler and library users.
+
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+
+/// Marker names identifying a project directory.
+pub const PROJECT_MARKERS: [&str; 2] = [".git", ".zessionizer"];
+
+/// Smallest accepted `scan_depth`; `find -maxdepth 0` would only test the root.
+pub const MIN_SCAN_DEPTH: u32 = 1;
+
+/// Largest accepted `scan_depth`; deeper scans crawl large trees for little gain.
+pub const MAX_SCAN_DEPTH: u32 = 10;
+
+/// A single directory scan to execute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanTarget {
+    /// Directory to scan, relative to the plugin's working directory.
+    pub dir: String,
+
+    /// Maximum depth below `dir` passed to `find -maxdepth`.
+    pub depth: u32,
+}
+
+/// Resolves a configured scan path to the directory passed to `find`.
+///
+/// Tilde paths are made relative to the plugin's working directory (the host
+/// home directory), since `run_command` executes from there.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::resolve_scan_root;
+///
+/// assert_eq!(resolve_scan_root("~/Projects"), "Projects");
+/// assert_eq!(resolve_scan_root("~"), ".");
+/// assert_eq!(resolve_scan_root("/opt/code"), "/opt/code");
+/// ```
+#[must_use]
+pub fn resolve_scan_root(scan_path: &str) -> &str {
+    if scan_path.starts_with("~/") {
+        scan_path.strip_prefix("~/").unwrap_or(scan_path)
+    } else if scan_path == "~" {
+        "."
+    } else {
+        scan_path
+    }
+}
+
+/// Splits configured scan paths into existing and missing ones.
+///
+/// `~` and relative paths are checked below `host_root`, where the sandbox
+/// mounts the plugin's working directory (`/host`). Absolute paths are not
+/// visible from the sandbox and count as existing; `find` reports them if
+/// they are missing.
+///
+/// # Returns
+///
+/// `(existing, missing)`, each in configuration order.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::split_missing_scan_paths;
+///
+/// let host = tempfile::tempdir().unwrap();
+/// std::fs::create_dir_all(host.path().join("Projects")).unwrap();
+/// std::fs::write(host.path().join("notes"), "").unwrap();
+///
+/// let scan_paths = ["~/Projects", "~/Code", "~", "Projects", "~/notes", "/opt/src"].map(String::from);
+/// let (existing, missing) = split_missing_scan_paths(&scan_paths, host.path());
+/// assert_eq!(existing, ["~/Projects", "~", "Projects", "/opt/src"]);
+/// assert_eq!(missing, ["~/Code", "~/notes"]);
+/// ```
+#[must_use]
+pub fn split_missing_scan_paths(scan_paths: &[String], host_root: &Path) -> (Vec<String>, Vec<String>) {
+    scan_paths.iter().cloned().partition(|scan_path| {
+        let root = resolve_scan_root(scan_path);
+        root.starts_with('/') || host_root.join(root).is_dir()
+    })
+}
+
+/// Returns one scan target per configured root at the full scan depth.
+#[must_use]
+pub fn full_scan_targets(scan_paths: &[String], depth: u32) -> Vec<ScanTarget> {
+    scan_paths
+        .iter()
+        .map(|scan_path| ScanTarget {
+            dir: resolve_scan_root(scan_path).to_string(),
+            depth,
+        })
+        .collect()
+}
+
+/// Maps changed filesystem paths to the scans needed to pick up new projects.
+///
+/// For each changed path, the directory to rescan is:
+/// - the project directory, if the path lies inside a marker (e.g. `foo/.git/index` → `foo`)
+/// - otherwise the parent directory of the changed entry
+///
+/// The depth of each target is reduced by how far it sits below its scan root,
+/// so scoped scans never look deeper than a full scan would. Changes beyond the
+/// depth limit produce no target.
+///
+/// Event paths may carry the sandbox's `/host` prefix; absolute roots match
+/// the host path below it, like [`resolve_data_dir`](super::paths::resolve_data_dir)
+/// maps `/srv/zs` to `/host/srv/zs`.
+///
+/// # Returns
+///
+/// - `Some(targets)` if every changed path falls under a configured root
+/// - `None` if any path is outside all roots, signalling a full rescan
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use zessionizer::infrastructure::scanner::{find_args, scoped_scan_targets, ScanTarget};
+///
+/// let scan_paths = ["~/Projects".to_string(), "/opt/code".to_string()];
+/// let scoped = |changed: &[&str]| {
+///     let changed: Vec<PathBuf> = changed.iter().map(PathBuf::from).collect();
+///     scoped_scan_targets(&changed, &scan_paths, 3)
+/// };
+/// let target = |dir: &str, depth| ScanTarget { dir: dir.to_string(), depth };
+///
+/// // A change inside a marker rescans its project, one level deep.
+/// let targets = scoped(&["/host/Projects/work/api/.git/index"]).unwrap();
+/// assert_eq!(targets, [target("Projects/work/api", 1)]);
+/// assert_eq!(find_args(&targets[0], &[])[..4], ["find", "Projects/work/api", "-maxdepth", "1"]);
+///
+/// // A new entry rescans its parent with the depth left below the root.
+/// assert_eq!(scoped(&["/host/Projects/work/new-repo"]).unwrap(), [target("Projects/work", 2)]);
+///
+/// // Absolute roots match the host path the `/host` event path mirrors.
+/// let targets = scoped(&["/host/opt/code/svc/.git/HEAD", "/opt/code/svc/.git/index"]).unwrap();
+/// assert_eq!(targets, [target("/opt/code/svc", 2)]);
+/// assert_eq!(find_args(&targets[0], &[])[..4], ["find", "/opt/code/svc", "-maxdepth", "2"]);
+///
+/// // Changes beyond the depth limit need no scan.
+/// assert_eq!(scoped(&["/host/Projects/a/b/c/d"]).unwrap(), []);
+///
+/// // A change outside every root falls back to a full scan.
+/// assert_eq!(scoped(&["/host/Projects/api/.git/index", "/host/Downloads/file"]), None);
+/// ```
+#[must_use]
+pub fn scoped_scan_targets(
+    changed: &[PathBuf],
+    scan_paths: &[String],
+    depth: u32,
+) -> Option<Vec<ScanTarget>> {
+    let roots: Vec<&str> = scan_paths.iter().map(|p| resolve_scan_root(p)).collect();
+    let mut targets: Vec<ScanTarget> = Vec::new();
+
+    for path in changed {
+        let dir = rescan_dir_for(path);
+        let target = roots
+            .iter()
+            .find_map(|root| relative_to_root(&dir, root).map(|rest| (*root, rest)))
+            .map(|(root, rest)| {
+                let depth_below = u32::try_from(rest.components().count()).unwrap_or(u32::MAX);
+                (Path::new(root).join(rest), depth.saturating_sub(depth_below))
+            });
+
+        let Some((target_dir, remaining)) = target else {
+            tracing::debug!(path = ?path, "changed path outside scan roots");
+            return None;
+        };
+
+        if remaining == 0 {
+            tracing::trace!(path = ?path, "changed path beyond scan depth, skipping");
+            continue;
+        }
+
+        let target = ScanTarget {
+            dir: target_dir.to_string_lossy().to_string(),
+            depth: remaining,
+        };
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+
+    Some(targets)
+}
+
+/// Builds the `find` arguments for a scan target.
+///
+/// Matches `.git` entries and `.zessionizer` files up to the target depth.
+/// `.git` may be a directory or, in worktrees and submodules, a file pointing
+/// to the git directory, so its type is not restricted.
+/// Directories matching an `exclude_paths` entry (see [`exclude_pattern`]) are
+/// pruned, so nothing below them is visited; markers elsewhere are still found.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::{find_args, ScanTarget};
+///
+/// let target = ScanTarget { dir: "Projects".to_string(), depth: 3 };
+/// let args = find_args(&target, &["node_modules".to_string()]);
+/// assert_eq!(
+///     args[4..10],
+///     ["(", "-path", "*/node_modules", ")", "-prune", "-o"]
+/// );
+/// assert_eq!(args.last().map(String::as_str), Some("-print"));
+/// ```
+#[must_use]
+pub fn find_args(target: &ScanTarget, exclude_paths: &[String]) -> Vec<String> {
+    let mut args: Vec<String> = ["find", &target.dir, "-maxdepth", &target.depth.to_string()]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    let patterns: Vec<String> = exclude_paths
+        .iter()
+        .filter_map(|entry| exclude_pattern(entry))
+        .collect();
+
+    if !patterns.is_empty() {
+        args.push("(".to_string());
+        for (i, pattern) in patterns.into_iter().enumerate() {
+            if i > 0 {
+                args.push("-o".to_string());
+            }
+            args.push("-path".to_string());
+            args.push(pattern);
+        }
+        args.extend([")", "-prune", "-o"].iter().map(ToString::to_string));
+    }
+
+    args.extend(
+        [
+            "(",
+            "-name",
+            ".git",
+            "-o",
+            "-name",
+            ".zessionizer",
+            "-type",
+            "f",
+            ")",
+        ]
+        .iter()
+        .map(ToString::to_string),
+    );
+
+    // With `-prune`, pruned directories would be printed by the implicit action
+    if args.iter().any(|arg| arg == "-prune") {
+        args.push("-print".to_string());
+    }
+
+    args
+}
+
+/// Builds the `fd` arguments for a scan target.
+///
+/// Matches entries named `.git` or `.zessionizer` up to the target depth,
+/// including hidden entries and respecting `.gitignore` files. Like `find`, a
+/// `.git` file (as in worktrees) is reported too. Matching directories are not descended into, and `exclude_paths`
+/// entries are passed as `--exclude` globs. Paths are printed the way `find`
+/// prints them, so [`projects_from_scan`] handles either output.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::{fd_args, ScanTarget};
+///
+/// let target = ScanTarget { dir: "Projects".to_string(), depth: 3 };
+/// let args = fd_args(&target, &["node_modules".to_string()]);
+/// assert_eq!(args[..4], ["fd", "--hidden", "--prune", "--max-depth"]);
+/// assert!(args.windows(2).any(|w| w == ["--exclude", "**/node_modules"]));
+/// assert_eq!(args.last().map(String::as_str), Some("Projects"));
+/// ```
+#[must_use]
+pub fn fd_args(target: &ScanTarget, exclude_paths: &[String]) -> Vec<String> {
+    let mut args: Vec<String> = ["fd", "--hidden", "--prune", "--max-depth", &target.depth.to_string()]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    for pattern in exclude_paths.iter().filter_map(|entry| exclude_pattern(entry)) {
+        args.push("--exclude".to_string());
+        args.push(format!("*{pattern}"));
+    }
+
+    args.extend(
+        ["--glob", "{.git,.zessionizer}", &target.dir]
+            .iter()
+            .map(ToString::to_string),
+    );
+    args
+}
+
+/// Converts an `exclude_paths` entry into a `find -path` pattern.
+///
+/// Entries are directory names (`node_modules`) or trailing path fragments
+/// (`work/vendor`), matched at any depth. Surrounding slashes are ignored.
+///
+/// # Returns
+///
+/// - `Some(pattern)` such as `*/node_modules`
+/// - `None` for blank entries
+#[must_use]
+pub fn exclude_pattern(entry: &str) -> Option<String> {
+    let entry = entry.trim().trim_matches('/');
+    if entry.is_empty() {
+        None
+    } else {
+        Some(format!("*/{entry}"))
+    }
+}
+
+/// Splits scanner output into marker paths.
+///
+/// Accepts both `\n` and `\r\n` line endings, trims surrounding whitespace,
+/// and skips blank lines so a trailing newline never yields an empty marker.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::parse_scan_output;
+///
+/// let lf = parse_scan_output("code/api/.git\nnotes/.zessionizer\n");
+/// let crlf = parse_scan_output("code/api/.git\r\nnotes/.zessionizer\r\n\r\n");
+/// assert_eq!(lf, vec!["code/api/.git", "notes/.zessionizer"]);
+/// assert_eq!(crlf, lf);
+/// ```
+#[must_use]
+pub fn parse_scan_output(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Splits raw scanner stdout into marker paths, skipping lines that are not
+/// valid UTF-8.
+///
+/// A single undecodable path must not discard the whole scan, and replacing
+/// its bytes would produce a path that does not exist, so such lines are
+/// dropped individually. Decodable lines are parsed as in [`parse_scan_output`].
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::parse_scan_bytes;
+///
+/// let stdout = b"code/api/.git\ncode/bad\xff\xfe/.git\nnotes/.zessionizer\n";
+/// assert_eq!(parse_scan_bytes(stdout), vec!["code/api/.git", "notes/.zessionizer"]);
+/// ```
+#[must_use]
+pub fn parse_scan_bytes(stdout: &[u8]) -> Vec<String> {
+    stdout
+        .split(|&byte| byte == b'\n')
+        .filter_map(|line| match std::str::from_utf8(line) {
+            Ok(line) => Some(line),
+            Err(e) => {
+                tracing::debug!(error = %e, line = %String::from_utf8_lossy(line), "skipping scan line that is not valid UTF-8");
+                None
+            }
+        })
+        .flat_map(parse_scan_output)
+        .collect()
+}
+
+/// Extracts `(path, name)` project tuples from scanner marker paths.
+///
+/// Strips the `/host` sandbox prefix and the trailing marker component
+/// (e.g. `/.git`, whether a directory or a worktree's `.git` file, with or
+/// without a trailing `/`) from each path, canonicalizes the remaining directory with
+/// [`canonical_project_path`], then derives the project name from the last
+/// path segment. Paths are deduplicated after canonicalization, keeping the
+/// first occurrence, so a directory matching several markers yields a single
+/// project regardless of how each marker path was spelled.
+///
+/// # Parameters
+///
+/// * `markers` - Marker paths as printed by `find` (one per entry)
+/// * `marker_kinds` - Marker names to strip, typically [`PROJECT_MARKERS`]
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::{projects_from_scan, PROJECT_MARKERS};
+///
+/// let markers = vec![
+///     "/host/code/api/.git".to_string(),
+///     "/host/code/api/.zessionizer".to_string(),
+///     "notes/.zessionizer".to_string(),
+///     "notes//./.git".to_string(),
+/// ];
+/// let projects = projects_from_scan(&markers, &PROJECT_MARKERS);
+/// assert_eq!(projects, vec![
+///     ("/code/api".to_string(), "api".to_string()),
+///     ("notes".to_string(), "notes".to_string()),
+/// ]);
+///
+/// // `.git` directories (`fd` prints them with a trailing `/`) and the `.git`
+/// // files of worktrees and submodules both mark their parent directory
+/// let markers = vec![
+///     "code/api/.git/".to_string(),
+///     "code/api-feature/.git".to_string(),
+///     "code/api/vendor/lib/.git".to_string(),
+/// ];
+/// let projects = projects_from_scan(&markers, &PROJECT_MARKERS);
+/// assert_eq!(projects, vec![
+///     ("code/api".to_string(), "api".to_string()),
+///     ("code/api-feature".to_string(), "api-feature".to_string()),
+///     ("code/api/vendor/lib".to_string(), "lib".to_string()),
+/// ]);
+/// ```
+#[must_use]
+pub fn projects_from_scan(markers: &[String], marker_kinds: &[&str]) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+
+    markers
+        .iter()
+        .filter_map(|marker_path| {
+            let without_host = marker_path.strip_prefix("/host").unwrap_or(marker_path);
+            let without_host = without_host.trim_end_matches('/');
+            let project_dir = marker_kinds
+                .iter()
+                .find_map(|kind| {
+                    without_host
+                        .strip_suffix(kind)
+                        .and_then(|rest| rest.strip_suffix('/'))
+                })
+                .unwrap_or(without_host);
+            let project_path = canonical_project_path(project_dir);
+
+            let project_name = project_path
+                .split('/')
+                .next_back()
+                .unwrap_or("unknown");
+
+            if !seen.insert(project_path.clone()) {
+                return None;
+            }
+
+            tracing::debug!(
+                project_name = %project_name,
+                project_path = %project_path,
+                "discovered project"
+            );
+
+            Some((project_path.clone(), project_name.to_string()))
+        })
+        .collect()
+}
+
+/// Returns whether `path` contains any of the `excluded` segments.
+///
+/// The path is checked with a trailing `/`, so a segment like `/vendor/` also
+/// matches a project located directly at `.../vendor`.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::is_excluded;
+///
+/// let excluded = vec!["/node_modules/".to_string()];
+/// assert!(is_excluded("/code/app/node_modules/left-pad", &excluded));
+/// assert!(is_excluded("/code/app/node_modules", &excluded));
+/// assert!(!is_excluded("/code/app", &excluded));
+/// ```
+#[must_use]
+pub fn is_excluded(path: &str, excluded: &[String]) -> bool {
+    if excluded.is_empty() {
+        return false;
+    }
+
+    let with_slash = format!("{}/", path.trim_end_matches('/'));
+    excluded.iter().any(|segment| with_slash.contains(segment.as_str()))
+}
+
+/// Normalizes a project directory so every marker for it maps to one path.
+///
+/// Collapses repeated separators, drops interior `.` segments and trailing
+/// slashes. A leading `./` is kept, since scans rooted at `~` report paths in
+/// that form and existing stored paths depend on it.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::infrastructure::scanner::canonical_project_path;
+///
+/// assert_eq!(canonical_project_path("/code//api/./"), "/code/api");
+/// assert_eq!(canonical_project_path("./notes/."), "./notes");
+/// assert_eq!(canonical_project_path("Projects/app"), "Projects/app");
+/// ```
+#[must_use]
+pub fn canonical_project_path(path: &str) -> String {
+    let absolute = path.starts_with('/');
+    let leading_dot = path.starts_with("./");
+
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect();
+
+    let joined = segments.join("/");
+    if absolute {
+        format!("/{joined}")
+    } else if joined.is_empty() {
+        ".".to_string()
+    } else if leading_dot {
+        format!("./{joined}")
+    } else {
+        joined
+    }
+}
+
+/// Returns the directory to rescan for a single changed path.
+fn rescan_dir_for(path: &Path) -> PathBuf {
+    let components: Vec<Component<'_>> = path.components().collect();
+
+    if let Some(marker_idx) = components.iter().position(|c| {
+        PROJECT_MARKERS
+            .iter()
+            .any(|marker| c.as_os_str() == *marker)
+    }) {
+        return components[..marker_idx].iter().collect();
+    }
+
+    path.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+/// Returns `dir` relative to `root`, trying both the raw and `/host`-stripped forms.
+///
+/// A `/host`-prefixed path is tried both relative to `/host`, for relative
+/// roots, and as the absolute host path it mirrors (`/host/opt/code` is
+/// `/opt/code`), for absolute roots.
+fn relative_to_root(dir: &Path, root: &str) -> Option<PathBuf> {
+    let host_relative = dir.strip_prefix("/host").ok();
+    let candidates = [
+        Some(dir.to_path_buf()),
+        host_relative.map(Path::to_path_buf),
+        host_relative.map(|rest| Path::new("/").join(rest)),
+    ];
+
+    candidates.iter().flatten().find_map(|candidate| {
+        if root == "." {
+            return candidate
+                .is_relative()
+                .then(|| candidate.strip_prefix(".").unwrap_or(candidate).to_path_buf());
+        }
+        candidate
+            .strip_prefix(root)
+            .ok()
+            .map(Path::to_path_buf)
+    })
+}
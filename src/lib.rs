@@ -186,10 +186,14 @@ pub mod ui;
 
 pub mod observability;
 
-pub use app::{handle_event, Action, AppState, Event, InputMode, SearchFocus, ViewMode};
+pub use app::{
+    handle_event, Action, AppState, EnterAction, EnterActions, Event, InputMode, LayoutDensity,
+    PathDisplay, SearchFocus, SearchScope, SortMode, ViewMode,
+};
 pub use domain::{Project, Result, ZessionizerError};
 pub use ui::Theme;
 
+use infrastructure::discovery::ScanBackendKind;
 use std::collections::BTreeMap;
 
 /// Plugin configuration parsed from Zellij's configuration system.
@@ -209,6 +213,7 @@ use std::collections::BTreeMap;
 /// }
 /// ```
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Config {
     /// Comma-separated paths to scan for projects.
     ///
@@ -218,13 +223,35 @@ pub struct Config {
 
     /// Maximum directory depth for recursive scanning.
     ///
-    /// Higher values scan deeper but take longer. Recommended: 3-5. Default: 4
+    /// Higher values scan deeper but take longer. Clamped to 1-10.
+    /// Recommended: 3-5. Default: 4
     pub scan_depth: u32,
 
+    /// Comma-separated path segments excluding discovered projects.
+    ///
+    /// Scanned projects whose path contains any segment (e.g. `/vendor/`,
+    /// `/node_modules/`) are dropped before being stored. Default: `[]`
+    pub path_exclude: Vec<String>,
+
+    /// Directories skipped while scanning, e.g. `node_modules` or `.cache`.
+    ///
+    /// Each entry is a directory name or trailing path fragment, matched at
+    /// any depth; matching subtrees are pruned instead of being searched, which
+    /// speeds up scans of large trees. Unlike `path_exclude`, this never looks
+    /// at the discovered project paths themselves. Default: `[]`
+    pub exclude_paths: Vec<String>,
+
+    /// File listing project directories to add without scanning, one per line.
+    ///
+    /// Read once when the plugin starts; see [`infrastructure::import`] for
+    /// the format. Default: none
+    pub import_file: Option<String>,
+
     /// Built-in theme name to use.
     ///
     /// Options: `catppuccin-mocha`, `catppuccin-latte`, `catppuccin-frappe`,
-    /// `catppuccin-macchiato`. Ignored if `theme_file` is set.
+    /// `catppuccin-macchiato`, `gruvbox-dark`, `gruvbox-light`, `tokyonight`,
+    /// `nord`. Ignored if `theme_file` is set.
     pub theme_name: Option<String>,
 
     /// Path to a custom TOML theme file.
@@ -232,10 +259,226 @@ pub struct Config {
     /// Takes precedence over `theme_name`. See [`ui::theme`] for format.
     pub theme_file: Option<String>,
 
+    /// Color depth used when rendering theme colors.
+    ///
+    /// Use `256` or `16` on terminals without truecolor support; colors are
+    /// mapped to the nearest palette entry. Default: truecolor (`auto`)
+    pub color_mode: ui::theme::ColorMode,
+
     /// Tracing level for OpenTelemetry spans.
     ///
     /// Options: `trace`, `debug`, `info`, `warn`, `error`. Default: `"info"`
     pub trace_level: Option<String>,
+
+    /// Where traces are written.
+    ///
+    /// `file` exports OTLP JSON to the data directory, `stderr` prints plain
+    /// lines to the Zellij log, and `none` disables tracing. Default: `file`
+    pub trace_output: observability::TraceOutput,
+
+    /// Extra OTLP resource attributes added to every exported trace.
+    ///
+    /// Parsed from comma-separated `key=value` pairs, e.g.
+    /// `"host.name=laptop,deployment.environment=dev"`. Default: empty
+    pub trace_attributes: Vec<(String, String)>,
+
+    /// Trace file size in megabytes above which it is rotated.
+    ///
+    /// Must be positive. Default: `10`
+    pub trace_max_size_mb: u64,
+
+    /// Number of rotated trace files kept.
+    ///
+    /// Must be positive. Default: `3`
+    pub trace_backups: usize,
+
+    /// Whether filesystem events rescan only the changed directory.
+    ///
+    /// When enabled, changes under a scan root trigger a scan of the affected
+    /// subtree only; changes outside all roots still trigger a full scan.
+    /// Default: `true`
+    pub scoped_rescan: bool,
+
+    /// How project markers are discovered.
+    ///
+    /// `find` runs `find` on the host (needs `RunCommands`); `fd` runs `fd`,
+    /// which is faster and respects `.gitignore`, falling back to `find` if `fd`
+    /// cannot run; `walk` reads the host filesystem directly (needs only
+    /// `FullHdAccess`); `auto` uses `find` and switches to `walk` if `find`
+    /// cannot run. Also read from the `scanner` key.
+    /// Default: `auto`
+    pub scan_backend: ScanBackendKind,
+
+    /// Whether projects with active sessions are listed first in mixed views.
+    ///
+    /// Frecency order is preserved within each group. Default: `false`
+    pub sessions_first: bool,
+
+    /// Whether the current session is listed first in the Sessions view.
+    ///
+    /// Default: `false`
+    pub current_session_first: bool,
+
+    /// Whether the current session is left out of the Sessions view.
+    ///
+    /// Switching to the session the plugin runs in is a no-op. Default: `false`
+    pub hide_current_session: bool,
+
+    /// Number of projects loaded per page when the plugin starts.
+    ///
+    /// More pages are loaded while scrolling towards the end of the list, and
+    /// all remaining projects once a search starts. `0` loads every project at
+    /// once. Default: `0`
+    pub projects_page_size: usize,
+
+    /// Maximum number of fuzzy-match highlight ranges per row.
+    ///
+    /// Bounds render cost for pathological matches; the longest runs are kept.
+    /// Default: `32`
+    pub max_highlight_ranges: usize,
+
+    /// Pane count above which killing a session asks for confirmation.
+    ///
+    /// When set, pressing `K` on a session with more terminal panes than this
+    /// only prompts; pressing `K` again kills it. `0` confirms every kill.
+    /// Default: `None` (kill immediately)
+    pub kill_confirm_panes: Option<usize>,
+
+    /// Whether to restore the last selected project when the plugin reopens.
+    ///
+    /// The selected project's path is stored on close and looked up again once
+    /// projects load, so reordering does not change which project is selected.
+    /// Default: `true`
+    pub remember_selection: bool,
+
+    /// Whether to mark the last-known sessions active on startup.
+    ///
+    /// Sessions stored by the previous run are shown as active until Zellij
+    /// reports the live session list, which then replaces them.
+    /// Default: `true`
+    pub persist_sessions: bool,
+
+    /// Whether the PATH column is shown initially.
+    ///
+    /// Can be toggled at runtime with `P`.
+    /// Default: `true`
+    pub show_path: bool,
+
+    /// What Enter does in each view mode.
+    ///
+    /// Comma-separated `view=action` pairs, where view is `sessions`,
+    /// `projects`, or `all` and action is `session` or `tab`. A bare action
+    /// applies to every view. Default: `session` everywhere
+    pub enter_action: EnterActions,
+
+    /// Keys of the remappable Normal mode commands.
+    ///
+    /// Set with `key_<command>` options (e.g. `key_down "e"`), `"none"`
+    /// disables a command. Default: the built-in keys (see [`app::keys`])
+    pub key_bindings: app::keys::KeyBindings,
+
+    /// Seconds without a key press after which the plugin hides itself.
+    ///
+    /// `0` disables auto-close. Default: `0`
+    pub auto_close_secs: u32,
+
+    /// Whether the worker appends each handled operation to a log file.
+    ///
+    /// Written to `zessionizer-worker-ops.json` in the data directory, with the
+    /// same size-based rotation as trace files. Default: `false`
+    pub operation_log: bool,
+
+    /// Directory holding storage, UI state, and trace files.
+    ///
+    /// Tilde-expanded and placed under `/host` (see
+    /// [`infrastructure::resolve_data_dir`]). Default: `None`
+    /// (`~/.local/share/zellij/zessionizer`)
+    pub data_dir: Option<String>,
+
+    /// Editor command used to open files such as project layouts.
+    ///
+    /// Split on whitespace (e.g. `"code --wait"`). Falls back to `$EDITOR`,
+    /// then `vi`. Default: `None`
+    pub editor: Option<String>,
+
+    /// Text drawn between the NAME and PATH columns, in the border color.
+    ///
+    /// Used verbatim, so surrounding spaces are kept (e.g. `"│ "`).
+    /// Default: `""` (plain spacing)
+    pub column_separator: String,
+
+    /// Whether the footer ends with `[visible/total]` project counts.
+    ///
+    /// Counts stay visible when the keybinding hints are truncated.
+    /// Default: `false`
+    pub footer_show_counts: bool,
+
+    /// Whether moving past the last or first project wraps around.
+    ///
+    /// When disabled, the selection stops at either end. Default: `true`
+    pub wrap_navigation: bool,
+
+    /// Fields matched by search queries: `name`, `path`, or `both`.
+    ///
+    /// Cycled at runtime with `Tab`. Default: `name`
+    pub search_scope: SearchScope,
+
+    /// How the PATH column shows paths: `absolute`, `relative_to_scan_path`
+    /// (e.g. `foo` under `~/Projects`), or `home_tilde` (`~/Projects/foo`).
+    /// Default: `absolute`
+    pub path_display: PathDisplay,
+
+    /// Layout density: `comfortable` or `compact`.
+    ///
+    /// `compact` drops the blank top line and the borders around the list,
+    /// giving small panes more list rows. Default: `comfortable`
+    pub layout_density: LayoutDensity,
+
+    /// Whether `F` resets the frecency data of every project.
+    ///
+    /// Off by default so a stray key press cannot wipe the access history.
+    /// Default: `false`
+    pub allow_reset_all: bool,
+
+    /// Whether the worker reports project lists by hash only.
+    ///
+    /// The full list is fetched only when the hash differs from the current
+    /// one, reducing IPC payloads for large, unchanged lists. Default: `false`
+    pub hash_only_responses: bool,
+
+    /// Whether the storage file is pretty-printed.
+    ///
+    /// Disable for smaller, faster writes on large stores. Default: `true`
+    pub storage_pretty: bool,
+
+    /// Frecency decay half-life in hours.
+    ///
+    /// Shorter values favor recently opened projects; longer values favor
+    /// frequently opened ones. Non-positive values fall back to the default.
+    /// Default: `168.0` (1 week)
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use zessionizer::Config;
+    ///
+    /// let half_life = |value: &str| {
+    ///     let map = BTreeMap::from([("frecency_half_life".to_string(), value.to_string())]);
+    ///     Config::from_zellij(&map).frecency_half_life_hours
+    /// };
+    /// assert_eq!(half_life("12.5"), 12.5);
+    /// for fallback in ["a day", "", "0", "-24", "NaN", "inf"] {
+    ///     assert_eq!(half_life(fallback), 168.0, "{fallback}");
+    /// }
+    /// assert_eq!(Config::from_zellij(&BTreeMap::new()).frecency_half_life_hours, 168.0);
+    /// ```
+    pub frecency_half_life_hours: f64,
+
+    /// Storage backend used by the worker (`json` or `sqlite`).
+    ///
+    /// `sqlite` requires building with the `sqlite` feature; otherwise JSON is
+    /// used. The two backends use separate files and do not share data.
+    /// Default: `json`
+    pub storage_backend: storage::StorageBackendKind,
 }
 
 impl Default for Config {
@@ -243,19 +486,89 @@ impl Default for Config {
         Self {
             scan_paths: vec!["~/Projects".to_string()],
             scan_depth: 4,
+            path_exclude: Vec::new(),
+            exclude_paths: Vec::new(),
+            import_file: None,
             theme_name: None,
             theme_file: None,
+            color_mode: ui::theme::ColorMode::TrueColor,
             trace_level: None,
+            trace_output: observability::TraceOutput::File,
+            trace_max_size_mb: observability::file_writer::DEFAULT_MAX_FILE_SIZE_MB,
+            trace_backups: observability::file_writer::DEFAULT_MAX_BACKUP_FILES,
+            trace_attributes: Vec::new(),
+            scoped_rescan: true,
+            scan_backend: ScanBackendKind::Auto,
+            sessions_first: false,
+            current_session_first: false,
+            hide_current_session: false,
+            projects_page_size: 0,
+            max_highlight_ranges: app::state::DEFAULT_MAX_HIGHLIGHT_RANGES,
+            kill_confirm_panes: None,
+            remember_selection: true,
+            persist_sessions: true,
+            show_path: true,
+            enter_action: EnterActions::default(),
+            key_bindings: app::keys::KeyBindings::default(),
+            auto_close_secs: 0,
+            operation_log: false,
+            data_dir: None,
+            editor: None,
+            column_separator: String::new(),
+            footer_show_counts: false,
+            wrap_navigation: true,
+            search_scope: SearchScope::Name,
+            path_display: PathDisplay::Absolute,
+            layout_density: LayoutDensity::Comfortable,
+            allow_reset_all: false,
+            hash_only_responses: false,
+            storage_pretty: true,
+            frecency_half_life_hours: storage::DEFAULT_HALF_LIFE_HOURS,
+            storage_backend: storage::StorageBackendKind::Json,
         }
     }
 }
 
+/// An invalid configuration value that was replaced by its default.
+///
+/// Displayed as `invalid {key} '{value}', using {fallback}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// Configuration key, e.g. `scan_depth`.
+    pub key: String,
+
+    /// Value given in the configuration.
+    pub value: String,
+
+    /// Description of the value used instead.
+    pub fallback: String,
+}
+
+impl ConfigWarning {
+    /// Creates a warning for `key`.
+    #[must_use]
+    pub fn new(key: &str, value: &str, fallback: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            value: value.to_string(),
+            fallback: fallback.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {} '{}', using {}", self.key, self.value, self.fallback)
+    }
+}
+
 impl Config {
     /// Parses configuration from Zellij's configuration map.
     ///
     /// Zellij provides configuration as a `BTreeMap<String, String>` during
     /// plugin initialization. This function extracts and parses typed values
-    /// with fallback defaults.
+    /// with fallback defaults. Use [`Config::from_zellij_with_warnings`] to
+    /// learn which values were invalid.
     ///
     /// # Parameters
     ///
@@ -264,10 +577,47 @@ impl Config {
     /// # Parsing Rules
     ///
     /// - `scan_paths`: Comma-separated string → `Vec<String>` (filters empty values)
-    /// - `scan_depth`: String → `u32` (falls back to 4 on parse error)
+    /// - `scan_depth`: String → `u32` (falls back to 4 on parse error, clamped to 1-10)
+    /// - `path_exclude`: Comma-separated string → `Vec<String>` (filters empty values)
+    /// - `exclude_paths`: Comma-separated string → `Vec<String>` (filters empty values)
+    /// - `import_file`: String → `Option<String>`
     /// - `theme`: String → `Option<String>`
     /// - `theme_file`: String → `Option<String>`
+    /// - `color_mode`: `"auto"`/`"truecolor"`/`"256"`/`"16"` → [`ui::theme::ColorMode`] (falls back to truecolor)
     /// - `trace_level`: String → `Option<String>`
+    /// - `trace_output`: `"file"`/`"stderr"`/`"none"` → [`observability::TraceOutput`] (falls back to `file`)
+    /// - `trace_attributes`: `"key=value,..."` → `Vec<(String, String)>` (entries without `=` ignored)
+    /// - `trace_max_size_mb`: String → `u64` (falls back to 10 if not positive)
+    /// - `trace_backups`: String → `usize` (falls back to 3 if not positive)
+    /// - `scoped_rescan`: `"true"`/`"false"` → `bool` (falls back to `true`)
+    /// - `scan_backend` (or `scanner`): `"auto"`/`"find"`/`"fd"`/`"walk"` → [`ScanBackendKind`] (falls back to `auto`)
+    /// - `sessions_first`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `current_session_first`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `hide_current_session`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `projects_page_size`: String → `usize` (falls back to 0, load all, on parse error)
+    /// - `max_highlight_ranges`: String → `usize` (falls back to 32 on parse error)
+    /// - `kill_confirm_panes`: String → `Option<usize>` (unset on parse error)
+    /// - `remember_selection`: `"true"`/`"false"` → `bool` (falls back to `true`)
+    /// - `persist_sessions`: `"true"`/`"false"` → `bool` (falls back to `true`)
+    /// - `show_path`: `"true"`/`"false"` → `bool` (falls back to `true`)
+    /// - `enter_action`: `"view=action,..."` → [`EnterActions`] (unknown entries ignored)
+    /// - `key_<command>`: single character or `"none"` → [`KeyBindings`](app::keys::KeyBindings)
+    ///   (falls back to the default key)
+    /// - `auto_close_secs`: String → `u32` (falls back to 0, disabled, on parse error)
+    /// - `operation_log`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `data_dir`: String → `Option<String>` (empty values ignored)
+    /// - `editor`: String → `Option<String>` (empty values ignored)
+    /// - `column_separator`: String → `String` (kept verbatim, including spaces)
+    /// - `footer_show_counts`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `wrap_navigation`: `"true"`/`"false"` → `bool` (falls back to `true`)
+    /// - `search_scope`: `"name"`/`"path"`/`"both"` → [`SearchScope`] (falls back to `name`)
+    /// - `path_display`: `"absolute"`/`"relative_to_scan_path"`/`"home_tilde"` → [`PathDisplay`] (falls back to `absolute`)
+    /// - `layout_density`: `"comfortable"`/`"compact"` → [`LayoutDensity`] (falls back to `comfortable`)
+    /// - `allow_reset_all`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `hash_only_responses`: `"true"`/`"false"` → `bool` (falls back to `false`)
+    /// - `storage_pretty`: `"true"`/`"false"` → `bool` (falls back to `true`)
+    /// - `frecency_half_life`: String → `f64` hours (falls back to 168.0 on parse error or non-positive values)
+    /// - `storage_backend`: `"json"`/`"sqlite"` → [`storage::StorageBackendKind`] (falls back to `json`)
     ///
     /// # Example
     ///
@@ -285,30 +635,398 @@ impl Config {
     /// ```
     #[must_use]
     pub fn from_zellij(config: &BTreeMap<String, String>) -> Self {
+        Self::from_zellij_with_warnings(config).0
+    }
+
+    /// Parses configuration like [`Config::from_zellij`], also returning a
+    /// warning for every value that was invalid and replaced by its default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use zessionizer::Config;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert("scan_depth".to_string(), "abc".to_string());
+    ///
+    /// let (config, warnings) = Config::from_zellij_with_warnings(&map);
+    /// assert_eq!(config.scan_depth, 4);
+    /// assert_eq!(warnings[0].to_string(), "invalid scan_depth 'abc', using 4");
+    ///
+    /// // Every malformed field produces exactly one warning
+    /// for (key, value) in [
+    ///     ("scan_depth", "deep"),
+    ///     ("theme", "solarized"),
+    ///     ("color_mode", "8"),
+    ///     ("trace_output", "syslog"),
+    ///     ("trace_max_size_mb", "0"),
+    ///     ("trace_backups", "-1"),
+    ///     ("scoped_rescan", "yes"),
+    ///     ("scan_backend", "locate"),
+    ///     ("sessions_first", "1"),
+    ///     ("current_session_first", "on"),
+    ///     ("hide_current_session", "sometimes"),
+    ///     ("projects_page_size", "lots"),
+    ///     ("max_highlight_ranges", "-1"),
+    ///     ("kill_confirm_panes", "many"),
+    ///     ("remember_selection", "nope"),
+    ///     ("persist_sessions", "maybe"),
+    ///     ("show_path", "visible"),
+    ///     ("auto_close_secs", "soon"),
+    ///     ("operation_log", "y"),
+    ///     ("footer_show_counts", "n"),
+    ///     ("wrap_navigation", "around"),
+    ///     ("search_scope", "title"),
+    ///     ("path_display", "short"),
+    ///     ("layout_density", "dense"),
+    ///     ("allow_reset_all", "yes"),
+    ///     ("hash_only_responses", "t"),
+    ///     ("storage_pretty", "f"),
+    ///     ("frecency_half_life", "0"),
+    ///     ("storage_backend", "redis"),
+    ///     ("key_down", "down"),
+    /// ] {
+    ///     let map = BTreeMap::from([(key.to_string(), value.to_string())]);
+    ///     let (_, warnings) = Config::from_zellij_with_warnings(&map);
+    ///     assert_eq!(warnings.len(), 1, "{key}");
+    ///     assert_eq!(warnings[0].key, key);
+    /// }
+    ///
+    /// let valid = BTreeMap::from([("scan_depth".to_string(), "3".to_string())]);
+    /// assert!(Config::from_zellij_with_warnings(&valid).1.is_empty());
+    /// ```
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_zellij_with_warnings(config: &BTreeMap<String, String>) -> (Self, Vec<ConfigWarning>) {
+        let mut warnings = Vec::new();
+
         let scan_paths = config
             .get("scan_paths")
-            .map(|s| {
-                s.split(',')
-                    .map(str::trim)
-                    .filter(|p| !p.is_empty())
-                    .map(String::from)
-                    .collect::<Vec<_>>()
-            })
+            .map(|s| Self::parse_list(s))
             .filter(|v: &Vec<String>| !v.is_empty())
             .unwrap_or_else(|| vec!["~/Projects".to_string()]);
 
-        let scan_depth = config
-            .get("scan_depth")
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or(4);
+        let scan_depth = Self::parse_scan_depth(config, &mut warnings);
 
-        Self {
+        let theme_name = config.get("theme").cloned();
+        if let Some(name) = theme_name.as_deref().filter(|name| !ui::theme::BUILTIN_THEMES.contains(name)) {
+            warnings.push(ConfigWarning::new("theme", name, "catppuccin-mocha"));
+        }
+
+        let scan_backend_key = if config.contains_key("scan_backend") { "scan_backend" } else { "scanner" };
+
+        let parsed = Self {
             scan_paths,
             scan_depth,
-            theme_name: config.get("theme").cloned(),
+            path_exclude: config
+                .get("path_exclude")
+                .map(|s| Self::parse_list(s))
+                .unwrap_or_default(),
+            exclude_paths: config
+                .get("exclude_paths")
+                .map(|s| Self::parse_list(s))
+                .unwrap_or_default(),
+            import_file: config.get("import_file").cloned(),
+            theme_name,
             theme_file: config.get("theme_file").cloned(),
+            color_mode: Self::parse_value(config, "color_mode", "truecolor", &mut warnings, ui::theme::ColorMode::parse)
+                .unwrap_or_default(),
             trace_level: config.get("trace_level").cloned(),
+            trace_output: Self::parse_value(config, "trace_output", "file", &mut warnings, observability::TraceOutput::parse)
+                .unwrap_or_default(),
+            trace_attributes: config
+                .get("trace_attributes")
+                .map(|s| Self::parse_pairs(s))
+                .unwrap_or_default(),
+            trace_max_size_mb: Self::parse_positive(
+                config,
+                "trace_max_size_mb",
+                observability::file_writer::DEFAULT_MAX_FILE_SIZE_MB,
+                &mut warnings,
+            ),
+            trace_backups: Self::parse_positive(
+                config,
+                "trace_backups",
+                observability::file_writer::DEFAULT_MAX_BACKUP_FILES,
+                &mut warnings,
+            ),
+            scoped_rescan: Self::parse_bool(config, "scoped_rescan", true, &mut warnings),
+            scan_backend: Self::parse_value(config, scan_backend_key, "auto", &mut warnings, ScanBackendKind::parse)
+                .unwrap_or_default(),
+            sessions_first: Self::parse_bool(config, "sessions_first", false, &mut warnings),
+            current_session_first: Self::parse_bool(config, "current_session_first", false, &mut warnings),
+            hide_current_session: Self::parse_bool(config, "hide_current_session", false, &mut warnings),
+            projects_page_size: Self::parse_value(config, "projects_page_size", "0", &mut warnings, |s| {
+                s.parse::<usize>().ok()
+            })
+            .unwrap_or(0),
+            max_highlight_ranges: Self::parse_value(
+                config,
+                "max_highlight_ranges",
+                &app::state::DEFAULT_MAX_HIGHLIGHT_RANGES.to_string(),
+                &mut warnings,
+                |s| s.parse::<usize>().ok(),
+            )
+            .unwrap_or(app::state::DEFAULT_MAX_HIGHLIGHT_RANGES),
+            kill_confirm_panes: Self::parse_value(config, "kill_confirm_panes", "no threshold", &mut warnings, |s| {
+                s.parse::<usize>().ok()
+            }),
+            remember_selection: Self::parse_bool(config, "remember_selection", true, &mut warnings),
+            persist_sessions: Self::parse_bool(config, "persist_sessions", true, &mut warnings),
+            show_path: Self::parse_bool(config, "show_path", true, &mut warnings),
+            enter_action: config
+                .get("enter_action")
+                .map(|s| EnterActions::parse(s))
+                .unwrap_or_default(),
+            key_bindings: Self::parse_key_bindings(config, &mut warnings),
+            auto_close_secs: Self::parse_value(config, "auto_close_secs", "0", &mut warnings, |s| s.parse::<u32>().ok())
+                .unwrap_or(0),
+            operation_log: Self::parse_bool(config, "operation_log", false, &mut warnings),
+            data_dir: config
+                .get("data_dir")
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            editor: config
+                .get("editor")
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            column_separator: config.get("column_separator").cloned().unwrap_or_default(),
+            footer_show_counts: Self::parse_bool(config, "footer_show_counts", false, &mut warnings),
+            wrap_navigation: Self::parse_bool(config, "wrap_navigation", true, &mut warnings),
+            search_scope: Self::parse_value(config, "search_scope", "name", &mut warnings, SearchScope::parse)
+                .unwrap_or_default(),
+            path_display: Self::parse_value(config, "path_display", "absolute", &mut warnings, PathDisplay::parse)
+                .unwrap_or_default(),
+            layout_density: Self::parse_value(
+                config,
+                "layout_density",
+                "comfortable",
+                &mut warnings,
+                LayoutDensity::parse,
+            )
+            .unwrap_or_default(),
+            allow_reset_all: Self::parse_bool(config, "allow_reset_all", false, &mut warnings),
+            hash_only_responses: Self::parse_bool(config, "hash_only_responses", false, &mut warnings),
+            storage_pretty: Self::parse_bool(config, "storage_pretty", true, &mut warnings),
+            frecency_half_life_hours: Self::parse_value(
+                config,
+                "frecency_half_life",
+                &storage::DEFAULT_HALF_LIFE_HOURS.to_string(),
+                &mut warnings,
+                |s| s.parse::<f64>().ok().filter(|hours| hours.is_finite() && *hours > 0.0),
+            )
+            .unwrap_or(storage::DEFAULT_HALF_LIFE_HOURS),
+            storage_backend: Self::parse_value(
+                config,
+                "storage_backend",
+                "json",
+                &mut warnings,
+                storage::StorageBackendKind::parse,
+            )
+            .unwrap_or_default(),
+        };
+
+        (parsed, warnings)
+    }
+
+    /// Returns the resolved data directory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use std::path::PathBuf;
+    /// use zessionizer::Config;
+    ///
+    /// let default = Config::default();
+    /// assert_eq!(default.data_dir(), PathBuf::from("/host/.local/share/zellij/zessionizer"));
+    ///
+    /// let map = BTreeMap::from([("data_dir".to_string(), "~/.zessionizer".to_string())]);
+    /// let config = Config::from_zellij(&map);
+    /// assert_eq!(config.data_dir(), PathBuf::from("/host/.zessionizer"));
+    /// assert_eq!(config.worker_settings().data_dir, config.data_dir());
+    /// ```
+    #[must_use]
+    pub fn data_dir(&self) -> std::path::PathBuf {
+        infrastructure::resolve_data_dir(self.data_dir.as_deref())
+    }
+
+    /// Returns the trace file rotation built from `trace_max_size_mb` and
+    /// `trace_backups`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use zessionizer::Config;
+    ///
+    /// let map = BTreeMap::from([
+    ///     ("trace_max_size_mb".to_string(), "2".to_string()),
+    ///     ("trace_backups".to_string(), "5".to_string()),
+    /// ]);
+    /// let rotation = Config::from_zellij(&map).trace_rotation();
+    /// assert_eq!(rotation.max_size_bytes, 2 * 1024 * 1024);
+    /// assert_eq!(rotation.max_backups, 5);
+    ///
+    /// let map = BTreeMap::from([("trace_backups".to_string(), "0".to_string())]);
+    /// assert_eq!(Config::from_zellij(&map).trace_rotation().max_backups, 3);
+    /// ```
+    #[must_use]
+    pub const fn trace_rotation(&self) -> observability::Rotation {
+        observability::Rotation {
+            max_size_bytes: self.trace_max_size_mb.saturating_mul(1024 * 1024),
+            max_backups: self.trace_backups,
+        }
+    }
+
+    /// Returns the subset of configuration applied inside the worker thread.
+    #[must_use]
+    pub fn worker_settings(&self) -> worker::WorkerSettings {
+        worker::WorkerSettings {
+            data_dir: self.data_dir(),
+            operation_log: self.operation_log,
+            log_rotation: self.trace_rotation(),
+            hash_only_responses: self.hash_only_responses,
+            storage_pretty: self.storage_pretty,
+            frecency_half_life_hours: self.frecency_half_life_hours,
+            storage_backend: self.storage_backend,
+        }
+    }
+
+    /// Parses `scan_depth`, clamping it to
+    /// [`MIN_SCAN_DEPTH`](infrastructure::scanner::MIN_SCAN_DEPTH)..=[`MAX_SCAN_DEPTH`](infrastructure::scanner::MAX_SCAN_DEPTH).
+    ///
+    /// Out-of-range values record a warning naming the depth used instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use zessionizer::Config;
+    ///
+    /// for (value, expected, warned) in [("0", 1, true), ("100", 10, true), ("6", 6, false)] {
+    ///     let map = BTreeMap::from([("scan_depth".to_string(), value.to_string())]);
+    ///     let (config, warnings) = Config::from_zellij_with_warnings(&map);
+    ///     assert_eq!(config.scan_depth, expected);
+    ///     assert_eq!(!warnings.is_empty(), warned);
+    /// }
+    /// ```
+    fn parse_scan_depth(config: &BTreeMap<String, String>, warnings: &mut Vec<ConfigWarning>) -> u32 {
+        use infrastructure::scanner::{MAX_SCAN_DEPTH, MIN_SCAN_DEPTH};
+
+        let Some(depth) = Self::parse_value(config, "scan_depth", "4", warnings, |s| s.parse::<u32>().ok()) else {
+            return 4;
+        };
+
+        let clamped = depth.clamp(MIN_SCAN_DEPTH, MAX_SCAN_DEPTH);
+        if clamped != depth {
+            warnings.push(ConfigWarning::new("scan_depth", &depth.to_string(), &clamped.to_string()));
+        }
+        clamped
+    }
+
+    /// Parses the `key_<command>` options over the default key bindings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use zessionizer::{Config, Event};
+    ///
+    /// let map = BTreeMap::from([
+    ///     ("key_down".to_string(), "e".to_string()),
+    ///     ("key_quit".to_string(), "none".to_string()),
+    /// ]);
+    /// let (config, warnings) = Config::from_zellij_with_warnings(&map);
+    /// assert!(warnings.is_empty());
+    /// assert_eq!(config.key_bindings.event_for('e'), Some(Event::KeyDown));
+    /// assert_eq!(config.key_bindings.event_for('j'), None);
+    /// assert_eq!(config.key_bindings.event_for('q'), None);
+    /// assert_eq!(config.key_bindings.event_for('k'), Some(Event::KeyUp));
+    /// ```
+    fn parse_key_bindings(config: &BTreeMap<String, String>, warnings: &mut Vec<ConfigWarning>) -> app::keys::KeyBindings {
+        let mut bindings = app::keys::KeyBindings::default();
+
+        for name in app::keys::KeyBindings::names() {
+            let option = format!("key_{name}");
+            let fallback = bindings.key(name).map_or_else(|| "none".to_string(), String::from);
+            if let Some(key) = Self::parse_value(config, &option, &fallback, warnings, app::keys::KeyBindings::parse_key) {
+                bindings.set(name, key);
+            }
         }
+        bindings
+    }
+
+    /// Parses a comma-separated list, trimming entries and dropping empty ones.
+    fn parse_list(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    /// Parses comma-separated `key=value` pairs, skipping malformed entries.
+    fn parse_pairs(value: &str) -> Vec<(String, String)> {
+        value
+            .split(',')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect()
+    }
+
+    /// Parses a boolean option, falling back to `default` when missing or invalid.
+    ///
+    /// Invalid values are recorded in `warnings`.
+    fn parse_bool(
+        config: &BTreeMap<String, String>,
+        key: &str,
+        default: bool,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> bool {
+        Self::parse_value(config, key, &default.to_string(), warnings, |s| s.parse::<bool>().ok())
+            .unwrap_or(default)
+    }
+
+    /// Parses a positive integer option, falling back to `default` when
+    /// missing, invalid, or zero.
+    ///
+    /// Invalid values are recorded in `warnings`.
+    fn parse_positive<T>(
+        config: &BTreeMap<String, String>,
+        key: &str,
+        default: T,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> T
+    where
+        T: std::str::FromStr + Default + PartialOrd + ToString,
+    {
+        Self::parse_value(config, key, &default.to_string(), warnings, |s| {
+            s.parse::<T>().ok().filter(|value| *value > T::default())
+        })
+        .unwrap_or(default)
+    }
+
+    /// Parses an option with `parse`, recording a warning if it is set but invalid.
+    ///
+    /// Values are trimmed before parsing. Returns `None` when the option is
+    /// missing or invalid; `fallback` describes the value used instead.
+    fn parse_value<T>(
+        config: &BTreeMap<String, String>,
+        key: &str,
+        fallback: &str,
+        warnings: &mut Vec<ConfigWarning>,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Option<T> {
+        let value = config.get(key)?;
+        let parsed = parse(value.trim());
+        if parsed.is_none() {
+            warnings.push(ConfigWarning::new(key, value, fallback));
+        }
+        parsed
     }
 }
 
@@ -349,7 +1067,7 @@ impl Config {
 pub fn initialize(config: &Config) -> AppState {
     tracing::debug!("initializing zessionizer plugin");
 
-    let theme = config.theme_file.as_ref().map_or_else(
+    let mut theme = config.theme_file.as_ref().map_or_else(
         || {
             config.theme_name.as_ref().map_or_else(
                 Theme::default,
@@ -369,5 +1087,29 @@ pub fn initialize(config: &Config) -> AppState {
         },
     );
 
-    AppState::new(vec![], theme)
+    theme.color_mode = config.color_mode;
+    theme.no_color = ui::theme::no_color_requested();
+
+    let mut state = AppState::new(vec![], theme);
+    state.path_exclude.clone_from(&config.path_exclude);
+    state.sessions_first = config.sessions_first;
+    state.current_session_first = config.current_session_first;
+    state.hide_current_session = config.hide_current_session;
+    state.max_highlight_ranges = config.max_highlight_ranges;
+    state.projects_page_size = config.projects_page_size;
+    state.kill_confirm_panes = config.kill_confirm_panes;
+    state.remember_selection = config.remember_selection;
+    state.persist_sessions = config.persist_sessions;
+    state.show_path = config.show_path;
+    state.enter_actions = config.enter_action;
+    state.column_separator.clone_from(&config.column_separator);
+    state.footer_show_counts = config.footer_show_counts;
+    state.wrap_navigation = config.wrap_navigation;
+    state.search_scope = config.search_scope;
+    state.path_display = config.path_display;
+    state.layout_density = config.layout_density;
+    state.allow_reset_all = config.allow_reset_all;
+    state.scan_paths.clone_from(&config.scan_paths);
+    state.frecency_half_life_hours = config.frecency_half_life_hours;
+    state
 }
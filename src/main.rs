@@ -28,6 +28,7 @@
 //!
 //! 1. **Load**: Parse config, initialize tracing, create `AppState`
 //! 2. **Subscribe**: Register for Key, `SessionUpdate`, `CustomMessage`, `Timer` events
+//!    (`Timer` drives the optional idle auto-close and expires footer notices)
 //! 3. **Initial Scan**: Run `find` command to discover projects
 //! 4. **Periodic Scan**: Re-scan filesystem on timer intervals
 //! 5. **Update**: Handle events, delegate to library layer
@@ -47,7 +48,7 @@
 //! - `Key(Down)` → `Event::KeyDown`
 //! - `Key(Enter)` → `Event::SelectProject` (unless typing in search)
 //! - `Key(Esc)` → `Event::ExitSearch` (in search mode)
-//! - `SessionUpdate` → `Event::SessionUpdate { active_sessions }`
+//! - `SessionUpdate` → `Event::SessionUpdate { active_sessions, session_cwds, .. }`
 //! - `RunCommandResult` → `Event::ProjectsScanned { git_directories }`
 //!
 //! # Keybindings
@@ -55,19 +56,54 @@
 //! Global (all modes):
 //! - `Ctrl+n`: Move down
 //! - `Ctrl+p`: Move up
+//! - `Ctrl+d`: Move down one page
+//! - `Ctrl+u`: Move up one page (clears the query while typing a search)
+//! - `Ctrl+Enter`: Open selected project in a new tab
 //!
-//! In normal mode:
+//! In normal mode (character keys can be remapped, see `zessionizer::app::keys`):
 //! - `j`/`Down`: Move down
 //! - `k`/`Up`: Move up
+//! - `g`: Jump to the first project
+//! - `G` (shift): Jump to the last project
 //! - `Enter`: Select project
 //! - `q`: Close plugin
 //! - `/`: Enter search mode
 //! - `n`: Show projects view
 //! - `s`: Show sessions view
-//! - `K` (shift): Kill selected session
+//! - `a`: Show all projects view
+//! - `P` (shift): Toggle path column
+//! - `x`: Expand the selected project's path
+//! - `L` (shift): Edit selected project's layout file
+//! - `e`: Open the selected project in the editor, without a session
+//! - `l`: Pick the layout used to create the selected project's session
+//! - `y`: Copy the switch command for the selected project
+//! - `d`: Remove the selected project from storage
+//! - `p`: Pin or unpin the selected project
+//! - `f`: Reset the selected project's frecency
+//! - `F` (shift): Reset every project's frecency (needs `allow_reset_all`)
+//! - `o`: Cycle sort mode (frecency / alphabetical / recent / frequent)
+//! - `r`: Rename the current session (Sessions view)
+//! - `N` (shift): Create a session for the selected project under a custom name
+//! - `Space`: Mark or unmark the selected session (Sessions view)
+//! - `K` (shift): Kill marked sessions, or the selected session
+//!
+//! In the layout picker:
+//! - `j`/`k`/`Down`/`Up`: Move selection
+//! - `Enter`: Use the selected layout
+//! - `Esc`: Close the picker
+//!
+//! While renaming or naming a new session:
+//! - Type to edit the name
+//! - `Enter`: Apply the name
+//! - `Esc`: Cancel
 //!
 //! In search mode:
 //! - `j`/`k`/etc.: Type characters
+//! - `Up`/`Down` (empty query): Recall recent searches
+//! - `Left`/`Right`: Move the cursor within the query
+//! - `Ctrl+w`: Delete the word before the cursor
+//! - `Ctrl+u`: Clear the query
+//! - `Tab`: Cycle search scope (name / name+path / path)
 //! - `Enter`: Select project
 //! - `Esc`: Exit search
 //! - `/`: Return to search input
@@ -75,16 +111,34 @@
 #![allow(clippy::multiple_crate_versions)]
 
 use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 use zellij_tile::prelude::*;
 use zellij_tile::shim::post_message_to;
 
-use zessionizer::worker::{WorkerMessage, WorkerResponse, ZessionizerWorker};
-use zessionizer::{handle_event, Action, Config, Event, InputMode};
+use zessionizer::app::idle::{Expired, IdleTimer};
+use zessionizer::app::keys::KeyBindings;
+use zessionizer::app::scan_batch::ScanBatch;
+use zessionizer::app::session_cwd;
+use zessionizer::infrastructure::clipboard;
+use zessionizer::infrastructure::discovery::{self, ScanBackend, ScanBackendKind, ScanOutput};
+use zessionizer::infrastructure::editor;
+use zessionizer::infrastructure::import;
+use zessionizer::infrastructure::layout;
+use zessionizer::infrastructure::scanner::{self, ScanTarget};
+use zessionizer::observability::TracingGuard;
+use zessionizer::ui::theme::{theme_file_changed, Theme};
+use zessionizer::worker::{WorkerMessage, WorkerResponse, WorkerSettings, ZessionizerWorker};
+use zessionizer::{handle_event, Action, Config, Event, InputMode, SearchFocus};
 
 // Register plugin and worker with Zellij
 register_plugin!(State);
 register_worker!(ZessionizerWorker, zessionizer_worker, ZESSIONIZER_WORKER);
 
+
+/// Sandbox mount point of the plugin's working directory (the host home).
+const HOST_ROOT: &str = "/host";
+
 /// Plugin state wrapper.
 ///
 /// Wraps the library's `AppState` with Zellij-specific concerns like worker
@@ -99,8 +153,48 @@ struct State {
     /// Configured scan paths (for `find` command).
     scan_paths: Vec<String>,
 
+    /// Scan paths found missing by the last full scan, already reported.
+    missing_scan_paths: Vec<String>,
+
     /// Configured scan depth (for `find` command).
     scan_depth: u32,
+
+    /// Directories pruned from scans.
+    exclude_paths: Vec<String>,
+
+    /// Whether filesystem events rescan only the changed directory.
+    scoped_rescan: bool,
+
+    /// Configured discovery backend selection.
+    scan_backend_kind: ScanBackendKind,
+
+    /// Backend currently used for scans.
+    scan_backend: Box<dyn ScanBackend>,
+
+    /// Scheduled timeouts for the idle auto-close (disabled unless
+    /// `auto_close_secs` is set) and footer notices.
+    idle_timer: IdleTimer,
+
+    /// Scan command results collected until the scan cycle completes.
+    scan_batch: ScanBatch,
+
+    /// Settings sent to the worker before any other message.
+    worker_settings: WorkerSettings,
+
+    /// Keys of the remappable Normal mode commands.
+    key_bindings: KeyBindings,
+
+    /// Configured editor command (falls back to `$EDITOR`).
+    editor: Option<String>,
+
+    /// Configured theme file, reloaded when it changes on disk.
+    theme_file: Option<String>,
+
+    /// Configured list of projects to import once permissions are granted.
+    import_file: Option<String>,
+
+    /// Tracer provider, flushed on close and shut down before unloading.
+    tracing: TracingGuard,
 }
 
 impl Default for State {
@@ -110,7 +204,20 @@ impl Default for State {
             app: zessionizer::initialize(&default_config),
             worker_name: "zessionizer".to_string(),
             scan_paths: Vec::new(),
+            missing_scan_paths: Vec::new(),
             scan_depth: 4,
+            exclude_paths: Vec::new(),
+            scoped_rescan: true,
+            scan_backend_kind: ScanBackendKind::default(),
+            scan_backend: ScanBackendKind::default().backend(&[]),
+            idle_timer: IdleTimer::default(),
+            scan_batch: ScanBatch::default(),
+            worker_settings: WorkerSettings::default(),
+            key_bindings: KeyBindings::default(),
+            editor: None,
+            theme_file: None,
+            import_file: None,
+            tracing: TracingGuard::disabled(),
         }
     }
 }
@@ -131,7 +238,9 @@ impl ZellijPlugin for State {
     /// Requests:
     /// - `ReadApplicationState`: Read session info
     /// - `ChangeApplicationState`: Switch/create/kill sessions
-    /// - `RunCommands`: Execute `find` for project scanning
+    /// - `RunCommands`: Execute `find` for project scanning (not requested
+    ///   with `scan_backend "walk"`; with `"auto"`, a denied request is
+    ///   retried without it and scans use the walk backend)
     /// - `FullHdAccess`: Read filesystem for Git directories
     ///
     /// # Subscriptions
@@ -140,9 +249,13 @@ impl ZellijPlugin for State {
     /// - `SessionUpdate`: Session lifecycle changes
     /// - `CustomMessage`: Worker responses
     /// - `RunCommandResult`: `find` command output
+    /// - `FileSystemCreate`/`FileSystemUpdate`/`FileSystemDelete`: Rescans and
+    ///   theme file reloads
+    /// - `Timer`: Idle auto-close timeouts and footer notice expiry
+    /// - `BeforeClose`: Flushing and shutting down the tracer provider
     fn load(&mut self, configuration: BTreeMap<String, String>) {
-        let config = Config::from_zellij(&configuration);
-        zessionizer::observability::init_tracing(&config);
+        let (config, config_warnings) = Config::from_zellij_with_warnings(&configuration);
+        self.tracing = zessionizer::observability::init_tracing(&config);
 
         let span = tracing::debug_span!("plugin_load");
         let _guard = span.entered();
@@ -152,13 +265,16 @@ impl ZellijPlugin for State {
         self.app = zessionizer::initialize(&config);
         tracing::debug!("app state initialized");
 
+        for warning in &config_warnings {
+            tracing::warn!(key = %warning.key, value = %warning.value, "{warning}");
+        }
+        if !config_warnings.is_empty() {
+            let messages: Vec<String> = config_warnings.iter().map(ToString::to_string).collect();
+            self.app.footer_message = Some(format!("Config: {}", messages.join("; ")));
+        }
+
         tracing::debug!("requesting permissions");
-        request_permission(&[
-            PermissionType::ReadApplicationState,
-            PermissionType::ChangeApplicationState,
-            PermissionType::RunCommands,
-            PermissionType::FullHdAccess,
-        ]);
+        request_permission(&Self::permissions(config.scan_backend != ScanBackendKind::Walk));
 
         tracing::debug!("subscribing to events");
         subscribe(&[
@@ -170,10 +286,22 @@ impl ZellijPlugin for State {
             EventType::FileSystemCreate,
             EventType::FileSystemUpdate,
             EventType::FileSystemDelete,
+            EventType::Timer,
+            EventType::BeforeClose,
         ]);
 
         self.scan_paths.clone_from(&config.scan_paths);
         self.scan_depth = config.scan_depth;
+        self.exclude_paths.clone_from(&config.exclude_paths);
+        self.scoped_rescan = config.scoped_rescan;
+        self.scan_backend_kind = config.scan_backend;
+        self.scan_backend = config.scan_backend.backend(&config.exclude_paths);
+        self.idle_timer = IdleTimer::new(config.auto_close_secs);
+        self.worker_settings = config.worker_settings();
+        self.key_bindings = config.key_bindings.clone();
+        self.editor.clone_from(&config.editor);
+        self.theme_file.clone_from(&config.theme_file);
+        self.import_file.clone_from(&config.import_file);
 
         tracing::debug!("plugin load complete - waiting for permissions");
     }
@@ -205,13 +333,37 @@ impl ZellijPlugin for State {
         let event_name = Self::get_event_name(&event);
         let span_name = format!("plugin_update::{event_name}");
         let span = tracing::debug_span!("plugin_update_event", otel.name = %span_name, event_type = %event_name);
-        let _guard = span.entered();
+        let span_guard = span.entered();
 
         tracing::debug!(event = %event_name, "processing event");
 
+        let mut footer_cleared = false;
+        let from_key = matches!(event, zellij_tile::prelude::Event::Key(_));
         let our_event = match event {
-            zellij_tile::prelude::Event::Key(ref key) => match self.map_key_event(key) {
-                Some(event) => event,
+            zellij_tile::prelude::Event::Key(ref key) => {
+                self.schedule_idle_timeout();
+                let event = self.map_key_event(key);
+                if event != Some(Event::KillSession) && self.app.cancel_pending_kill() {
+                    tracing::debug!("pending kill cancelled");
+                    footer_cleared = true;
+                }
+                if self.app.footer_notice.take().is_some() {
+                    footer_cleared = true;
+                }
+                match event {
+                    Some(event) => event,
+                    None => return footer_cleared,
+                }
+            }
+            zellij_tile::prelude::Event::Timer(_elapsed) => match self.idle_timer.on_timer() {
+                Some(Expired::Idle) => {
+                    tracing::debug!("idle timeout expired - closing plugin");
+                    Event::CloseFocus
+                }
+                Some(Expired::Notice) => {
+                    tracing::debug!("footer notice expired");
+                    return self.app.footer_notice.take().is_some();
+                }
                 None => return false,
             },
             zellij_tile::prelude::Event::CustomMessage(message, payload) => {
@@ -220,8 +372,11 @@ impl ZellijPlugin for State {
                     None => return false,
                 }
             }
-            zellij_tile::prelude::Event::RunCommandResult(exit_code, stdout, stderr, _context) => {
-                Self::map_command_result_event(exit_code, stdout, stderr)
+            zellij_tile::prelude::Event::RunCommandResult(exit_code, stdout, stderr, context) => {
+                match self.map_run_command_result(exit_code, &stdout, &stderr, &context) {
+                    Some(event) => event,
+                    None => return false,
+                }
             }
             zellij_tile::prelude::Event::SessionUpdate(session_infos, _resurrectable_sessions) => {
                 Self::map_session_update_event(&session_infos)
@@ -233,13 +388,21 @@ impl ZellijPlugin for State {
                     path_count = paths.len(),
                     "filesystem change detected - triggering scan"
                 );
-                self.trigger_filesystem_scan();
-                return false;
+                let changed: Vec<PathBuf> = paths.into_iter().map(|(path, _metadata)| path).collect();
+                let theme_reloaded = self.reload_theme_if_changed(&changed);
+                self.trigger_rescan(&changed);
+                return theme_reloaded;
             }
             zellij_tile::prelude::Event::PermissionRequestResult(permissions) => {
                 self.handle_permission_result(permissions);
                 return false;
             }
+            zellij_tile::prelude::Event::BeforeClose => {
+                tracing::debug!("plugin unloading - shutting down tracer provider");
+                drop(span_guard);
+                self.tracing.shutdown();
+                return false;
+            }
             _ => return false,
         };
 
@@ -250,14 +413,17 @@ impl ZellijPlugin for State {
                     should_render = should_render,
                     "event handled successfully"
                 );
+                if from_key && !actions.is_empty() && self.app.footer_message.take().is_some() {
+                    footer_cleared = true;
+                }
                 for a in actions {
                     self.execute_action(&a);
                 }
-                should_render
+                should_render || footer_cleared
             }
             Err(e) => {
                 tracing::debug!(error = %e, "error handling event");
-                false
+                footer_cleared
             }
         }
     }
@@ -271,48 +437,223 @@ impl ZellijPlugin for State {
     /// * `rows` - Terminal height in rows
     /// * `cols` - Terminal width in columns
     fn render(&mut self, rows: usize, cols: usize) {
+        self.app.viewport_rows = rows;
         zessionizer::ui::render(&self.app, rows, cols);
     }
 }
 
 impl State {
-    /// Triggers filesystem scan for .git directories and .zessionizer marker files.
-    fn trigger_filesystem_scan(&self) {
+    /// Triggers filesystem scan for .git entries and .zessionizer marker files.
+    fn trigger_filesystem_scan(&mut self) {
         tracing::debug!(
-            "running find command to scan for .git directories and .zessionizer marker files"
+            backend = self.scan_backend.name(),
+            "scanning for .git entries and .zessionizer marker files"
         );
 
-        for scan_path in &self.scan_paths {
-            let expanded_path = if scan_path.starts_with("~/") {
-                scan_path.strip_prefix("~/").unwrap_or(scan_path)
-            } else if scan_path == "~" {
-                "."
-            } else {
-                scan_path.as_str()
-            };
+        let (existing, missing) = scanner::split_missing_scan_paths(&self.scan_paths, Path::new(HOST_ROOT));
+        self.report_missing_scan_paths(missing);
+        self.run_scans(&scanner::full_scan_targets(&existing, self.scan_depth));
+    }
 
-            tracing::debug!(scan_path = %scan_path, expanded_path = %expanded_path, "scanning path");
-
-            run_command(
-                &[
-                    "find",
-                    expanded_path,
-                    "-maxdepth",
-                    &self.scan_depth.to_string(),
-                    "(",
-                    "-name",
-                    ".git",
-                    "-type",
-                    "d",
-                    "-o",
-                    "-name",
-                    ".zessionizer",
-                    "-type",
-                    "f",
-                    ")",
-                ],
-                BTreeMap::new(),
-            );
+    /// Shows a footer warning when the set of missing scan paths changes.
+    ///
+    /// Missing paths are skipped by full scans and reported once, not on
+    /// every rescan.
+    fn report_missing_scan_paths(&mut self, missing: Vec<String>) {
+        if missing == self.missing_scan_paths {
+            return;
+        }
+
+        for scan_path in &missing {
+            tracing::warn!(scan_path = %scan_path, "scan path does not exist, skipping");
+        }
+        if !missing.is_empty() {
+            let warnings: Vec<String> = missing
+                .iter()
+                .map(|scan_path| format!("scan path {scan_path} does not exist"))
+                .collect();
+            let warning = warnings.join("; ");
+            self.app.footer_message = Some(match self.app.footer_message.take() {
+                Some(message) => format!("{message}; {warning}"),
+                None => warning,
+            });
+        }
+        self.missing_scan_paths = missing;
+    }
+
+    /// Rescans after a filesystem change, limited to the affected directories.
+    ///
+    /// Falls back to a full scan if scoped rescans are disabled or any changed
+    /// path lies outside the configured scan roots.
+    fn trigger_rescan(&mut self, changed: &[PathBuf]) {
+        if !self.scoped_rescan {
+            self.trigger_filesystem_scan();
+            return;
+        }
+
+        if let Some(targets) = scanner::scoped_scan_targets(changed, &self.scan_paths, self.scan_depth) {
+            tracing::debug!(target_count = targets.len(), "running scoped rescan");
+            self.run_scans(&targets);
+        } else {
+            tracing::debug!("change outside scan roots - running full scan");
+            self.trigger_filesystem_scan();
+        }
+    }
+
+    /// Reloads the theme file if it is among the changed paths.
+    ///
+    /// On parse errors the current theme is kept.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a new theme was loaded and the UI should re-render.
+    fn reload_theme_if_changed(&mut self, changed: &[PathBuf]) -> bool {
+        let Some(theme_file) = &self.theme_file else {
+            return false;
+        };
+        if !theme_file_changed(theme_file, changed) {
+            return false;
+        }
+
+        match Theme::from_file(theme_file) {
+            Ok(mut theme) => {
+                theme.color_mode = self.app.theme.color_mode;
+                theme.no_color = self.app.theme.no_color;
+                tracing::debug!(theme_file = %theme_file, theme_name = %theme.name, "theme file reloaded");
+                self.app.theme = theme;
+                true
+            }
+            Err(e) => {
+                tracing::warn!(theme_file = %theme_file, error = %e, "failed to reload theme file, keeping current theme");
+                false
+            }
+        }
+    }
+
+    /// Records key activity and schedules a new idle timeout, if enabled.
+    fn schedule_idle_timeout(&mut self) {
+        if let Some(secs) = self.idle_timer.record_activity(now_secs()) {
+            set_timeout(secs);
+        }
+    }
+
+    /// Runs one scan per target with the current backend.
+    ///
+    /// Command-based scans report back through `RunCommandResult`; markers
+    /// found synchronously are handled immediately as one `ProjectsScanned`.
+    fn run_scans(&mut self, targets: &[ScanTarget]) {
+        let mut markers = Vec::new();
+        let mut commands = Vec::new();
+        let mut synchronous = false;
+
+        for target in targets {
+            tracing::debug!(dir = %target.dir, depth = target.depth, "scanning path");
+
+            match self.scan_backend.scan(target) {
+                ScanOutput::Command(args) => commands.push(args),
+                ScanOutput::Markers(found) => {
+                    synchronous = true;
+                    markers.extend(found);
+                }
+            }
+        }
+
+        if !commands.is_empty() {
+            let generation = self.scan_batch.start(commands.len());
+            let context = BTreeMap::from([(
+                discovery::SCAN_CYCLE_CONTEXT_KEY.to_string(),
+                generation.to_string(),
+            )]);
+            for args in &commands {
+                let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                run_command(&args, context.clone());
+            }
+        }
+
+        if synchronous {
+            self.dispatch(&Event::ProjectsScanned { git_directories: markers });
+        }
+    }
+
+    /// Maps a finished host command to an application event.
+    ///
+    /// Clipboard results are ignored. If the scan command is unavailable, a
+    /// fallback backend is selected and the scan restarted. Scan results are
+    /// collected per scan cycle (see [`ScanBatch`]).
+    ///
+    /// # Returns
+    ///
+    /// The event to handle, or `None` if there is nothing to handle yet.
+    fn map_run_command_result(
+        &mut self,
+        exit_code: Option<i32>,
+        stdout: &[u8],
+        stderr: &[u8],
+        context: &BTreeMap<String, String>,
+    ) -> Option<Event> {
+        if context.get(clipboard::COMMAND_CONTEXT_KEY).map(String::as_str)
+            == Some(clipboard::CLIPBOARD_CONTEXT)
+        {
+            tracing::debug!(exit_code = ?exit_code, "clipboard command finished");
+            return None;
+        }
+        if discovery::command_unavailable(exit_code) {
+            if let Some(fallback) = discovery::fallback_backend(
+                self.scan_backend_kind,
+                self.scan_backend.as_ref(),
+                &self.exclude_paths,
+            ) {
+                tracing::warn!(
+                    exit_code = ?exit_code,
+                    from = self.scan_backend.name(),
+                    to = fallback.name(),
+                    "scan command unavailable - switching scan backend"
+                );
+                self.scan_backend = fallback;
+                self.scan_batch.reset();
+                self.trigger_filesystem_scan();
+                return None;
+            }
+        }
+
+        let event = Self::map_command_result_event(exit_code, stdout, stderr);
+        self.batch_scan_result(context, event)
+    }
+
+    /// Adds a scan command's result to the current scan cycle.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(ProjectsScanned)` with all markers once the cycle completes
+    /// - `None` while other scans of the cycle are outstanding
+    /// - The event unchanged for commands not tagged with a scan cycle
+    fn batch_scan_result(&mut self, context: &BTreeMap<String, String>, event: Event) -> Option<Event> {
+        let Some(generation) = context
+            .get(discovery::SCAN_CYCLE_CONTEXT_KEY)
+            .and_then(|generation| generation.parse::<u64>().ok())
+        else {
+            return Some(event);
+        };
+
+        let markers = match event {
+            Event::ProjectsScanned { git_directories } => Some(git_directories),
+            _ => None,
+        };
+
+        self.scan_batch
+            .record(generation, markers)
+            .map(|git_directories| Event::ProjectsScanned { git_directories })
+    }
+
+    /// Handles an event raised outside `update` and executes its actions.
+    fn dispatch(&mut self, event: &Event) {
+        match handle_event(&mut self.app, event) {
+            Ok((_should_render, actions)) => {
+                for a in actions {
+                    self.execute_action(&a);
+                }
+            }
+            Err(e) => tracing::debug!(error = %e, "error handling event"),
         }
     }
 
@@ -329,6 +670,8 @@ impl State {
             zellij_tile::prelude::Event::FileSystemCreate(..) => "FileSystemCreate".to_string(),
             zellij_tile::prelude::Event::FileSystemUpdate(..) => "FileSystemUpdate".to_string(),
             zellij_tile::prelude::Event::FileSystemDelete(..) => "FileSystemDelete".to_string(),
+            zellij_tile::prelude::Event::Timer(..) => "Timer".to_string(),
+            zellij_tile::prelude::Event::BeforeClose => "BeforeClose".to_string(),
             _ => "Other".to_string(),
         }
     }
@@ -337,64 +680,148 @@ impl State {
     fn map_key_event(&self, key: &KeyWithModifier) -> Option<Event> {
         tracing::debug!(bare_key = ?key.bare_key, "key event");
 
+        if let Some(picker) = &self.app.layout_picker {
+            return match key.bare_key {
+                BareKey::Down | BareKey::Char('j') => Some(Event::KeyDown),
+                BareKey::Up | BareKey::Char('k') => Some(Event::KeyUp),
+                BareKey::Enter => Some(Event::UpdateProjectLayout {
+                    path: picker.project_path.clone(),
+                    layout: picker.selected_layout().map(str::to_string),
+                }),
+                BareKey::Esc => Some(Event::Escape),
+                _ => None,
+            };
+        }
+
+        if self.app.input_mode.edits_session_name() {
+            return match key.bare_key {
+                BareKey::Enter if self.app.input_mode == InputMode::NewSession => Some(Event::ConfirmNewSession),
+                BareKey::Enter => Some(Event::ConfirmRename),
+                BareKey::Esc => Some(Event::Escape),
+                BareKey::Backspace => Some(Event::Backspace),
+                BareKey::Char(c) if key.key_modifiers.is_empty() => Some(Event::Char(c)),
+                _ => None,
+            };
+        }
+
         if key.bare_key == BareKey::Char('n') && key.has_modifiers(&[KeyModifier::Ctrl]) {
             return Some(Event::KeyDown);
         }
         if key.bare_key == BareKey::Char('p') && key.has_modifiers(&[KeyModifier::Ctrl]) {
             return Some(Event::KeyUp);
         }
+        if key.bare_key == BareKey::Char('d') && key.has_modifiers(&[KeyModifier::Ctrl]) {
+            return Some(Event::PageDown);
+        }
+        let typing = self.app.input_mode == InputMode::Search(SearchFocus::Typing);
+        if key.bare_key == BareKey::Char('w') && key.has_modifiers(&[KeyModifier::Ctrl]) && typing {
+            return Some(Event::DeleteWord);
+        }
+        if key.bare_key == BareKey::Char('u') && key.has_modifiers(&[KeyModifier::Ctrl]) {
+            return Some(if typing { Event::ClearQuery } else { Event::PageUp });
+        }
+        if key.bare_key == BareKey::Enter && key.has_modifiers(&[KeyModifier::Ctrl]) {
+            return Some(Event::OpenProjectInTab);
+        }
 
         Some(match key.bare_key {
-            BareKey::Down | BareKey::Char('j') => match self.app.input_mode {
-                InputMode::Search(_) => Event::Char('j'),
+            BareKey::Down => match self.app.input_mode {
+                InputMode::Search(_) if self.app.browses_search_history() => Event::RecallNewerSearch,
+                InputMode::Search(_) | InputMode::Rename | InputMode::NewSession => Event::Char('j'),
                 InputMode::Normal => Event::KeyDown,
             },
-            BareKey::Up | BareKey::Char('k') => match self.app.input_mode {
-                InputMode::Search(_) => Event::Char('k'),
+            BareKey::Up => match self.app.input_mode {
+                InputMode::Search(_) if self.app.browses_search_history() => Event::RecallOlderSearch,
+                InputMode::Search(_) | InputMode::Rename | InputMode::NewSession => Event::Char('k'),
                 InputMode::Normal => Event::KeyUp,
             },
             BareKey::Esc => match self.app.input_mode {
                 InputMode::Search(_) => Event::ExitSearch,
-                InputMode::Normal => Event::Escape,
+                InputMode::Normal | InputMode::Rename | InputMode::NewSession => Event::Escape,
             },
-            BareKey::Char('q') if self.app.input_mode == InputMode::Normal => Event::CloseFocus,
-            BareKey::Char('K') => Event::KillSession,
+            BareKey::Left if matches!(self.app.input_mode, InputMode::Search(_)) => Event::CursorLeft,
+            BareKey::Right if matches!(self.app.input_mode, InputMode::Search(_)) => Event::CursorRight,
             BareKey::Enter => Event::SelectProject,
-            BareKey::Char('/') => match self.app.input_mode {
-                InputMode::Normal => Event::SearchMode,
-                InputMode::Search(_) => Event::FocusSearchBar,
-            },
-            BareKey::Char('n') if self.app.input_mode == InputMode::Normal => Event::ShowProjects,
-            BareKey::Char('s') if self.app.input_mode == InputMode::Normal => Event::ShowSessions,
+            BareKey::Tab if matches!(self.app.input_mode, InputMode::Search(_)) => Event::ToggleSearchScope,
             BareKey::Backspace => Event::Backspace,
-            BareKey::Char(c) => Event::Char(c),
+            BareKey::Char(c) => match (self.app.input_mode, self.key_bindings.event_for(c)) {
+                (InputMode::Normal, Some(event)) => event,
+                (InputMode::Search(_), Some(Event::KillSession)) => Event::KillSession,
+                (InputMode::Search(_), Some(Event::SearchMode)) => Event::FocusSearchBar,
+                _ => Event::Char(c),
+            },
             _ => return None,
         })
     }
 
     /// Handles permission request results.
-    fn handle_permission_result(&self, permissions: PermissionStatus) {
+    fn handle_permission_result(&mut self, permissions: PermissionStatus) {
         match permissions {
             PermissionStatus::Granted => {
                 tracing::debug!("permissions granted - initializing plugin");
-                self.post_worker_message(&WorkerMessage::load_projects(false));
+                self.schedule_idle_timeout();
+                self.post_worker_message(&WorkerMessage::configure(self.worker_settings.clone()));
+                self.post_worker_message(&WorkerMessage::load_ui_state());
+                if self.app.remember_selection {
+                    self.post_worker_message(&WorkerMessage::load_selection());
+                }
+                if self.app.persist_sessions {
+                    self.post_worker_message(&WorkerMessage::load_sessions());
+                }
+                if let Some(import_file) = &self.import_file {
+                    let paths = import::read_import_file(import_file);
+                    tracing::debug!(import_file = %import_file, path_count = paths.len(), "importing projects");
+                    if !paths.is_empty() {
+                        self.post_worker_message(&WorkerMessage::import_projects(paths));
+                    }
+                }
+                let load_projects = self.app.initial_projects_request();
+                self.post_worker_message(&load_projects);
                 if !self.scan_paths.is_empty() {
                     tracing::debug!("triggering initial filesystem scan");
                     self.trigger_filesystem_scan();
                 }
             }
             PermissionStatus::Denied => {
-                tracing::warn!("permissions denied - plugin functionality limited");
+                if let Some(walk) = discovery::permission_denied_backend(
+                    self.scan_backend_kind,
+                    self.scan_backend.as_ref(),
+                    &self.exclude_paths,
+                ) {
+                    tracing::warn!(
+                        from = self.scan_backend.name(),
+                        to = walk.name(),
+                        "permissions denied - retrying without RunCommands"
+                    );
+                    self.scan_backend = walk;
+                    request_permission(&Self::permissions(false));
+                } else {
+                    tracing::warn!("permissions denied - plugin functionality limited");
+                }
             }
         }
     }
 
+    /// Returns the permissions to request, with `RunCommands` only if scans
+    /// run host commands.
+    fn permissions(run_commands: bool) -> Vec<PermissionType> {
+        let mut permissions = vec![
+            PermissionType::ReadApplicationState,
+            PermissionType::ChangeApplicationState,
+            PermissionType::FullHdAccess,
+        ];
+        if run_commands {
+            permissions.insert(2, PermissionType::RunCommands);
+        }
+        permissions
+    }
+
     /// Maps custom message events to application events.
     fn map_custom_message_event(&self, message: &str, payload: &str) -> Option<Event> {
         tracing::debug!(message_name = %message, payload_len = payload.len(), "custom message event");
 
         if message == self.worker_name {
-            match serde_json::from_str::<WorkerResponse>(payload) {
+            match WorkerResponse::from_json(payload) {
                 Ok(response) => {
                     tracing::debug!(response = ?response, "worker response received");
                     Some(Event::WorkerResponse(response))
@@ -411,12 +838,11 @@ impl State {
     }
 
     /// Maps run command result events to application events.
-    fn map_command_result_event(exit_code: Option<i32>, stdout: Vec<u8>, stderr: Vec<u8>) -> Event {
+    fn map_command_result_event(exit_code: Option<i32>, stdout: &[u8], stderr: &[u8]) -> Event {
         tracing::debug!(exit_code = ?exit_code, "run command result event");
 
         if exit_code == Some(0) {
-            let output = String::from_utf8(stdout).unwrap_or_default();
-            let git_dirs: Vec<String> = output.lines().map(ToString::to_string).collect();
+            let git_dirs = scanner::parse_scan_bytes(stdout);
             tracing::debug!(
                 git_directory_count = git_dirs.len(),
                 "found git directories"
@@ -425,7 +851,7 @@ impl State {
                 git_directories: git_dirs,
             }
         } else {
-            let error = String::from_utf8(stderr).unwrap_or_default();
+            let error = String::from_utf8_lossy(stderr).to_string();
             tracing::debug!(error = %error, "find command failed");
             Event::ScanFailed { error }
         }
@@ -435,6 +861,19 @@ impl State {
     fn map_session_update_event(session_infos: &[zellij_tile::prelude::SessionInfo]) -> Event {
         tracing::debug!(session_count = session_infos.len(), "session update event");
         let active_sessions = session_infos.iter().map(|s| s.name.clone()).collect();
+        let session_panes = session_infos
+            .iter()
+            .map(|s| {
+                let panes = s
+                    .panes
+                    .panes
+                    .values()
+                    .flatten()
+                    .filter(|pane| !pane.is_plugin && !pane.is_suppressed)
+                    .count();
+                (s.name.clone(), panes)
+            })
+            .collect();
         let current_session = session_infos
             .iter()
             .find(|s| s.is_current_session)
@@ -442,6 +881,8 @@ impl State {
         Event::SessionUpdate {
             active_sessions,
             current_session,
+            session_panes,
+            session_cwds: session_cwd::session_cwds(session_infos),
         }
     }
 
@@ -457,7 +898,7 @@ impl State {
     ///
     /// Logs serialization errors but does not propagate them.
     fn post_worker_message(&self, message: &WorkerMessage) {
-        match serde_json::to_string(&message) {
+        match message.to_json() {
             Ok(payload) => {
                 tracing::debug!(payload_len = payload.len(), "posting message to worker");
                 post_message_to(PluginMessage {
@@ -478,46 +919,114 @@ impl State {
     ///
     /// # Actions
     ///
-    /// - `CloseFocus`: Close plugin pane
+    /// - `CloseFocus`: Close plugin pane and flush pending traces
     /// - `SwitchSession`: Switch to existing session and close plugin
-    /// - `CreateSession`: Create new session, switch to it, and close plugin
+    /// - `CreateSession`: Create new session (with the picked layout), switch to it, and close plugin
+    /// - `OpenTab`: Open a new tab at the project path and close plugin
+    /// - `EditLayout`: Open the layout file in a floating editor pane
+    /// - `OpenInEditor`: Open the project directory in an editor pane and close
+    /// - `CopyToClipboard`: Pipe text into the host clipboard tool
+    /// - `ExpireFooterNotice`: Schedule clearing the footer notice
     /// - `KillSession`: Terminate session by name
+    /// - `KillSessions`: Terminate several sessions by name
     /// - `PostToWorker`: Send IPC message to worker thread
     ///
     /// # Parameters
     ///
     /// * `action` - Action to execute
     #[tracing::instrument(level = "debug", skip(self))]
-    fn execute_action(&self, action: &Action) {
+    fn execute_action(&mut self, action: &Action) {
         match action {
             Action::CloseFocus => {
                 tracing::debug!("closing plugin focus");
                 hide_self();
+                self.tracing.flush();
             }
-            Action::SwitchSession { ref name, ref path } => {
-                tracing::debug!(session = %name, path = ?path, "switching to session");
+            Action::SwitchSession { ref name, ref path, ref layout } => {
+                tracing::debug!(session = %name, path = ?path, layout = ?layout, "switching to session");
 
                 let path_str = path.to_string_lossy().to_string();
                 self.post_worker_message(&WorkerMessage::update_frecency(path_str));
                 self.post_worker_message(&WorkerMessage::load_projects(false));
 
-                switch_session_with_cwd(Some(name), Some(path.clone()));
+                switch_to_session(name, path, layout.as_deref());
                 hide_self();
             }
-            Action::CreateSession { ref name, ref path } => {
-                tracing::debug!(session = %name, path = ?path, "creating new session");
+            Action::CreateSession { ref name, ref path, ref layout } => {
+                tracing::debug!(session = %name, path = ?path, layout = ?layout, "creating new session");
 
                 let path_str = path.to_string_lossy().to_string();
                 self.post_worker_message(&WorkerMessage::update_frecency(path_str));
                 self.post_worker_message(&WorkerMessage::load_projects(false));
 
-                switch_session_with_cwd(Some(name), Some(path.clone()));
+                switch_to_session(name, path, layout.as_deref());
+                hide_self();
+            }
+            Action::OpenTab { ref name, ref path } => {
+                tracing::debug!(tab = %name, path = ?path, "opening new tab");
+
+                let path_str = path.to_string_lossy().to_string();
+                self.post_worker_message(&WorkerMessage::update_frecency(path_str.clone()));
+                self.post_worker_message(&WorkerMessage::load_projects(false));
+
+                new_tab(Some(name.as_str()), Some(path_str.as_str()));
                 hide_self();
             }
+            Action::EditLayout { ref name, ref path } => {
+                tracing::debug!(layout_path = ?path, "opening layout in editor");
+
+                let template = layout::layout_template(name);
+                let args = layout::edit_layout_args(path, &template, self.editor.as_deref());
+                // The layout path is relative to the plugin's working directory.
+                let command = CommandToRun {
+                    path: PathBuf::from("sh"),
+                    args,
+                    cwd: Some(get_plugin_ids().initial_cwd),
+                };
+                open_command_pane_floating(command, None, BTreeMap::new());
+                hide_self();
+            }
+            Action::OpenInEditor { ref path } => {
+                tracing::debug!(path = ?path, "opening project in editor");
+
+                let path_str = path.to_string_lossy().to_string();
+                self.post_worker_message(&WorkerMessage::update_frecency(path_str));
+
+                let command = CommandToRun {
+                    path: PathBuf::from("sh"),
+                    args: editor::open_editor_args(self.editor.as_deref()),
+                    cwd: Some(path.clone()),
+                };
+                open_command_pane(command, BTreeMap::new());
+                hide_self();
+            }
+            Action::CopyToClipboard { ref text } => {
+                tracing::debug!(text = %text, "copying to clipboard");
+
+                let args = clipboard::copy_args(text);
+                let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                let context = BTreeMap::from([(
+                    clipboard::COMMAND_CONTEXT_KEY.to_string(),
+                    clipboard::CLIPBOARD_CONTEXT.to_string(),
+                )]);
+                run_command(&args, context);
+            }
+            Action::RenameSession { ref old_name, ref new_name } => {
+                tracing::debug!(old_name = %old_name, new_name = %new_name, "renaming session");
+                rename_session(new_name);
+            }
+            Action::ExpireFooterNotice { secs } => {
+                tracing::debug!(secs = secs, "scheduling footer notice expiry");
+                set_timeout(self.idle_timer.schedule_notice(now_secs(), *secs));
+            }
             Action::KillSession { ref name } => {
                 tracing::debug!(session = %name, "killing session");
                 kill_sessions(&[name]);
             }
+            Action::KillSessions { ref names } => {
+                tracing::debug!(sessions = ?names, "killing sessions");
+                kill_sessions(names);
+            }
             Action::PostToWorker(ref message) => {
                 tracing::debug!(message = ?message, "posting message to worker");
                 self.post_worker_message(message);
@@ -525,3 +1034,25 @@ impl State {
         }
     }
 }
+
+/// Returns the current time in seconds, for ordering scheduled timeouts.
+fn now_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |since_epoch| since_epoch.as_secs_f64())
+}
+
+/// Switches to (or creates) a session rooted at `path`.
+///
+/// With a layout, Zellij uses it only when the session has to be created;
+/// existing sessions keep their current layout.
+fn switch_to_session(name: &str, path: &std::path::Path, layout: Option<&str>) {
+    match layout {
+        Some(layout) => switch_session_with_layout(
+            Some(name),
+            LayoutInfo::File(layout.to_string()),
+            Some(path.to_path_buf()),
+        ),
+        None => switch_session_with_cwd(Some(name), Some(path.to_path_buf())),
+    }
+}
@@ -2,25 +2,48 @@
 //!
 //! This module provides a thread-safe file writer that automatically rotates
 //! files when they exceed a size threshold, maintaining a fixed number of
-//! backup files. This prevents unbounded disk usage for trace files.
+//! backup files. This prevents unbounded disk usage for trace files. Both
+//! limits are configurable through [`Rotation`].
 
+use serde::{Deserialize, Serialize};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
-/// Maximum file size before rotation (10 MB).
-const MAX_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// Default maximum file size before rotation, in megabytes.
+pub const DEFAULT_MAX_FILE_SIZE_MB: u64 = 10;
 
-/// Number of backup files to retain after rotation.
-const MAX_BACKUP_FILES: usize = 3;
+/// Default maximum file size before rotation (10 MB).
+pub const DEFAULT_MAX_FILE_SIZE_BYTES: u64 = DEFAULT_MAX_FILE_SIZE_MB * 1024 * 1024;
+
+/// Default number of backup files to retain after rotation.
+pub const DEFAULT_MAX_BACKUP_FILES: usize = 3;
+
+/// Size threshold and backup retention for a [`FileWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rotation {
+    /// File size in bytes above which the file is rotated.
+    pub max_size_bytes: u64,
+    /// Number of rotated backups kept; older ones are deleted.
+    pub max_backups: usize,
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self {
+            max_size_bytes: DEFAULT_MAX_FILE_SIZE_BYTES,
+            max_backups: DEFAULT_MAX_BACKUP_FILES,
+        }
+    }
+}
 
 /// Thread-safe rotating file writer.
 ///
 /// Provides automatic file rotation based on size thresholds. When the current
-/// file exceeds `MAX_FILE_SIZE_BYTES`, it is renamed with a timestamp suffix
-/// and a new file is created. Old backups beyond `MAX_BACKUP_FILES` are
-/// automatically cleaned up.
+/// file exceeds [`Rotation::max_size_bytes`], it is renamed with a timestamp
+/// suffix and a new file is created. Old backups beyond
+/// [`Rotation::max_backups`] are automatically cleaned up.
 ///
 /// # Thread Safety
 ///
@@ -30,27 +53,45 @@ const MAX_BACKUP_FILES: usize = 3;
 /// # Rotation Strategy
 ///
 /// 1. Check file size before each write
-/// 2. If size > 10MB, rotate:
+/// 2. If size > `max_size_bytes` (10MB by default), rotate:
 ///    - Rename current file to `<name>.json.<timestamp>`
 ///    - Create new empty file
-///    - Remove oldest backups beyond 3
+///    - Remove oldest backups beyond `max_backups` (3 by default)
 ///
 /// # Example
 ///
 /// ```rust
-/// use std::path::PathBuf;
+/// use zessionizer::observability::{FileWriter, Rotation};
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("traces.json");
+/// let rotation = Rotation { max_size_bytes: 10, max_backups: 2 };
+/// let writer = FileWriter::new(path.clone(), rotation);
 ///
-/// let path = PathBuf::from("/tmp/traces.json");
-/// let writer = FileWriter::new(path);
+/// // Every line exceeds the threshold, so each write after the first rotates
+/// for i in 1..=6 {
+///     writer.write_line(&format!("{{\"line\": {i}}}")).unwrap();
+/// }
 ///
-/// // Writes are automatically rotated when file grows too large
-/// writer.write_line("{\"trace\": \"data\"}").unwrap();
+/// let mut backups: Vec<_> = std::fs::read_dir(dir.path())
+///     .unwrap()
+///     .map(|entry| entry.unwrap().path())
+///     .filter(|p| p.file_name().unwrap().to_str().unwrap().starts_with("traces.json."))
+///     .collect();
+/// backups.sort();
+///
+/// assert_eq!(backups.len(), 2);
+/// assert_eq!(std::fs::read_to_string(&backups[0]).unwrap(), "{\"line\": 4}\n");
+/// assert_eq!(std::fs::read_to_string(&backups[1]).unwrap(), "{\"line\": 5}\n");
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"line\": 6}\n");
 /// ```
 pub struct FileWriter {
     /// Path to the primary log file.
     file_path: PathBuf,
     /// Lazily-initialized file handle (opens on first write).
     writer: Mutex<Option<std::fs::File>>,
+    /// Size threshold and backup retention.
+    rotation: Rotation,
 }
 
 impl FileWriter {
@@ -62,10 +103,13 @@ impl FileWriter {
     /// # Parameters
     ///
     /// * `file_path` - Path to the log file (will be created if it doesn't exist)
-    pub const fn new(file_path: PathBuf) -> Self {
+    /// * `rotation` - When to rotate and how many backups to keep
+    #[must_use]
+    pub const fn new(file_path: PathBuf, rotation: Rotation) -> Self {
         Self {
             file_path,
             writer: Mutex::new(None),
+            rotation,
         }
     }
 
@@ -93,8 +137,9 @@ impl FileWriter {
     /// # Example
     ///
     /// ```rust
-    /// # use std::path::PathBuf;
-    /// # let writer = FileWriter::new(PathBuf::from("/tmp/test.json"));
+    /// # use zessionizer::observability::{FileWriter, Rotation};
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let writer = FileWriter::new(dir.path().join("test.json"), Rotation::default());
     /// writer.write_line("{\"event\": \"test\"}").unwrap();
     /// ```
     pub fn write_line(&self, json: &str) -> std::io::Result<()> {
@@ -125,7 +170,7 @@ impl FileWriter {
 
     /// Checks file size and rotates if necessary.
     ///
-    /// If the current file exceeds `MAX_FILE_SIZE_BYTES`, closes the file
+    /// If the current file exceeds `max_size_bytes`, closes the file
     /// handle and triggers rotation.
     ///
     /// # Parameters
@@ -133,7 +178,7 @@ impl FileWriter {
     /// * `writer` - Current file handle (set to `None` if rotation occurs)
     fn check_and_rotate(&self, writer: &mut Option<std::fs::File>) -> std::io::Result<()> {
         if let Ok(metadata) = fs::metadata(&self.file_path) {
-            if metadata.len() > MAX_FILE_SIZE_BYTES {
+            if metadata.len() > self.rotation.max_size_bytes {
                 *writer = None;
                 self.rotate_files()?;
             }
@@ -148,16 +193,23 @@ impl FileWriter {
     ///
     /// # Backup Naming
     ///
-    /// Backups are named: `<original_name>.json.<unix_timestamp>`
+    /// Backups are named: `<original_name>.json.<unix_timestamp_millis>`
+    ///
+    /// Example: `zessionizer-otlp.json.1234567890123`
     ///
-    /// Example: `zessionizer-otlp.json.1234567890`
+    /// The timestamp is bumped past any existing backup, so rotations within
+    /// the same millisecond keep separate files.
     fn rotate_files(&self) -> std::io::Result<()> {
-        let timestamp = std::time::SystemTime::now()
+        let mut timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or(std::time::Duration::from_secs(0))
-            .as_secs();
+            .as_millis();
 
-        let backup_path = self.file_path.with_extension(format!("json.{timestamp}"));
+        let mut backup_path = self.file_path.with_extension(format!("json.{timestamp}"));
+        while backup_path.exists() {
+            timestamp += 1;
+            backup_path = self.file_path.with_extension(format!("json.{timestamp}"));
+        }
 
         if self.file_path.exists() {
             fs::rename(&self.file_path, &backup_path)?;
@@ -171,8 +223,8 @@ impl FileWriter {
     /// Removes old backup files beyond the retention limit.
     ///
     /// Scans the directory for backup files matching the pattern
-    /// `<name>.json.*`, sorts by modification time (newest first), and deletes
-    /// all backups beyond `MAX_BACKUP_FILES`.
+    /// `<name>.json.*`, sorts by modification time (newest first, ties broken
+    /// by the timestamp suffix), and deletes all backups beyond `max_backups`.
     ///
     /// # Error Handling
     ///
@@ -204,11 +256,11 @@ impl FileWriter {
         backups.sort_by(|a, b| {
             let a_time = fs::metadata(a).and_then(|m| m.modified()).ok();
             let b_time = fs::metadata(b).and_then(|m| m.modified()).ok();
-            b_time.cmp(&a_time)
+            b_time.cmp(&a_time).then_with(|| b.cmp(a))
         });
 
         // Remove backups beyond retention limit
-        for old_backup in backups.iter().skip(MAX_BACKUP_FILES) {
+        for old_backup in backups.iter().skip(self.rotation.max_backups) {
             let _ = fs::remove_file(old_backup);
         }
 
@@ -220,6 +272,7 @@ impl std::fmt::Debug for FileWriter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FileWriter")
             .field("file_path", &self.file_path)
+            .field("rotation", &self.rotation)
             .finish_non_exhaustive()
     }
 }
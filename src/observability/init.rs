@@ -2,18 +2,167 @@
 //!
 //! This module configures the tracing subscriber with OpenTelemetry integration,
 //! setting up the complete observability pipeline from `tracing` macros to file
-//! export.
+//! export, or a plain-text stderr subscriber when [`TraceOutput::Stderr`] is
+//! configured.
 
 use super::tracer;
 use crate::Config;
 use opentelemetry::trace::TracerProvider as _;
 use opentelemetry_sdk::resource::Resource;
+use opentelemetry_sdk::trace::TracerProvider;
 use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-/// Initializes the tracing subscriber with file-based OTLP export.
+/// Destination of emitted spans and events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TraceOutput {
+    /// OTLP JSON written to the rotating trace file (default).
+    #[default]
+    File,
+    /// Plain human-readable lines on stderr, shown in the Zellij log.
+    Stderr,
+    /// No subscriber is installed, so tracing macros cost nothing.
+    None,
+}
+
+impl TraceOutput {
+    /// Parses a `trace_output` config value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::observability::TraceOutput;
+    ///
+    /// assert_eq!(TraceOutput::parse("file"), Some(TraceOutput::File));
+    /// assert_eq!(TraceOutput::parse(" Stderr "), Some(TraceOutput::Stderr));
+    /// assert_eq!(TraceOutput::parse("none"), Some(TraceOutput::None));
+    /// assert_eq!(TraceOutput::parse("off"), Some(TraceOutput::None));
+    /// assert_eq!(TraceOutput::parse("stdout"), None);
+    /// ```
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "file" => Some(Self::File),
+            "stderr" => Some(Self::Stderr),
+            "none" | "off" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// A trace pipeline that can be flushed before the plugin goes away.
+///
+/// Implemented by the SDK `TracerProvider`; [`TracingGuard`] is generic over
+/// it so the shutdown path can be exercised without a real exporter.
+pub trait FlushTraces {
+    /// Exports any spans still buffered by the pipeline.
+    fn force_flush(&self);
+    /// Flushes and stops the pipeline; later spans are dropped.
+    fn shutdown(&self);
+}
+
+impl FlushTraces for TracerProvider {
+    fn force_flush(&self) {
+        for result in Self::force_flush(self) {
+            if let Err(e) = result {
+                eprintln!("zessionizer: failed to flush traces: {e}");
+            }
+        }
+    }
+
+    fn shutdown(&self) {
+        if let Err(e) = Self::shutdown(self) {
+            eprintln!("zessionizer: failed to shut down tracer provider: {e}");
+        }
+    }
+}
+
+/// Keeps the tracer provider built by [`init_tracing`] so it can be flushed.
+///
+/// Empty when no OpenTelemetry pipeline was installed (`stderr` or `none`
+/// output, or tracing already initialized), in which case both methods do
+/// nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::Cell;
+/// use zessionizer::observability::{FlushTraces, TracingGuard};
 ///
-/// Sets up a tracing subscriber pipeline that:
+/// #[derive(Default)]
+/// struct Recorder {
+///     flushes: Cell<u32>,
+///     shutdowns: Cell<u32>,
+/// }
+///
+/// impl FlushTraces for &Recorder {
+///     fn force_flush(&self) {
+///         self.flushes.set(self.flushes.get() + 1);
+///     }
+///     fn shutdown(&self) {
+///         self.shutdowns.set(self.shutdowns.get() + 1);
+///     }
+/// }
+///
+/// let recorder = Recorder::default();
+/// let mut guard = TracingGuard::new(&recorder);
+///
+/// guard.flush();
+/// assert_eq!((recorder.flushes.get(), recorder.shutdowns.get()), (1, 0));
+///
+/// guard.shutdown();
+/// assert_eq!((recorder.flushes.get(), recorder.shutdowns.get()), (2, 1));
+///
+/// // The provider is released, so a second shutdown is a no-op
+/// guard.shutdown();
+/// guard.flush();
+/// assert_eq!((recorder.flushes.get(), recorder.shutdowns.get()), (2, 1));
+/// ```
+#[derive(Debug)]
+pub struct TracingGuard<P: FlushTraces = TracerProvider> {
+    provider: Option<P>,
+}
+
+impl<P: FlushTraces> TracingGuard<P> {
+    /// Wraps a provider to flush on shutdown.
+    #[must_use]
+    pub const fn new(provider: P) -> Self {
+        Self { provider: Some(provider) }
+    }
+
+    /// Returns a guard holding no provider.
+    #[must_use]
+    pub const fn disabled() -> Self {
+        Self { provider: None }
+    }
+
+    /// Exports buffered spans, keeping the pipeline running.
+    pub fn flush(&self) {
+        if let Some(provider) = &self.provider {
+            provider.force_flush();
+        }
+    }
+
+    /// Flushes and shuts down the pipeline, releasing the provider.
+    pub fn shutdown(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            provider.force_flush();
+            provider.shutdown();
+        }
+    }
+}
+
+impl<P: FlushTraces> Default for TracingGuard<P> {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+/// Initializes the tracing subscriber for the configured [`TraceOutput`].
+///
+/// With [`TraceOutput::File`] (the default), sets up a tracing subscriber
+/// pipeline that:
 /// 1. Filters spans based on configured trace level
 /// 2. Exports spans to OpenTelemetry
 /// 3. Serializes spans to OTLP JSON format
@@ -21,7 +170,22 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 ///
 /// # Parameters
 ///
-/// * `config` - Plugin configuration containing `trace_level` option
+/// * `config` - Plugin configuration containing `trace_level`,
+///   `trace_output`, and `trace_attributes` options
+///
+/// # Outputs
+///
+/// - `file`: OTLP JSON trace file, described below
+/// - `stderr`: one plain-text line per event and closed span (with its
+///   duration), without ANSI colors; the level filter still applies
+/// - `none`: returns without installing a subscriber or building the
+///   OpenTelemetry layer
+///
+/// # Resource Attributes
+///
+/// In file mode, the OTLP resource always carries `service.name = "Zessionizer"`. Entries
+/// from `config.trace_attributes` are added after it, so a configured
+/// `service.name` overrides the default.
 ///
 /// # Trace Level Resolution
 ///
@@ -32,6 +196,7 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 /// # File Location
 ///
 /// Traces are written to: `~/.local/share/zellij/zessionizer/zessionizer-otlp.json`
+/// and rotated per `trace_max_size_mb` and `trace_backups`.
 ///
 /// The plugin uses `/data/zessionizer-otlp.json` in Zellij's sandbox environment,
 /// which typically maps to the path above when Zellij is started from the user's
@@ -43,6 +208,12 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 /// - Silently fails if directory creation fails (observability is optional)
 /// - Idempotent: Safe to call multiple times (only first call takes effect)
 ///
+/// # Returns
+///
+/// A [`TracingGuard`] holding the file mode's tracer provider. Keep it for the
+/// plugin's lifetime and call [`TracingGuard::shutdown`] before unloading so
+/// the last spans are exported.
+///
 /// # Example
 ///
 /// ```rust
@@ -54,29 +225,86 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 ///     ..Default::default()
 /// };
 ///
-/// init_tracing(&config);
+/// let _tracing = init_tracing(&config);
 ///
 /// tracing::debug!("tracing is now active");
 /// ```
-pub fn init_tracing(config: &Config) {
+#[must_use]
+pub fn init_tracing(config: &Config) -> TracingGuard {
     let level = config
         .trace_level
         .clone()
         .unwrap_or_else(|| "info".to_string());
 
-    let data_dir = crate::infrastructure::paths::get_data_dir();
+    match config.trace_output {
+        TraceOutput::None => TracingGuard::disabled(),
+        TraceOutput::Stderr => {
+            init_stderr(&level);
+            TracingGuard::disabled()
+        }
+        TraceOutput::File => init_file(config, &level),
+    }
+}
+
+/// Builds the OTLP resource written with every trace batch.
+///
+/// Carries `service.name = "Zessionizer"`, followed by `config.trace_attributes`,
+/// so a configured `service.name` overrides the default.
+///
+/// # Example
+///
+/// ```rust
+/// use opentelemetry::{Key, Value};
+/// use zessionizer::observability::trace_resource;
+/// use zessionizer::Config;
+///
+/// let config = Config {
+///     trace_attributes: vec![("host.name".to_string(), "laptop".to_string())],
+///     ..Config::default()
+/// };
+/// let resource = trace_resource(&config);
+/// assert_eq!(resource.get(Key::new("service.name")), Some(Value::from("Zessionizer")));
+/// assert_eq!(resource.get(Key::new("host.name")), Some(Value::from("laptop")));
+/// ```
+#[must_use]
+pub fn trace_resource(config: &Config) -> Resource {
+    let attributes = std::iter::once(opentelemetry::KeyValue::new("service.name", "Zessionizer"))
+        .chain(
+            config
+                .trace_attributes
+                .iter()
+                .map(|(key, value)| opentelemetry::KeyValue::new(key.clone(), value.clone())),
+        );
+    Resource::new(attributes)
+}
+
+/// Installs a plain-text subscriber writing to stderr.
+fn init_stderr(level: &str) {
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_ansi(false)
+        .with_span_events(FmtSpan::CLOSE);
+
+    let subscriber = tracing_subscriber::registry()
+        .with(EnvFilter::new(level))
+        .with(fmt_layer);
+
+    let _ = subscriber.try_init();
+}
+
+/// Installs the OpenTelemetry subscriber exporting to the trace file.
+///
+/// Returns a guard owning the provider, or an empty one if the subscriber
+/// could not be installed.
+fn init_file(config: &Config, level: &str) -> TracingGuard {
+    let data_dir = config.data_dir();
     if let Err(_e) = std::fs::create_dir_all(&data_dir) {
         // Silently fail if we can't create the directory
-        return;
+        return TracingGuard::disabled();
     }
 
-    let resource = Resource::new(vec![opentelemetry::KeyValue::new(
-        "service.name",
-        "Zessionizer",
-    )]);
-
     let trace_file = data_dir.join("zessionizer-otlp.json");
-    let provider = tracer::create_tracer_provider(trace_file, resource);
+    let provider = tracer::create_tracer_provider(trace_file, trace_resource(config), config.trace_rotation());
 
     let tracer = provider.tracer("Zessionizer");
     let otel_layer = OpenTelemetryLayer::new(tracer);
@@ -85,5 +313,8 @@ pub fn init_tracing(config: &Config) {
         .with(EnvFilter::new(level))
         .with(otel_layer);
 
-    let _ = subscriber.try_init();
+    match subscriber.try_init() {
+        Ok(()) => TracingGuard::new(provider),
+        Err(_) => TracingGuard::disabled(),
+    }
 }
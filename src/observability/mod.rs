@@ -15,7 +15,8 @@
 //! # Features
 //!
 //! - **File-Based Export**: Traces written to `~/.local/share/zellij/zessionizer/zessionizer-otlp.json`
-//! - **Automatic Rotation**: Files rotate at 10MB with 3-backup retention
+//! - **Automatic Rotation**: Files rotate at 10MB with 3-backup retention by
+//!   default (`trace_max_size_mb`, `trace_backups`)
 //! - **OTLP Format**: Standard OpenTelemetry Protocol JSON format
 //! - **Resource Metadata**: Includes service name and environment info
 //!
@@ -26,6 +27,10 @@
 //! 2. `trace_level` config option in plugin configuration
 //! 3. Default: `"info"`
 //!
+//! The `trace_output` option selects where spans go: the OTLP file (`file`,
+//! default), plain text on stderr (`stderr`), or nowhere (`none`, which
+//! installs no subscriber at all). See [`TraceOutput`].
+//!
 //! # Usage
 //!
 //! Initialize tracing early in plugin lifecycle:
@@ -35,7 +40,7 @@
 //! use zessionizer::Config;
 //!
 //! let config = Config::default();
-//! init_tracing(&config);
+//! let _tracing = init_tracing(&config);
 //!
 //! tracing::debug!("plugin initialized");
 //! ```
@@ -45,11 +50,13 @@
 //! - [`init`]: Tracing initialization and subscriber setup
 //! - [`tracer`]: Custom OpenTelemetry tracer provider with file export
 //! - [`span_formatter`]: OTLP JSON span serialization
-//! - [`file_writer`]: Rotating file writer with size-based rotation
+//! - [`file_writer`]: Rotating file writer with configurable size-based
+//!   rotation (also used by the worker operation log)
 
-mod file_writer;
-mod span_formatter;
+pub mod file_writer;
+pub mod span_formatter;
 mod tracer;
 mod init;
 
-pub use init::init_tracing;
+pub use init::{init_tracing, trace_resource, FlushTraces, TraceOutput, TracingGuard};
+pub use file_writer::{FileWriter, Rotation};
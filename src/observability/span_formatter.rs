@@ -23,6 +23,7 @@ impl SpanFormatter {
     /// # Parameters
     ///
     /// * `resource` - OpenTelemetry resource to include in formatted output
+    #[must_use]
     pub const fn new(resource: Resource) -> Self {
         Self { resource }
     }
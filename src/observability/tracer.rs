@@ -4,7 +4,7 @@
 //! rotating JSON file instead of sending them over the network. This enables
 //! offline trace analysis and debugging in sandbox environments.
 
-use super::file_writer::FileWriter;
+use super::file_writer::{FileWriter, Rotation};
 use super::span_formatter::SpanFormatter;
 use futures_util::future::BoxFuture;
 use opentelemetry::trace::TraceError;
@@ -35,9 +35,10 @@ impl FileSpanExporter {
     ///
     /// * `file_path` - Path to the JSON trace file
     /// * `resource` - OpenTelemetry resource metadata (service name, etc.)
-    const fn new(file_path: PathBuf, resource: Resource) -> Self {
+    /// * `rotation` - Trace file size threshold and backup retention
+    const fn new(file_path: PathBuf, resource: Resource, rotation: Rotation) -> Self {
         Self {
-            writer: FileWriter::new(file_path),
+            writer: FileWriter::new(file_path, rotation),
             formatter: SpanFormatter::new(resource),
             is_shutdown: AtomicBool::new(false),
         }
@@ -118,6 +119,7 @@ impl std::fmt::Debug for FileSpanExporter {
 ///
 /// * `file_path` - Path to the JSON trace file
 /// * `resource` - OpenTelemetry resource metadata
+/// * `rotation` - Trace file size threshold and backup retention
 ///
 /// # Returns
 ///
@@ -128,17 +130,19 @@ impl std::fmt::Debug for FileSpanExporter {
 /// ```rust
 /// use opentelemetry_sdk::resource::Resource;
 /// use opentelemetry::KeyValue;
+/// use zessionizer::observability::Rotation;
 /// use std::path::PathBuf;
 ///
 /// let resource = Resource::new(vec![KeyValue::new("service.name", "myapp")]);
 /// let path = PathBuf::from("/tmp/traces.json");
-/// let provider = create_tracer_provider(path, resource);
+/// let provider = create_tracer_provider(path, resource, Rotation::default());
 /// ```
 pub fn create_tracer_provider(
     file_path: PathBuf,
     resource: Resource,
+    rotation: Rotation,
 ) -> TracerProvider {
-    let exporter = FileSpanExporter::new(file_path, resource.clone());
+    let exporter = FileSpanExporter::new(file_path, resource.clone(), rotation);
 
     TracerProvider::builder()
         .with_config(
@@ -11,7 +11,39 @@
 //! in the worker thread.
 
 use crate::domain::error::Result;
-use crate::storage::models::{ProjectRecord, SessionRecord};
+use crate::storage::models::{BatchEntry, ProjectPage, ProjectRecord, ProjectSort, SessionRecord};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Selects which [`Storage`] implementation the worker opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackendKind {
+    /// [`JsonStorage`](crate::storage::JsonStorage), stored in `projects.json`.
+    #[default]
+    Json,
+
+    /// `SqliteStorage`, stored in `projects.sqlite3`. Requires the `sqlite`
+    /// feature; without it the worker falls back to JSON.
+    Sqlite,
+}
+
+impl StorageBackendKind {
+    /// Parses a backend name (`"json"` or `"sqlite"`), case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(StorageBackendKind)` if the name is recognized
+    /// - `None` otherwise
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "sqlite" => Some(Self::Sqlite),
+            _ => None,
+        }
+    }
+}
 
 /// Abstraction over persistent storage backends.
 ///
@@ -21,6 +53,7 @@ use crate::storage::models::{ProjectRecord, SessionRecord};
 /// # Implementations
 ///
 /// - [`JsonStorage`]: Uses JSON file with atomic writes (default)
+/// - `SqliteStorage`: Uses a `SQLite` database (`sqlite` feature)
 ///
 /// # Examples
 ///
@@ -45,14 +78,18 @@ pub trait Storage: Send {
 
     /// Adds or updates multiple projects in a single operation.
     ///
-    /// More efficient than calling [`add_project`] in a loop. Returns the list
-    /// of successfully stored projects (may differ from input due to deduplication).
+    /// More efficient than calling [`add_project`] in a loop. Returns one entry
+    /// per input project, in input order, holding the stored record and whether
+    /// it was newly inserted. A path repeated within the batch is inserted once
+    /// and reported as updated afterwards. Existing projects keep their stored
+    /// name, which differs from the scanned directory name after a rename, but
+    /// take the scanned tags.
     ///
     /// # Errors
     ///
     /// Returns an error if the batch operation fails. Some backends may perform
     /// partial writes before failing.
-    fn add_projects_batch(&mut self, projects: &[ProjectRecord]) -> Result<Vec<ProjectRecord>>;
+    fn add_projects_batch(&mut self, projects: &[ProjectRecord]) -> Result<Vec<BatchEntry>>;
 
     /// Retrieves all projects from storage.
     ///
@@ -64,6 +101,20 @@ pub trait Storage: Send {
     /// Returns an error if the read operation fails.
     fn get_all_projects(&self) -> Result<Vec<ProjectRecord>>;
 
+    /// Retrieves one page of projects in the given order, plus the total count.
+    ///
+    /// Returns up to `limit` projects starting at `offset`; offsets past the
+    /// last project yield an empty page. The default implementation sorts the
+    /// result of [`get_all_projects`](Storage::get_all_projects).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the read operation fails.
+    fn get_projects_paginated(&self, offset: usize, limit: usize, sort: ProjectSort) -> Result<ProjectPage> {
+        let records = self.get_all_projects()?;
+        Ok(ProjectPage::from_records(&records, offset, limit, sort))
+    }
+
     /// Updates the access timestamp and increments access count for a project.
     ///
     /// This is called when the user selects a project, maintaining frecency data.
@@ -73,6 +124,50 @@ pub trait Storage: Send {
     /// Returns an error if the project doesn't exist or the update fails.
     fn update_project_access(&mut self, path: &str, timestamp: i64) -> Result<()>;
 
+    /// Resets frecency data to that of a newly added project.
+    ///
+    /// Sets `access_count` to 1 and `last_accessed` to `None` for the project
+    /// at `path`, or for every project if `path` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project at `path` doesn't exist or the update
+    /// fails.
+    fn reset_frecency(&mut self, path: Option<&str>) -> Result<()>;
+
+    /// Renames a project and the session records linked to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or the update fails.
+    fn rename_project(&mut self, path: &str, name: &str) -> Result<()>;
+
+    /// Pins or unpins a project.
+    ///
+    /// Pinned projects sort above all unpinned ones (see
+    /// [`sort_by_frecency`](crate::storage::sort_by_frecency)).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or the update fails.
+    fn set_project_pinned(&mut self, path: &str, pinned: bool) -> Result<()>;
+
+    /// Sets or clears the Zellij layout a project's session is created with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or the update fails.
+    fn set_project_layout(&mut self, path: &str, layout: Option<&str>) -> Result<()>;
+
+    /// Removes a project and any sessions linked to it.
+    ///
+    /// Removing a project that doesn't exist is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write operation fails.
+    fn remove_project(&mut self, path: &str) -> Result<()>;
+
     /// Retrieves a single project by its filesystem path.
     ///
     /// Returns `Ok(None)` if the project doesn't exist.
@@ -97,8 +192,72 @@ pub trait Storage: Send {
     /// Removes sessions that are no longer active and adds new ones. The exact
     /// implementation strategy (clear+insert vs diff) is backend-specific.
     ///
+    /// Each session is linked to a project of the same name. When several
+    /// projects share it, [`session_project`](crate::storage::session_project)
+    /// picks one using the working directory from `session_cwds` (keyed by
+    /// session name), then the previously linked project. Sessions named
+    /// after no project are linked to the project containing their working
+    /// directory, if any.
+    ///
     /// # Errors
     ///
     /// Returns an error if the sync operation fails.
-    fn sync_sessions(&mut self, active_session_names: &[String]) -> Result<()>;
+    fn sync_sessions(
+        &mut self,
+        active_session_names: &[String],
+        session_cwds: &HashMap<String, String>,
+    ) -> Result<()>;
+
+    /// Retrieves the path of the project selected when the plugin was last closed.
+    ///
+    /// Returns `Ok(None)` if no selection has been saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the read operation fails.
+    fn get_last_selected(&self) -> Result<Option<String>>;
+
+    /// Stores the path of the currently selected project.
+    ///
+    /// Paths are stored instead of indices so the selection survives reordering.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write operation fails.
+    fn set_last_selected(&mut self, path: &str) -> Result<()>;
+
+    /// Starts buffering writes until the matching [`end_batch`](Storage::end_batch).
+    ///
+    /// Batches may nest; only the outermost `end_batch` writes. Backends that
+    /// write cheaply per operation may ignore this.
+    fn begin_batch(&mut self) {}
+
+    /// Ends a batch started with [`begin_batch`](Storage::begin_batch),
+    /// writing buffered changes once the outermost batch ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing the buffered changes fails.
+    fn end_batch(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs `operations` inside a storage batch, so buffered backends write once.
+///
+/// The batch is ended even if `operations` fails; the first error is returned.
+///
+/// # Errors
+///
+/// Returns the error from `operations`, or from ending the batch.
+pub fn with_batch<T>(
+    storage: &mut dyn Storage,
+    operations: impl FnOnce(&mut dyn Storage) -> Result<T>,
+) -> Result<T> {
+    storage.begin_batch();
+    let result = operations(storage);
+    let flushed = storage.end_batch();
+    let value = result?;
+    flushed?;
+    Ok(value)
 }
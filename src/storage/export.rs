@@ -0,0 +1,149 @@
+//! Project database export for scripting.
+//!
+//! Projects are exported as JSON together with their frecency scores, so
+//! external tools can build their own pickers on top of the plugin's ranking.
+//!
+//! # Format
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "projects": [
+//!     {
+//!       "path": "/home/user/code/api",
+//!       "name": "api",
+//!       "last_accessed": 1700000000,
+//!       "created_at": 1690000000,
+//!       "access_count": 12,
+//!       "pinned": false,
+//!       "tags": ["work"],
+//!       "frecency": 8.41
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `layout` and `tags` are omitted when unset. Projects keep the order they
+//! were exported in, which is frecency order for both the plugin's list and
+//! the worker's export.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::domain::Project;
+//! use zessionizer::storage::{ProjectsExport, DEFAULT_HALF_LIFE_HOURS};
+//!
+//! let mut project = Project::new("/code/api".to_string(), "api".to_string());
+//! project.access_count = 4;
+//! project.tags = vec!["work".to_string()];
+//! let now = project.last_accessed;
+//!
+//! let export = ProjectsExport::from_projects(&[project], now, DEFAULT_HALF_LIFE_HOURS);
+//! let json = export.to_json();
+//! assert!(json.contains(r#""frecency":4.0"#));
+//!
+//! let parsed = ProjectsExport::from_json(&json).unwrap();
+//! assert_eq!(parsed, export);
+//! assert_eq!(parsed.projects[0].name, "api");
+//! assert_eq!(parsed.projects[0].tags, ["work"]);
+//!
+//! assert!(ProjectsExport::from_json("{ not json").is_err());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::{Result, ZessionizerError};
+use crate::domain::Project;
+
+use super::frecency::frecency_score;
+
+/// Version of the export format, bumped on incompatible changes.
+pub const EXPORT_VERSION: u32 = 1;
+
+/// Exported project list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectsExport {
+    /// Format version, see [`EXPORT_VERSION`].
+    pub version: u32,
+
+    /// Exported projects, in the order they were given.
+    pub projects: Vec<ExportedProject>,
+}
+
+/// One exported project with its frecency score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedProject {
+    /// Absolute filesystem path to the project directory.
+    pub path: String,
+
+    /// Display name of the project.
+    pub name: String,
+
+    /// Unix timestamp of the most recent access (creation time if never opened).
+    pub last_accessed: i64,
+
+    /// Unix timestamp when the project was first added.
+    pub created_at: i64,
+
+    /// Number of times the project has been opened.
+    pub access_count: i32,
+
+    /// Whether the project is pinned above unpinned projects.
+    #[serde(default)]
+    pub pinned: bool,
+
+    /// Zellij layout name used when creating the project's session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<String>,
+
+    /// Tags from the project's `.zessionizer` marker file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Frecency score at export time.
+    pub frecency: f64,
+}
+
+impl ProjectsExport {
+    /// Builds an export of `projects`, scoring each at `now`.
+    #[must_use]
+    pub fn from_projects(projects: &[Project], now: i64, half_life_hours: f64) -> Self {
+        let projects = projects
+            .iter()
+            .map(|project| ExportedProject {
+                path: project.path.clone(),
+                name: project.name.clone(),
+                last_accessed: project.last_accessed,
+                created_at: project.created_at,
+                access_count: project.access_count,
+                pinned: project.pinned,
+                layout: project.layout.clone(),
+                tags: project.tags.clone(),
+                frecency: frecency_score(project.access_count, Some(project.last_accessed), now, half_life_hours),
+            })
+            .collect();
+
+        Self {
+            version: EXPORT_VERSION,
+            projects,
+        }
+    }
+
+    /// Serializes the export as compact JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // Plain strings and numbers only; serde_json writes non-finite scores
+        // as `null` rather than failing.
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Parses an export produced by [`ProjectsExport::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZessionizerError::Storage`] if `json` is not a valid export.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| ZessionizerError::Storage(format!("failed to parse project export: {e}")))
+    }
+}
@@ -4,17 +4,19 @@
 //! both how often they are accessed and how recently. This provides a more useful
 //! ordering than pure alphabetical or modification-time sorting.
 //!
-//! The algorithm uses exponential decay with a half-life of 168 hours (1 week),
-//! meaning projects accessed a week ago contribute about half their frequency weight
-//! to the final score.
+//! The algorithm uses exponential decay with a configurable half-life, 168 hours
+//! (1 week) by default, meaning projects accessed a week ago contribute about half
+//! their frequency weight to the final score.
+
+use std::cmp::Ordering;
 
 use super::models::ProjectRecord;
 
-/// Half-life for exponential decay in hours.
+/// Default half-life for exponential decay in hours.
 ///
 /// Projects accessed this many hours ago contribute approximately 50% of their
 /// access count to the frecency score. Set to 168 hours (1 week).
-const HALF_LIFE_HOURS: f64 = 168.0;
+pub const DEFAULT_HALF_LIFE_HOURS: f64 = 168.0;
 
 /// Number of seconds per hour for time conversion.
 const SECONDS_PER_HOUR: f64 = 3600.0;
@@ -25,7 +27,7 @@ const SECONDS_PER_HOUR: f64 = 3600.0;
 /// using exponential decay:
 ///
 /// ```text
-/// score = access_count × e^(-age_hours / HALF_LIFE_HOURS)
+/// score = access_count × e^(-age_hours / half_life_hours)
 /// ```
 ///
 /// Projects never accessed receive a score based on their access count alone.
@@ -34,27 +36,51 @@ const SECONDS_PER_HOUR: f64 = 3600.0;
 /// # Examples
 ///
 /// ```
-/// use crate::storage::{ProjectRecord, calculate_score};
+/// use zessionizer::storage::{ProjectRecord, calculate_score, DEFAULT_HALF_LIFE_HOURS};
 ///
 /// let mut project = ProjectRecord::new("/home/user/project", "project");
 /// project.access_count = 10;
 /// project.last_accessed = Some(chrono::Utc::now().timestamp() - 3600); // 1 hour ago
 ///
 /// let now = chrono::Utc::now().timestamp();
-/// let score = calculate_score(&project, now);
+/// let score = calculate_score(&project, now, DEFAULT_HALF_LIFE_HOURS);
 /// assert!(score > 0.0);
 /// assert!(score < 10.0); // Less than pure access count due to time decay
 /// ```
+///
+/// A shorter half-life decays the same visits faster:
+///
+/// ```
+/// use zessionizer::storage::{ProjectRecord, calculate_score, DEFAULT_HALF_LIFE_HOURS};
+///
+/// let now = 1_700_000_000;
+/// let mut project = ProjectRecord::new("/home/user/project", "project");
+/// project.access_count = 8;
+/// project.last_accessed = Some(now - 24 * 3600); // 1 day ago
+///
+/// assert!((calculate_score(&project, now, 24.0) - 8.0 * (-1.0_f64).exp()).abs() < 1e-9);
+/// assert!(calculate_score(&project, now, 24.0) < calculate_score(&project, now, DEFAULT_HALF_LIFE_HOURS));
+/// assert!(calculate_score(&project, now, 1.0) < 1e-6);
+/// ```
 #[must_use]
-pub fn calculate_score(project: &ProjectRecord, now: i64) -> f64 {
-    let access_count = f64::from(project.access_count);
+pub fn calculate_score(project: &ProjectRecord, now: i64, half_life_hours: f64) -> f64 {
+    frecency_score(project.access_count, project.last_accessed, now, half_life_hours)
+}
 
-    let recency_multiplier = project.last_accessed.map_or(1.0, |last_accessed| {
+/// Calculates a frecency score from an access count and last access time.
+///
+/// The formula behind [`calculate_score`], for callers holding a domain
+/// `Project` rather than a storage record.
+#[must_use]
+pub fn frecency_score(access_count: i32, last_accessed: Option<i64>, now: i64, half_life_hours: f64) -> f64 {
+    let access_count = f64::from(access_count);
+
+    let recency_multiplier = last_accessed.map_or(1.0, |last_accessed| {
         #[allow(clippy::cast_precision_loss)]
         let age_seconds = (now - last_accessed).max(0) as f64;
         let age_hours = age_seconds / SECONDS_PER_HOUR;
 
-        f64::exp(-age_hours / HALF_LIFE_HOURS)
+        f64::exp(-age_hours / half_life_hours)
     });
 
     access_count * recency_multiplier
@@ -62,31 +88,88 @@ pub fn calculate_score(project: &ProjectRecord, now: i64) -> f64 {
 
 /// Sorts a slice of project records by frecency score in descending order.
 ///
-/// Projects with higher frecency scores (more frequently and recently accessed)
-/// appear first in the sorted slice.
+/// Pinned projects come first regardless of score. Within the pinned and
+/// unpinned groups, projects with higher frecency scores (more frequently and
+/// recently accessed) appear first. Equal scores are ordered by `name`, then
+/// `path`, so the order is stable across loads.
 ///
 /// # Parameters
 ///
 /// * `records` - Mutable slice of project records to sort in-place
+/// * `half_life_hours` - Decay half-life passed to [`calculate_score`]
 ///
 /// # Examples
 ///
 /// ```
-/// use crate::storage::{ProjectRecord, sort_by_frecency};
+/// use zessionizer::storage::{ProjectRecord, sort_by_frecency, DEFAULT_HALF_LIFE_HOURS};
 ///
 /// let mut projects = vec![
 ///     ProjectRecord::new("/home/user/old-project", "old-project"),
 ///     ProjectRecord::new("/home/user/new-project", "new-project"),
 /// ];
 ///
-/// sort_by_frecency(&mut projects);
+/// sort_by_frecency(&mut projects, DEFAULT_HALF_LIFE_HOURS);
 /// // projects is now sorted by frecency score (highest first)
 /// ```
-pub fn sort_by_frecency(records: &mut [ProjectRecord]) {
+///
+/// Freshly scanned projects share the same score and sort alphabetically:
+///
+/// ```
+/// use zessionizer::storage::{ProjectRecord, sort_by_frecency, DEFAULT_HALF_LIFE_HOURS};
+///
+/// let mut projects = vec![
+///     ProjectRecord::new("/work/web", "web"),
+///     ProjectRecord::new("/home/user/api", "api"),
+///     ProjectRecord::new("/work/api", "api"),
+///     ProjectRecord::new("/home/user/cli", "cli"),
+/// ];
+///
+/// sort_by_frecency(&mut projects, DEFAULT_HALF_LIFE_HOURS);
+///
+/// let paths: Vec<&str> = projects.iter().map(|p| p.path.as_str()).collect();
+/// assert_eq!(paths, ["/home/user/api", "/work/api", "/home/user/cli", "/work/web"]);
+/// ```
+///
+/// A pinned project outranks unpinned ones with far higher scores:
+///
+/// ```
+/// use zessionizer::storage::{ProjectRecord, sort_by_frecency, DEFAULT_HALF_LIFE_HOURS};
+///
+/// let now = chrono::Utc::now().timestamp();
+/// let mut busy = ProjectRecord::new("/code/busy", "busy");
+/// busy.access_count = 500;
+/// busy.last_accessed = Some(now);
+/// let mut rare = ProjectRecord::new("/code/rare", "rare");
+/// rare.access_count = 1;
+/// rare.last_accessed = Some(now - 90 * 24 * 3600);
+/// rare.pinned = true;
+/// let mut pinned_busy = ProjectRecord::new("/code/pinned-busy", "pinned-busy");
+/// pinned_busy.access_count = 50;
+/// pinned_busy.last_accessed = Some(now);
+/// pinned_busy.pinned = true;
+///
+/// let mut projects = vec![busy, rare, pinned_busy];
+/// sort_by_frecency(&mut projects, DEFAULT_HALF_LIFE_HOURS);
+///
+/// // Pinned projects first, each group in frecency order.
+/// let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+/// assert_eq!(names, ["pinned-busy", "rare", "busy"]);
+/// ```
+pub fn sort_by_frecency(records: &mut [ProjectRecord], half_life_hours: f64) {
     let now = chrono::Utc::now().timestamp();
-    records.sort_by(|a, b| {
-        let score_a = calculate_score(a, now);
-        let score_b = calculate_score(b, now);
-        score_b.partial_cmp(&score_a).unwrap_or(std::cmp::Ordering::Equal)
-    });
+    records.sort_by(|a, b| compare_by_frecency(a, b, now, half_life_hours));
+}
+
+/// Orders two project records the way [`sort_by_frecency`] does.
+///
+/// Pinned projects first, then higher scores at `now`, then `name` and `path`.
+#[must_use]
+pub fn compare_by_frecency(a: &ProjectRecord, b: &ProjectRecord, now: i64, half_life_hours: f64) -> Ordering {
+    let score_a = calculate_score(a, now, half_life_hours);
+    let score_b = calculate_score(b, now, half_life_hours);
+    b.pinned
+        .cmp(&a.pinned)
+        .then_with(|| score_b.partial_cmp(&score_a).unwrap_or(Ordering::Equal))
+        .then_with(|| a.name.cmp(&b.name))
+        .then_with(|| a.path.cmp(&b.path))
 }
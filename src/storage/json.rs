@@ -1,8 +1,9 @@
 //! JSON file-based storage backend.
 //!
 //! This module provides a simple, human-readable storage implementation using
-//! JSON serialization. It uses atomic file writes (write-to-temp + rename) to
-//! prevent corruption on crashes.
+//! JSON serialization. It uses atomic file writes (write-to-temp, fsync, then
+//! rename) to prevent corruption on crashes. Output is pretty-printed by
+//! default; compact output can be enabled for large stores.
 //!
 //! # Performance Characteristics
 //!
@@ -13,10 +14,65 @@
 
 use crate::domain::error::{Result, ZessionizerError};
 use crate::storage::backend::Storage;
-use crate::storage::models::{ProjectRecord, SessionRecord};
+use crate::storage::models::{session_project, BatchEntry, ProjectPage, ProjectRecord, ProjectSort, SessionRecord};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Atomically replaces `path` with `contents`.
+///
+/// Writes a sibling `.tmp` file, flushes it to disk with `sync_all`, then
+/// renames it over `path`, so a crash leaves either the old or the new file
+/// and never a truncated one. The parent directory is synced afterwards on a
+/// best-effort basis to persist the rename; platforms that cannot open
+/// directories skip that step.
+///
+/// # Errors
+///
+/// Returns an error if the temporary file cannot be written or synced, or
+/// the rename fails.
+///
+/// # Example
+///
+/// ```rust
+/// use zessionizer::storage::json::write_atomic;
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let path = dir.path().join("projects.json");
+///
+/// write_atomic(&path, b"{\"version\":1}").unwrap();
+/// write_atomic(&path, b"{\"version\":2}").unwrap();
+///
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"version\":2}");
+/// assert!(!path.with_extension("tmp").exists());
+/// ```
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+
+    tracing::trace!(tmp_path = ?tmp_path, "writing to temporary file");
+    let mut file = std::fs::File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    tracing::trace!("renaming temporary file to final location");
+    std::fs::rename(&tmp_path, path)?;
+
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::File::open(parent).and_then(|dir| dir.sync_all()) {
+            tracing::trace!(error = %e, "skipping directory sync");
+        }
+    }
+
+    Ok(())
+}
+
+/// Current version of the storage file format.
+///
+/// - 1: projects, sessions, and the last selection
+/// - 2: projects may carry `pinned` and `layout`
+pub const STORAGE_VERSION: u32 = 2;
 
 /// JSON storage container format.
 ///
@@ -34,18 +90,44 @@ struct StorageData {
     /// Active sessions linking session names to project paths.
     #[serde(default)]
     sessions: Vec<SessionRecord>,
+
+    /// Path of the project selected when the plugin was last closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_selected: Option<String>,
 }
 
 impl Default for StorageData {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: STORAGE_VERSION,
             projects: HashMap::new(),
             sessions: Vec::new(),
+            last_selected: None,
         }
     }
 }
 
+/// Upgrades storage data from older format versions, one version at a time.
+///
+/// Data from a newer version is returned unchanged, so downgrades never drop
+/// fields this build does not know about on the next save.
+fn migrate(mut data: StorageData) -> StorageData {
+    if data.version > STORAGE_VERSION {
+        tracing::warn!(version = data.version, supported = STORAGE_VERSION, "storage file is newer than supported");
+        return data;
+    }
+
+    if data.version < 2 {
+        // v2 adds `pinned` and `layout` to projects. Serde fills them in as
+        // unpinned with no layout, matching v1 behavior, so records are kept
+        // as loaded.
+        data.version = 2;
+        tracing::debug!(projects = data.projects.len(), "migrated storage data to version 2");
+    }
+
+    data
+}
+
 /// JSON file storage backend.
 ///
 /// Stores projects and sessions in a human-readable JSON file with atomic writes.
@@ -60,7 +142,7 @@ impl Default for StorageData {
 ///
 /// ```json
 /// {
-///   "version": 1,
+///   "version": 2,
 ///   "projects": {
 ///     "/path/to/project": {
 ///       "path": "/path/to/project",
@@ -75,7 +157,8 @@ impl Default for StorageData {
 ///       "name": "session-name",
 ///       "project_path": "/path/to/project"
 ///     }
-///   ]
+///   ],
+///   "last_selected": "/path/to/project"
 /// }
 /// ```
 pub struct JsonStorage {
@@ -87,20 +170,32 @@ pub struct JsonStorage {
 
     /// Tracks if data has been modified since last save.
     dirty: bool,
+
+    /// Whether to pretty-print the JSON file.
+    pretty: bool,
+
+    /// Nesting depth of open write batches; saves are deferred while nonzero.
+    batch_depth: u32,
+
+    /// Number of times the file has been written since opening.
+    writes: u64,
 }
 
 impl JsonStorage {
     /// Creates or opens a JSON storage backend.
     ///
     /// If the file exists, loads existing data. Otherwise creates a new empty storage.
-    /// Parent directories are created automatically.
+    /// Parent directories are created automatically. Files in an older format are
+    /// migrated to [`STORAGE_VERSION`] and written back immediately.
+    /// Files that cannot be parsed are moved aside as `<name>.corrupt.<timestamp>`
+    /// and storage starts empty.
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Parent directory creation fails
-    /// - File exists but contains invalid JSON
     /// - File permissions prevent reading
+    /// - File contains invalid JSON and cannot be moved aside
     ///
     /// # Examples
     ///
@@ -119,12 +214,12 @@ impl JsonStorage {
             std::fs::create_dir_all(parent)?;
         }
 
-        let data = if file_path.exists() {
+        let (data, migrated) = if file_path.exists() {
             tracing::debug!("loading existing data");
             Self::load_from_file(&file_path)?
         } else {
             tracing::debug!("initializing new empty storage");
-            StorageData::default()
+            (StorageData::default(), false)
         };
 
         tracing::debug!(
@@ -133,22 +228,52 @@ impl JsonStorage {
             "storage initialized"
         );
 
-        Ok(Self {
+        let mut storage = Self {
             file_path,
             data,
-            dirty: false,
-        })
+            dirty: migrated,
+            pretty: true,
+            batch_depth: 0,
+            writes: 0,
+        };
+        storage.save_to_file()?;
+        Ok(storage)
     }
 
-    /// Loads storage data from a JSON file.
+    /// Sets whether the file is written pretty-printed or compact.
+    ///
+    /// Compact output is smaller and faster to write; reading accepts either.
+    #[must_use]
+    pub const fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Returns how many times the file has been written since opening.
+    #[must_use]
+    pub const fn write_count(&self) -> u64 {
+        self.writes
+    }
+
+    /// Loads storage data from a JSON file, migrating older format versions.
+    ///
+    /// Returns the data and whether it was migrated. A file that cannot be
+    /// parsed is moved aside (see [`Self::back_up_corrupt_file`]) and empty
+    /// data is returned, so the plugin stays usable.
     ///
     /// # Errors
     ///
-    /// Returns an error if the file cannot be read or contains invalid JSON.
-    fn load_from_file(path: &PathBuf) -> Result<StorageData> {
+    /// Returns an error if the file cannot be read, or a corrupt file cannot be
+    /// moved aside.
+    fn load_from_file(path: &PathBuf) -> Result<(StorageData, bool)> {
         let contents = std::fs::read_to_string(path)?;
-        let data: StorageData = serde_json::from_str(&contents)
-            .map_err(|e| ZessionizerError::Storage(format!("failed to parse JSON: {e}")))?;
+        let data: StorageData = match serde_json::from_str(&contents) {
+            Ok(data) => data,
+            Err(e) => {
+                Self::back_up_corrupt_file(path, &e)?;
+                return Ok((StorageData::default(), false));
+            }
+        };
 
         tracing::debug!(
             version = data.version,
@@ -157,19 +282,46 @@ impl JsonStorage {
             "loaded storage data"
         );
 
-        Ok(data)
+        let version = data.version;
+        let data = migrate(data);
+        let migrated = data.version != version;
+        Ok((data, migrated))
+    }
+
+    /// Renames a corrupt storage file to `<name>.corrupt.<timestamp>`.
+    ///
+    /// The backup keeps the data for manual recovery while letting the next
+    /// save start a fresh file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rename fails.
+    fn back_up_corrupt_file(path: &Path, error: &serde_json::Error) -> Result<()> {
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(format!(".corrupt.{}", chrono::Utc::now().timestamp()));
+        let backup = PathBuf::from(backup);
+
+        std::fs::rename(path, &backup)?;
+        tracing::warn!(
+            path = ?path,
+            backup = ?backup,
+            error = %error,
+            "storage file is corrupt, moved it aside and starting empty"
+        );
+        Ok(())
     }
 
     /// Saves storage data to disk using atomic write.
     ///
-    /// Writes to a temporary file first, then atomically renames it to the target path.
-    /// This ensures the file is never left in a corrupt state, even if the process crashes.
+    /// Writes to a temporary file first, then atomically renames it to the target path
+    /// (see [`write_atomic`]). This ensures the file is never left in a corrupt state,
+    /// even if the process crashes or the machine loses power.
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - JSON serialization fails (should never happen with valid data)
-    /// - Temporary file cannot be written
+    /// - Temporary file cannot be written or synced
     /// - Rename operation fails (rare on POSIX systems)
     fn save_to_file(&mut self) -> Result<()> {
         if !self.dirty {
@@ -179,22 +331,34 @@ impl JsonStorage {
 
         tracing::debug!(path = ?self.file_path, "saving storage data");
 
-        let json = serde_json::to_string_pretty(&self.data)
-            .map_err(|e| ZessionizerError::Storage(format!("failed to serialize JSON: {e}")))?;
-
-        let tmp_path = self.file_path.with_extension("tmp");
-
-        tracing::trace!(tmp_path = ?tmp_path, "writing to temporary file");
-        std::fs::write(&tmp_path, json)?;
+        let json = if self.pretty {
+            serde_json::to_string_pretty(&self.data)
+        } else {
+            serde_json::to_string(&self.data)
+        }
+        .map_err(|e| ZessionizerError::Storage(format!("failed to serialize JSON: {e}")))?;
 
-        tracing::trace!("renaming temporary file to final location");
-        std::fs::rename(&tmp_path, &self.file_path)?;
+        write_atomic(&self.file_path, json.as_bytes())?;
 
+        self.writes += 1;
         self.dirty = false;
         tracing::debug!("storage saved successfully");
         Ok(())
     }
 
+    /// Saves after a mutation, unless a write batch is open.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saving fails.
+    fn persist(&mut self) -> Result<()> {
+        if self.batch_depth > 0 {
+            tracing::trace!(batch_depth = self.batch_depth, "deferring save until batch ends");
+            return Ok(());
+        }
+        self.save_to_file()
+    }
+
     /// Returns the next available project ID.
     ///
     /// IDs are 1-indexed. Returns the count of projects + 1.
@@ -232,13 +396,13 @@ impl Storage for JsonStorage {
         };
 
         self.dirty = true;
-        self.save_to_file()?;
+        self.persist()?;
 
         tracing::debug!(project_id = id, "project added");
         Ok(id)
     }
 
-    fn add_projects_batch(&mut self, projects: &[ProjectRecord]) -> Result<Vec<ProjectRecord>> {
+    fn add_projects_batch(&mut self, projects: &[ProjectRecord]) -> Result<Vec<BatchEntry>> {
         let _span = tracing::debug_span!("json_add_projects_batch",
             count = projects.len()
         ).entered();
@@ -247,18 +411,18 @@ impl Storage for JsonStorage {
 
         for project in projects {
             if let Some(existing) = self.data.projects.get_mut(&project.path) {
-                existing.name.clone_from(&project.name);
                 existing.last_accessed = project.last_accessed;
                 existing.access_count = existing.access_count.max(project.access_count);
-                added.push(existing.clone());
+                existing.tags.clone_from(&project.tags);
+                added.push(BatchEntry { record: existing.clone(), inserted: false });
             } else {
                 self.data.projects.insert(project.path.clone(), project.clone());
-                added.push(project.clone());
+                added.push(BatchEntry { record: project.clone(), inserted: true });
             }
         }
 
         self.dirty = true;
-        self.save_to_file()?;
+        self.persist()?;
 
         tracing::debug!(added_count = added.len(), "batch added");
         Ok(added)
@@ -273,6 +437,18 @@ impl Storage for JsonStorage {
         Ok(projects)
     }
 
+    fn get_projects_paginated(&self, offset: usize, limit: usize, sort: ProjectSort) -> Result<ProjectPage> {
+        let _span = tracing::debug_span!("json_get_projects_paginated",
+            offset = offset,
+            limit = limit
+        ).entered();
+
+        let page = ProjectPage::from_records(self.data.projects.values(), offset, limit, sort);
+
+        tracing::debug!(count = page.projects.len(), total = page.total, "retrieved project page");
+        Ok(page)
+    }
+
     fn update_project_access(&mut self, path: &str, timestamp: i64) -> Result<()> {
         let _span = tracing::debug_span!("json_update_project_access",
             path = %path,
@@ -287,7 +463,7 @@ impl Storage for JsonStorage {
         let new_count = project.access_count;
 
         self.dirty = true;
-        self.save_to_file()?;
+        self.persist()?;
 
         tracing::debug!(
             new_count = new_count,
@@ -296,6 +472,120 @@ impl Storage for JsonStorage {
         Ok(())
     }
 
+    fn reset_frecency(&mut self, path: Option<&str>) -> Result<()> {
+        let _span = tracing::debug_span!("json_reset_frecency",
+            path = ?path
+        ).entered();
+
+        let reset = |project: &mut ProjectRecord| {
+            project.access_count = 1;
+            project.last_accessed = None;
+        };
+
+        if let Some(path) = path {
+            let project = self.data.projects.get_mut(path)
+                .ok_or_else(|| ZessionizerError::Storage(format!("project not found: {path}")))?;
+            reset(project);
+        } else {
+            self.data.projects.values_mut().for_each(reset);
+        }
+
+        self.dirty = true;
+        self.persist()?;
+
+        tracing::debug!("frecency reset");
+        Ok(())
+    }
+
+    fn rename_project(&mut self, path: &str, name: &str) -> Result<()> {
+        let _span = tracing::debug_span!("json_rename_project",
+            path = %path,
+            name = %name
+        ).entered();
+
+        let project = self.data.projects.get_mut(path)
+            .ok_or_else(|| ZessionizerError::Storage(format!("project not found: {path}")))?;
+
+        project.name = name.to_string();
+        for session in self.data.sessions.iter_mut().filter(|s| s.project_path == path) {
+            session.name = name.to_string();
+        }
+
+        self.dirty = true;
+        self.persist()?;
+
+        tracing::debug!("project renamed");
+        Ok(())
+    }
+
+    fn set_project_pinned(&mut self, path: &str, pinned: bool) -> Result<()> {
+        let _span = tracing::debug_span!("json_set_project_pinned",
+            path = %path,
+            pinned = pinned
+        ).entered();
+
+        let project = self.data.projects.get_mut(path)
+            .ok_or_else(|| ZessionizerError::Storage(format!("project not found: {path}")))?;
+
+        if project.pinned == pinned {
+            tracing::trace!("pin state unchanged, skipping save");
+            return Ok(());
+        }
+
+        project.pinned = pinned;
+
+        self.dirty = true;
+        self.persist()?;
+
+        tracing::debug!("project pin state updated");
+        Ok(())
+    }
+
+    fn set_project_layout(&mut self, path: &str, layout: Option<&str>) -> Result<()> {
+        let _span = tracing::debug_span!("json_set_project_layout",
+            path = %path,
+            layout = ?layout
+        ).entered();
+
+        let project = self.data.projects.get_mut(path)
+            .ok_or_else(|| ZessionizerError::Storage(format!("project not found: {path}")))?;
+
+        if project.layout.as_deref() == layout {
+            tracing::trace!("layout unchanged, skipping save");
+            return Ok(());
+        }
+
+        project.layout = layout.map(str::to_string);
+
+        self.dirty = true;
+        self.persist()?;
+
+        tracing::debug!("project layout updated");
+        Ok(())
+    }
+
+    fn remove_project(&mut self, path: &str) -> Result<()> {
+        let _span = tracing::debug_span!("json_remove_project",
+            path = %path
+        ).entered();
+
+        if self.data.projects.remove(path).is_none() {
+            tracing::debug!("project not stored, nothing to remove");
+            return Ok(());
+        }
+
+        self.data.sessions.retain(|s| s.project_path != path);
+        if self.data.last_selected.as_deref() == Some(path) {
+            self.data.last_selected = None;
+        }
+
+        self.dirty = true;
+        self.persist()?;
+
+        tracing::debug!("project removed");
+        Ok(())
+    }
+
     fn get_project_by_path(&self, path: &str) -> Result<Option<ProjectRecord>> {
         let _span = tracing::debug_span!("json_get_project_by_path",
             path = %path
@@ -316,19 +606,30 @@ impl Storage for JsonStorage {
         Ok(sessions)
     }
 
-    fn sync_sessions(&mut self, active_session_names: &[String]) -> Result<()> {
+    fn sync_sessions(
+        &mut self,
+        active_session_names: &[String],
+        session_cwds: &HashMap<String, String>,
+    ) -> Result<()> {
         let _span = tracing::debug_span!("json_sync_sessions",
             active_count = active_session_names.len()
         ).entered();
 
-        // Clear old sessions and rebuild from active list
-        self.data.sessions.clear();
+        // Clear old sessions and rebuild from active list, remembering the
+        // previous links to disambiguate same-named projects
+        let previous: HashMap<String, String> = self.data.sessions
+            .drain(..)
+            .map(|session| (session.name, session.project_path))
+            .collect();
 
         // For each active session, try to find matching project
         for session_name in active_session_names {
-            if let Some(project) = self.data.projects.values()
-                .find(|p| p.name == *session_name)
-            {
+            if let Some(project) = session_project(
+                session_name,
+                session_cwds.get(session_name).map(String::as_str),
+                previous.get(session_name).map(String::as_str),
+                self.data.projects.values(),
+            ) {
                 self.data.sessions.push(SessionRecord {
                     name: session_name.clone(),
                     project_path: project.path.clone(),
@@ -337,7 +638,7 @@ impl Storage for JsonStorage {
         }
 
         self.dirty = true;
-        self.save_to_file()?;
+        self.persist()?;
 
         tracing::debug!(
             synced_count = self.data.sessions.len(),
@@ -345,6 +646,46 @@ impl Storage for JsonStorage {
         );
         Ok(())
     }
+
+    fn get_last_selected(&self) -> Result<Option<String>> {
+        Ok(self.data.last_selected.clone())
+    }
+
+    fn set_last_selected(&mut self, path: &str) -> Result<()> {
+        let _span = tracing::debug_span!("json_set_last_selected",
+            path = %path
+        ).entered();
+
+        if self.data.last_selected.as_deref() == Some(path) {
+            tracing::trace!("selection unchanged, skipping save");
+            return Ok(());
+        }
+
+        self.data.last_selected = Some(path.to_string());
+
+        self.dirty = true;
+        self.persist()?;
+
+        tracing::debug!("last selection saved");
+        Ok(())
+    }
+
+    /// Defers saves until the outermost batch ends.
+    ///
+    /// Batches without changes do not write the file.
+    fn begin_batch(&mut self) {
+        self.batch_depth += 1;
+        tracing::trace!(batch_depth = self.batch_depth, "write batch started");
+    }
+
+    fn end_batch(&mut self) -> Result<()> {
+        self.batch_depth = self.batch_depth.saturating_sub(1);
+        tracing::trace!(batch_depth = self.batch_depth, "write batch ended");
+        if !self.dirty {
+            return Ok(());
+        }
+        self.persist()
+    }
 }
 
 impl Drop for JsonStorage {
@@ -2,21 +2,33 @@
 //!
 //! This module provides the storage abstraction for persisting project information,
 //! tracking access patterns, and managing Zellij session associations. It uses
-//! JSON file storage with frecency-based sorting for project lists.
+//! JSON file storage by default, or `SQLite` with the `sqlite` feature, with
+//! frecency-based sorting for project lists.
 //!
 //! # Modules
 //!
 //! - `backend`: Storage trait abstraction for backend implementations
+//! - `export`: JSON export of the project list with frecency scores
 //! - `json`: JSON file-based storage implementation
+//! - `sqlite`: `SQLite` storage implementation (`sqlite` feature only)
 //! - `frecency`: Scoring algorithm combining frequency and recency
 //! - `models`: Storage record types separate from domain models
+//! - `ui_state`: Last view mode and search query, restored on reload
 
 pub mod backend;
+pub mod export;
 pub mod frecency;
 pub mod json;
 pub mod models;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod ui_state;
 
-pub use backend::Storage;
-pub use frecency::{calculate_score, sort_by_frecency};
+pub use backend::{with_batch, Storage, StorageBackendKind};
+pub use export::{ExportedProject, ProjectsExport, EXPORT_VERSION};
+pub use frecency::{calculate_score, frecency_score, sort_by_frecency, DEFAULT_HALF_LIFE_HOURS};
 pub use json::JsonStorage;
-pub use models::{ProjectRecord, SessionRecord};
+pub use models::{session_project, BatchEntry, ProjectPage, ProjectRecord, ProjectSort, SessionRecord};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStorage;
+pub use ui_state::UiState;
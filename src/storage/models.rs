@@ -4,8 +4,14 @@
 //! These types are separate from domain models to maintain a clear boundary between
 //! storage representation and business logic.
 
+use std::cmp::Ordering;
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
+use crate::domain::Project;
+use crate::storage::frecency::compare_by_frecency;
+
 /// Represents a project record in storage.
 ///
 /// This is the storage-layer representation of a project, containing all fields
@@ -27,12 +33,25 @@ pub struct ProjectRecord {
 
     /// Unix timestamp when the project was first added to storage.
     pub created_at: i64,
+
+    /// Whether the project is pinned above all unpinned projects.
+    #[serde(default)]
+    pub pinned: bool,
+
+    /// Zellij layout name used when creating the project's session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<String>,
+
+    /// Tags read from the project's `.zessionizer` marker file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl ProjectRecord {
     /// Creates a new project record with default values.
     ///
-    /// Sets `access_count` to 1, `last_accessed` to `None`, and `created_at` to the current time.
+    /// Sets `access_count` to 1, `last_accessed` and `layout` to `None`, `pinned`
+    /// to `false`, `tags` to empty, and `created_at` to the current time.
     ///
     /// # Examples
     ///
@@ -53,6 +72,130 @@ impl ProjectRecord {
             last_accessed: None,
             access_count: 1,
             created_at: chrono::Utc::now().timestamp(),
+            pinned: false,
+            layout: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Converts a stored record into the domain `Project` sent to the main thread.
+///
+/// A record that was never accessed uses its creation time as
+/// `last_accessed`. The access count is preserved for display and sorting.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::domain::Project;
+/// use zessionizer::storage::ProjectRecord;
+///
+/// let mut record = ProjectRecord::new("/home/user/code/myproject", "myproject");
+/// record.access_count = 42;
+///
+/// let project = Project::from(record.clone());
+/// assert_eq!(project.access_count, 42);
+/// assert_eq!(project.last_accessed, record.created_at);
+/// ```
+impl From<ProjectRecord> for Project {
+    fn from(record: ProjectRecord) -> Self {
+        Self {
+            id: None,
+            path: record.path,
+            name: record.name,
+            last_accessed: record.last_accessed.unwrap_or(record.created_at),
+            created_at: record.created_at,
+            access_count: record.access_count,
+            pinned: record.pinned,
+            layout: record.layout,
+            tags: record.tags,
+        }
+    }
+}
+
+/// Result of storing one project of a batch.
+///
+/// Returned by [`Storage::add_projects_batch`](crate::storage::Storage::add_projects_batch)
+/// so callers can tell newly discovered projects from rescanned ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEntry {
+    /// The project as stored after the batch.
+    pub record: ProjectRecord,
+
+    /// Whether the project was inserted, `false` if an existing one was updated.
+    pub inserted: bool,
+}
+
+/// Order of the projects returned by
+/// [`Storage::get_projects_paginated`](crate::storage::Storage::get_projects_paginated).
+///
+/// Every order keeps pinned projects first and breaks ties by name, then path,
+/// so pages never overlap or skip projects between requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectSort {
+    /// Highest frecency first, as [`sort_by_frecency`](crate::storage::sort_by_frecency).
+    Frecency {
+        /// Decay half-life in hours.
+        half_life_hours: f64,
+    },
+
+    /// Alphabetically by name.
+    Name,
+
+    /// Most recently accessed first.
+    RecentlyAccessed,
+
+    /// Highest access count first.
+    MostFrequent,
+}
+
+impl ProjectSort {
+    /// Orders two project records, with scores evaluated at `now`.
+    #[must_use]
+    pub fn compare(self, a: &ProjectRecord, b: &ProjectRecord, now: i64) -> Ordering {
+        let order = match self {
+            Self::Frecency { half_life_hours } => return compare_by_frecency(a, b, now, half_life_hours),
+            Self::Name => Ordering::Equal,
+            Self::RecentlyAccessed => b.last_accessed.cmp(&a.last_accessed),
+            Self::MostFrequent => b.access_count.cmp(&a.access_count),
+        };
+
+        b.pinned
+            .cmp(&a.pinned)
+            .then(order)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.path.cmp(&b.path))
+    }
+}
+
+/// One page of projects and the number of projects in storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectPage {
+    /// Projects of the requested page, in the requested order.
+    pub projects: Vec<ProjectRecord>,
+
+    /// Number of stored projects across all pages.
+    pub total: usize,
+}
+
+impl ProjectPage {
+    /// Sorts `records` by `sort` and clones the `limit` records from `offset`.
+    ///
+    /// Offsets past the end yield an empty page.
+    #[must_use]
+    pub fn from_records<'a>(
+        records: impl IntoIterator<Item = &'a ProjectRecord>,
+        offset: usize,
+        limit: usize,
+        sort: ProjectSort,
+    ) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        let mut records: Vec<&ProjectRecord> = records.into_iter().collect();
+        records.sort_by(|a, b| sort.compare(a, b, now));
+
+        Self {
+            projects: records.iter().skip(offset).take(limit).map(|&record| record.clone()).collect(),
+            total: records.len(),
         }
     }
 }
@@ -70,6 +213,92 @@ pub struct SessionRecord {
     pub project_path: String,
 }
 
+/// Picks the project a session belongs to.
+///
+/// Several projects may have the same directory name, so the session name
+/// alone is ambiguous. Candidates are the projects named `session`, chosen by:
+///
+/// 1. The project containing the session's working directory `cwd` (the
+///    deepest one if nested)
+/// 2. The project the session was linked to before (`previous`)
+/// 3. The most recently accessed project, since opening a project from the
+///    plugin updates its access time
+///
+/// If no project has the session's name, the deepest project of any name
+/// containing `cwd` is used. Returns `None` if neither exists.
+///
+/// # Examples
+///
+/// ```rust
+/// use zessionizer::storage::{session_project, ProjectRecord};
+///
+/// let mut work = ProjectRecord::new("/work/api", "api");
+/// work.last_accessed = Some(200);
+/// let mut personal = ProjectRecord::new("/home/me/api", "api");
+/// personal.last_accessed = Some(100);
+/// let projects = [work, personal, ProjectRecord::new("/code/web", "web")];
+///
+/// let path = |cwd, previous| session_project("api", cwd, previous, &projects).map(|p| p.path.as_str());
+///
+/// assert_eq!(path(Some("/home/me/api/src"), Some("/work/api")), Some("/home/me/api"));
+/// assert_eq!(path(None, Some("/home/me/api")), Some("/home/me/api"));
+/// assert_eq!(path(Some("/tmp"), None), Some("/work/api"));
+/// assert_eq!(session_project("cli", None, None, &projects), None);
+///
+/// // A session named differently is linked by its working directory
+/// let frontend = session_project("frontend", Some("/code/web/src"), None, &projects);
+/// assert_eq!(frontend.map(|p| p.path.as_str()), Some("/code/web"));
+/// assert_eq!(session_project("frontend", Some("/tmp"), None, &projects), None);
+/// ```
+#[must_use]
+pub fn session_project<'a>(
+    session: &str,
+    cwd: Option<&str>,
+    previous: Option<&str>,
+    projects: impl IntoIterator<Item = &'a ProjectRecord>,
+) -> Option<&'a ProjectRecord> {
+    let projects: Vec<&ProjectRecord> = projects.into_iter().collect();
+    let candidates: Vec<&ProjectRecord> = projects.iter().copied().filter(|p| p.name == session).collect();
+    let containing_cwd = |projects: &[&'a ProjectRecord]| {
+        cwd.and_then(|cwd| {
+            projects
+                .iter()
+                .copied()
+                .filter(|p| contains_dir(&p.path, cwd))
+                .max_by_key(|p| Path::new(&p.path).components().count())
+        })
+    };
+
+    if candidates.is_empty() {
+        return containing_cwd(&projects);
+    }
+
+    let by_cwd = containing_cwd(&candidates);
+    let by_previous = || {
+        previous.and_then(|previous| candidates.iter().find(|p| p.path == previous))
+    };
+    let most_recent = || {
+        candidates
+            .iter()
+            .max_by(|a, b| a.last_accessed.cmp(&b.last_accessed).then_with(|| b.path.cmp(&a.path)))
+    };
+
+    by_cwd.or_else(|| by_previous().or_else(most_recent).copied())
+}
+
+/// Returns whether `dir` is the project directory at `project_path` or below it.
+///
+/// Relative project paths (below the home directory) match any `dir` ending
+/// in them, and the sandbox's `/host` prefix is ignored.
+fn contains_dir(project_path: &str, dir: &str) -> bool {
+    let project = Path::new(project_path.strip_prefix("/host").unwrap_or(project_path));
+    let dir = Path::new(dir.strip_prefix("/host").unwrap_or(dir));
+
+    dir.ancestors().any(|ancestor| {
+        ancestor == project || (project.is_relative() && ancestor.ends_with(project))
+    })
+}
+
 impl SessionRecord {
     /// Creates a new session record.
     ///
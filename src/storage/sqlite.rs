@@ -0,0 +1,522 @@
+//! `SQLite`-backed storage backend.
+//!
+//! This module provides a [`Storage`] implementation on top of `SQLite`, for
+//! project lists too large to rewrite as a whole JSON file on every change.
+//! It is only compiled with the `sqlite` cargo feature, which bundles `SQLite`
+//! through `rusqlite` (building for `wasm32-wasip1` needs a WASI C toolchain,
+//! e.g. `WASI_SDK_PATH` pointing at `wasi-sdk`).
+//!
+//! This is the one part of the plugin that is not pure Rust. No pure-Rust
+//! `SQLite` implementation is mature enough to store user data, so the C
+//! library is bundled instead, and the feature stays off by default: the
+//! default build, and the JSON backend it uses, need only the Rust toolchain.
+//!
+//! # Performance Characteristics
+//!
+//! - **Read**: O(n) per project list query, nothing cached in memory
+//! - **Write**: O(1) per changed row, batched in transactions
+//! - **Best for**: > 1000 projects or frequent writes
+//!
+//! # Schema
+//!
+//! The schema is created on first open and upgraded through [`MIGRATIONS`],
+//! tracked with `SQLite`'s `user_version` pragma.
+
+use crate::domain::error::{Result, ZessionizerError};
+use crate::storage::backend::Storage;
+use crate::storage::models::{session_project, BatchEntry, ProjectRecord, SessionRecord};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Schema migrations, applied in order. Entry `i` upgrades `user_version` `i` to `i + 1`.
+const MIGRATIONS: &[&str] = &["
+    CREATE TABLE IF NOT EXISTS projects (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        path TEXT NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        last_accessed INTEGER,
+        access_count INTEGER NOT NULL DEFAULT 1,
+        created_at INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS sessions (
+        name TEXT NOT NULL,
+        project_path TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS meta (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+", "
+    ALTER TABLE projects ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0;
+", "
+    ALTER TABLE projects ADD COLUMN layout TEXT;
+", "
+    ALTER TABLE projects ADD COLUMN tags TEXT NOT NULL DEFAULT '';
+"];
+
+/// Columns selected for every project query, in [`project_from_row`] order.
+const PROJECT_COLUMNS: &str = "path, name, last_accessed, access_count, created_at, pinned, layout, tags";
+
+/// Separator of the tags stored in the `tags` column; tags never contain it.
+const TAG_SEPARATOR: char = ',';
+
+/// Meta key holding the last selected project path.
+const LAST_SELECTED_KEY: &str = "last_selected";
+
+/// `SQLite` storage backend.
+///
+/// Stores projects, sessions and the selection the way the JSON backend does.
+///
+/// # Thread Safety
+///
+/// This type is `Send` but not `Sync`, like [`JsonStorage`](crate::storage::JsonStorage).
+/// It's designed to be used from a single worker thread.
+pub struct SqliteStorage {
+    /// Open database connection.
+    conn: Connection,
+}
+
+impl SqliteStorage {
+    /// Creates or opens a `SQLite` storage backend, applying pending migrations.
+    ///
+    /// Parent directories are created automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Parent directory creation fails
+    /// - The database cannot be opened
+    /// - A migration fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use zessionizer::storage::SqliteStorage;
+    /// use std::path::Path;
+    ///
+    /// let storage = SqliteStorage::new(Path::new("/tmp/projects.sqlite3"))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// The first open creates the schema at the latest version; reopening
+    /// keeps the data and applies nothing. A database from before the `pinned`
+    /// column gains it on open, with existing projects unpinned.
+    pub fn new(file_path: &Path) -> Result<Self> {
+        tracing::debug!(path = ?file_path, "initializing SQLite storage");
+
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut conn = Connection::open(file_path).map_err(storage_error)?;
+        Self::migrate(&mut conn)?;
+
+        Ok(Self { conn })
+    }
+
+    /// Applies every migration newer than the database's `user_version`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the version or running a migration fails.
+    fn migrate(conn: &mut Connection) -> Result<()> {
+        let version: i64 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .map_err(storage_error)?;
+        let applied = usize::try_from(version).unwrap_or(0);
+
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(applied) {
+            tracing::debug!(version = index + 1, "applying storage migration");
+            let tx = conn.transaction().map_err(storage_error)?;
+            tx.execute_batch(migration).map_err(storage_error)?;
+            let next_version = i64::try_from(index + 1).unwrap_or(i64::MAX);
+            tx.pragma_update(None, "user_version", next_version).map_err(storage_error)?;
+            tx.commit().map_err(storage_error)?;
+        }
+
+        Ok(())
+    }
+
+    /// Looks up a single project by path.
+    fn query_project(conn: &Connection, path: &str) -> Result<Option<ProjectRecord>> {
+        conn.query_row(
+            &format!("SELECT {PROJECT_COLUMNS} FROM projects WHERE path = ?1"),
+            params![path],
+            project_from_row,
+        )
+        .optional()
+        .map_err(storage_error)
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn add_project(&mut self, project: &ProjectRecord) -> Result<i64> {
+        let _span = tracing::debug_span!("sqlite_add_project",
+            project_path = %project.path,
+            project_name = %project.name
+        ).entered();
+
+        self.conn
+            .execute(
+                "INSERT INTO projects (path, name, last_accessed, access_count, created_at, pinned, tags)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT(path) DO UPDATE SET
+                     name = excluded.name,
+                     last_accessed = excluded.last_accessed,
+                     access_count = excluded.access_count,
+                     tags = excluded.tags",
+                params![
+                    project.path,
+                    project.name,
+                    project.last_accessed,
+                    project.access_count,
+                    project.created_at,
+                    project.pinned,
+                    join_tags(&project.tags)
+                ],
+            )
+            .map_err(storage_error)?;
+
+        let id = self
+            .conn
+            .query_row("SELECT id FROM projects WHERE path = ?1", params![project.path], |row| row.get(0))
+            .map_err(storage_error)?;
+
+        tracing::debug!(project_id = id, "project added");
+        Ok(id)
+    }
+
+    fn add_projects_batch(&mut self, projects: &[ProjectRecord]) -> Result<Vec<BatchEntry>> {
+        let _span = tracing::debug_span!("sqlite_add_projects_batch",
+            count = projects.len()
+        ).entered();
+
+        let tx = self.conn.transaction().map_err(storage_error)?;
+        let mut added = Vec::with_capacity(projects.len());
+
+        for project in projects {
+            let inserted = Self::query_project(&tx, &project.path)?.is_none();
+            tx.execute(
+                "INSERT INTO projects (path, name, last_accessed, access_count, created_at, pinned, tags)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT(path) DO UPDATE SET
+                     last_accessed = excluded.last_accessed,
+                     access_count = MAX(access_count, excluded.access_count),
+                     tags = excluded.tags",
+                params![
+                    project.path,
+                    project.name,
+                    project.last_accessed,
+                    project.access_count,
+                    project.created_at,
+                    project.pinned,
+                    join_tags(&project.tags)
+                ],
+            )
+            .map_err(storage_error)?;
+
+            if let Some(record) = Self::query_project(&tx, &project.path)? {
+                added.push(BatchEntry { record, inserted });
+            }
+        }
+
+        tx.commit().map_err(storage_error)?;
+
+        tracing::debug!(added_count = added.len(), "batch added");
+        Ok(added)
+    }
+
+    fn get_all_projects(&self) -> Result<Vec<ProjectRecord>> {
+        let _span = tracing::debug_span!("sqlite_get_all_projects").entered();
+
+        let mut stmt = self
+            .conn
+            .prepare(&format!("SELECT {PROJECT_COLUMNS} FROM projects"))
+            .map_err(storage_error)?;
+        let projects = stmt
+            .query_map([], project_from_row)
+            .map_err(storage_error)?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(storage_error)?;
+
+        tracing::debug!(count = projects.len(), "retrieved projects");
+        Ok(projects)
+    }
+
+    fn update_project_access(&mut self, path: &str, timestamp: i64) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_update_project_access",
+            path = %path,
+            timestamp = timestamp
+        ).entered();
+
+        let updated = self
+            .conn
+            .execute(
+                "UPDATE projects SET last_accessed = ?2, access_count = access_count + 1 WHERE path = ?1",
+                params![path, timestamp],
+            )
+            .map_err(storage_error)?;
+
+        if updated == 0 {
+            return Err(ZessionizerError::Storage(format!("project not found: {path}")));
+        }
+
+        tracing::debug!("project access updated");
+        Ok(())
+    }
+
+    fn reset_frecency(&mut self, path: Option<&str>) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_reset_frecency",
+            path = ?path
+        ).entered();
+
+        let Some(path) = path else {
+            self.conn
+                .execute("UPDATE projects SET last_accessed = NULL, access_count = 1", [])
+                .map_err(storage_error)?;
+
+            tracing::debug!("frecency reset");
+            return Ok(());
+        };
+
+        let updated = self
+            .conn
+            .execute(
+                "UPDATE projects SET last_accessed = NULL, access_count = 1 WHERE path = ?1",
+                params![path],
+            )
+            .map_err(storage_error)?;
+
+        if updated == 0 {
+            return Err(ZessionizerError::Storage(format!("project not found: {path}")));
+        }
+
+        tracing::debug!("frecency reset");
+        Ok(())
+    }
+
+    fn rename_project(&mut self, path: &str, name: &str) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_rename_project",
+            path = %path,
+            name = %name
+        ).entered();
+
+        let tx = self.conn.transaction().map_err(storage_error)?;
+        let updated = tx
+            .execute("UPDATE projects SET name = ?2 WHERE path = ?1", params![path, name])
+            .map_err(storage_error)?;
+
+        if updated == 0 {
+            return Err(ZessionizerError::Storage(format!("project not found: {path}")));
+        }
+
+        tx.execute("UPDATE sessions SET name = ?2 WHERE project_path = ?1", params![path, name])
+            .map_err(storage_error)?;
+        tx.commit().map_err(storage_error)?;
+
+        tracing::debug!("project renamed");
+        Ok(())
+    }
+
+    fn set_project_pinned(&mut self, path: &str, pinned: bool) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_set_project_pinned",
+            path = %path,
+            pinned = pinned
+        ).entered();
+
+        let updated = self
+            .conn
+            .execute("UPDATE projects SET pinned = ?2 WHERE path = ?1", params![path, pinned])
+            .map_err(storage_error)?;
+
+        if updated == 0 {
+            return Err(ZessionizerError::Storage(format!("project not found: {path}")));
+        }
+
+        tracing::debug!("project pin state updated");
+        Ok(())
+    }
+
+    fn set_project_layout(&mut self, path: &str, layout: Option<&str>) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_set_project_layout",
+            path = %path,
+            layout = ?layout
+        ).entered();
+
+        let updated = self
+            .conn
+            .execute("UPDATE projects SET layout = ?2 WHERE path = ?1", params![path, layout])
+            .map_err(storage_error)?;
+
+        if updated == 0 {
+            return Err(ZessionizerError::Storage(format!("project not found: {path}")));
+        }
+
+        tracing::debug!("project layout updated");
+        Ok(())
+    }
+
+    fn remove_project(&mut self, path: &str) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_remove_project",
+            path = %path
+        ).entered();
+
+        let tx = self.conn.transaction().map_err(storage_error)?;
+        let removed = tx
+            .execute("DELETE FROM projects WHERE path = ?1", params![path])
+            .map_err(storage_error)?;
+        tx.execute("DELETE FROM sessions WHERE project_path = ?1", params![path])
+            .map_err(storage_error)?;
+        tx.execute(
+            "DELETE FROM meta WHERE key = ?1 AND value = ?2",
+            params![LAST_SELECTED_KEY, path],
+        )
+        .map_err(storage_error)?;
+        tx.commit().map_err(storage_error)?;
+
+        tracing::debug!(removed = removed > 0, "project removed");
+        Ok(())
+    }
+
+    fn get_project_by_path(&self, path: &str) -> Result<Option<ProjectRecord>> {
+        let _span = tracing::debug_span!("sqlite_get_project_by_path",
+            path = %path
+        ).entered();
+
+        let project = Self::query_project(&self.conn, path)?;
+
+        tracing::debug!(found = project.is_some(), "project lookup complete");
+        Ok(project)
+    }
+
+    fn get_all_sessions(&self) -> Result<Vec<SessionRecord>> {
+        let _span = tracing::debug_span!("sqlite_get_all_sessions").entered();
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, project_path FROM sessions")
+            .map_err(storage_error)?;
+        let sessions = stmt
+            .query_map([], |row| Ok(SessionRecord::new(row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(storage_error)?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(storage_error)?;
+
+        tracing::debug!(count = sessions.len(), "retrieved sessions");
+        Ok(sessions)
+    }
+
+    fn sync_sessions(
+        &mut self,
+        active_session_names: &[String],
+        session_cwds: &HashMap<String, String>,
+    ) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_sync_sessions",
+            active_count = active_session_names.len()
+        ).entered();
+
+        let tx = self.conn.transaction().map_err(storage_error)?;
+
+        // Remember the previous links to disambiguate same-named projects
+        let previous: HashMap<String, String> = {
+            let mut stmt = tx
+                .prepare("SELECT name, project_path FROM sessions")
+                .map_err(storage_error)?;
+            let rows = stmt
+                .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+                .map_err(storage_error)?;
+            rows.collect::<rusqlite::Result<_>>().map_err(storage_error)?
+        };
+
+        let projects = {
+            let mut stmt = tx
+                .prepare(&format!("SELECT {PROJECT_COLUMNS} FROM projects"))
+                .map_err(storage_error)?;
+            let rows = stmt.query_map([], project_from_row).map_err(storage_error)?;
+            rows.collect::<rusqlite::Result<Vec<_>>>().map_err(storage_error)?
+        };
+
+        tx.execute("DELETE FROM sessions", []).map_err(storage_error)?;
+
+        // Link each active session to its project, by name and working directory
+        for session_name in active_session_names {
+            let Some(project) = session_project(
+                session_name,
+                session_cwds.get(session_name).map(String::as_str),
+                previous.get(session_name).map(String::as_str),
+                &projects,
+            ) else {
+                continue;
+            };
+
+            tx.execute(
+                "INSERT INTO sessions (name, project_path) VALUES (?1, ?2)",
+                params![session_name, project.path],
+            )
+            .map_err(storage_error)?;
+        }
+
+        tx.commit().map_err(storage_error)?;
+
+        tracing::debug!("sessions synced");
+        Ok(())
+    }
+
+    fn get_last_selected(&self) -> Result<Option<String>> {
+        self.conn
+            .query_row("SELECT value FROM meta WHERE key = ?1", params![LAST_SELECTED_KEY], |row| row.get(0))
+            .optional()
+            .map_err(storage_error)
+    }
+
+    fn set_last_selected(&mut self, path: &str) -> Result<()> {
+        let _span = tracing::debug_span!("sqlite_set_last_selected",
+            path = %path
+        ).entered();
+
+        self.conn
+            .execute(
+                "INSERT INTO meta (key, value) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                params![LAST_SELECTED_KEY, path],
+            )
+            .map_err(storage_error)?;
+
+        tracing::debug!("last selection saved");
+        Ok(())
+    }
+}
+
+/// Builds a [`ProjectRecord`] from a row selected with [`PROJECT_COLUMNS`].
+fn project_from_row(row: &Row<'_>) -> rusqlite::Result<ProjectRecord> {
+    Ok(ProjectRecord {
+        path: row.get(0)?,
+        name: row.get(1)?,
+        last_accessed: row.get(2)?,
+        access_count: row.get(3)?,
+        created_at: row.get(4)?,
+        pinned: row.get(5)?,
+        layout: row.get(6)?,
+        tags: split_tags(&row.get::<_, String>(7)?),
+    })
+}
+
+/// Joins tags for the `tags` column.
+fn join_tags(tags: &[String]) -> String {
+    tags.join(&TAG_SEPARATOR.to_string())
+}
+
+/// Splits the `tags` column, an empty value meaning no tags.
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(TAG_SEPARATOR)
+        .filter(|tag| !tag.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Converts a `SQLite` error into a storage error.
+#[allow(clippy::needless_pass_by_value)]
+fn storage_error(e: rusqlite::Error) -> ZessionizerError {
+    ZessionizerError::Storage(format!("sqlite: {e}"))
+}
@@ -0,0 +1,104 @@
+//! Persistence of the plugin's last view across reloads.
+//!
+//! The view mode, search query and recent search queries are kept in
+//! `ui_state.json` in the data
+//! directory, next to the project storage but independent of its backend.
+//! The file is small and rewritten whenever the plugin closes or a project is
+//! selected.
+//!
+//! # Format
+//!
+//! ```json
+//! { "view_mode": "all", "query": "api", "search_history": ["api", "web"] }
+//! ```
+//!
+//! `view_mode` uses the view names of the `enter_action` configuration
+//! (`sessions`, `projects`, `all`). `search_history` lists queries newest
+//! first. All fields are optional; a missing or corrupt file loads as the
+//! default state.
+//!
+//! # Example
+//!
+//! ```rust
+//! use zessionizer::storage::UiState;
+//!
+//! let dir = tempfile::tempdir().unwrap();
+//! let path = dir.path().join("ui_state.json");
+//!
+//! let state = UiState {
+//!     view_mode: Some("all".to_string()),
+//!     query: "api".to_string(),
+//!     search_history: vec!["api".to_string(), "web".to_string()],
+//! };
+//! state.save(&path).unwrap();
+//! assert_eq!(UiState::load(&path), state);
+//!
+//! std::fs::write(&path, "{ not json").unwrap();
+//! assert_eq!(UiState::load(&path), UiState::default());
+//! assert_eq!(UiState::load(&dir.path().join("missing.json")), UiState::default());
+//! ```
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::{Result, ZessionizerError};
+
+/// File name of the UI state within the data directory.
+pub const UI_STATE_FILE: &str = "ui_state.json";
+
+/// View state restored when the plugin is loaded again.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UiState {
+    /// Name of the last view mode, `None` to keep the default view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_mode: Option<String>,
+
+    /// Last search query, empty if no search was active.
+    #[serde(default)]
+    pub query: String,
+
+    /// Recent search queries, newest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_history: Vec<String>,
+}
+
+impl UiState {
+    /// Reads the UI state from `path`.
+    ///
+    /// A missing or unparsable file yields the default state, so a damaged
+    /// file never prevents the plugin from loading.
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                tracing::debug!(path = ?path, error = %e, "no UI state to restore");
+                return Self::default();
+            }
+        };
+
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            tracing::warn!(path = ?path, error = %e, "ignoring corrupt UI state");
+            Self::default()
+        })
+    }
+
+    /// Writes the UI state to `path`, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string(self)
+            .map_err(|e| ZessionizerError::Storage(format!("failed to serialize UI state: {e}")))?;
+        std::fs::write(path, json)?;
+
+        tracing::debug!(path = ?path, "UI state saved");
+        Ok(())
+    }
+}
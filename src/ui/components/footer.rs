@@ -8,8 +8,9 @@ use crate::ui::helpers::position_cursor;
 
 /// Renders the footer help bar at the specified row.
 ///
-/// Displays keybinding hints centered horizontally with dimmed styling. Pads
-/// the line to fill the entire terminal width.
+/// Displays keybinding hints centered horizontally with dimmed styling, and
+/// the counts in the theme's status color. Pads the line to fill the entire
+/// terminal width.
 ///
 /// # Parameters
 ///
@@ -25,7 +26,7 @@ use crate::ui::helpers::position_cursor;
 /// # Layout
 ///
 /// ```text
-/// [left padding] keybindings [right padding]
+/// [left padding] keybindings  [counts] [right padding]
 /// ```
 ///
 /// Padding is split evenly on both sides to center the text. If the terminal
@@ -34,7 +35,8 @@ use crate::ui::helpers::position_cursor;
 /// # Truncation
 ///
 /// If the help text exceeds terminal width, it is truncated to fit. This
-/// prevents layout corruption on narrow terminals.
+/// prevents layout corruption on narrow terminals. Counts are kept and the
+/// keybindings are truncated first.
 ///
 /// # Example
 ///
@@ -44,22 +46,33 @@ use crate::ui::helpers::position_cursor;
 /// use crate::ui::Theme;
 ///
 /// let footer = FooterInfo {
-///     keybindings: "q: quit | /: search | n: projects".to_string()
+///     keybindings: "q: quit | /: search | n: projects".to_string(),
+///     counts: Some("[12/340]".to_string()),
 /// };
 /// let theme = Theme::default();
 /// let next_row = render_footer(1, &footer, &theme, 80);
 /// ```
 pub fn render_footer(row: usize, footer: &FooterInfo, theme: &Theme, cols: usize) -> usize {
-    let help_text = &footer.keybindings;
+    let counts = footer
+        .counts
+        .as_ref()
+        .map_or_else(String::new, |counts| format!("  {counts}"));
+    let counts_len = counts.chars().count().min(cols);
 
-    let text_len = help_text.len().min(cols);
+    let keybindings: String = footer.keybindings.chars().take(cols - counts_len).collect();
+    let counts: String = counts.chars().take(counts_len).collect();
+
+    let text_len = keybindings.chars().count() + counts_len;
     let padding = (cols.saturating_sub(text_len)) / 2;
 
     position_cursor(row, 1);
-    print!("{}", Theme::fg(&theme.colors.text_dim));
+    print!("{}", theme.fg(&theme.colors.text_dim));
     print!("{}", " ".repeat(padding));
-    print!("{help_text}");
+    print!("{keybindings}");
+    print!("{}", theme.fg(theme.colors.status_fg()));
+    print!("{counts}");
+    print!("{}", theme.fg(&theme.colors.text_dim));
     print!("{}", " ".repeat(cols.saturating_sub(padding + text_len)));
-    print!("{}", Theme::reset());
+    print!("{}", theme.reset());
     row + 1
 }
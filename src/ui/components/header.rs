@@ -49,16 +49,16 @@ pub fn render_header(row: usize, header: &HeaderInfo, theme: &Theme, cols: usize
     let padding = (cols.saturating_sub(title_len)) / 2;
 
     position_cursor(row, 1);
-    print!("{}", Theme::bold());
-    print!("{}", Theme::fg(&theme.colors.header_fg));
+    print!("{}", theme.bold());
+    print!("{}", theme.fg(&theme.colors.header_fg));
     if let Some(bg) = &theme.colors.header_bg {
-        print!("{}", Theme::bg(bg));
+        print!("{}", theme.bg(bg));
     }
 
     print!("{}", " ".repeat(padding));
     print!("{}", header.title);
     print!("{}", " ".repeat(cols.saturating_sub(padding + title_len)));
 
-    print!("{}", Theme::reset());
+    print!("{}", theme.reset());
     row + 1
 }
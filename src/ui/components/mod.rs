@@ -19,6 +19,11 @@
 //! - [`render_normal_mode`]: Header + Table + Footer
 //! - [`render_search_mode`]: Header + `SearchBar` + Table + Footer
 //!
+//! Both branch on the view model's [`LayoutDensity`]: compact layouts drop the
+//! blank top line and the borders around the list.
+//!
+//! Panes too small for either get a one-line notice from [`render_too_small`].
+//!
 //! # Example
 //!
 //! ```rust
@@ -39,8 +44,9 @@ mod empty;
 
 pub use empty::render_empty_state;
 
+use crate::app::LayoutDensity;
 use crate::ui::theme::Theme;
-use crate::ui::viewmodel::{UIViewModel, SearchBarInfo};
+use crate::ui::viewmodel::{UIViewModel, SearchBarInfo, NAME_COLUMN_WIDTH, TIME_COLUMN_WIDTH, VISITS_COLUMN_WIDTH};
 use crate::ui::helpers::position_cursor;
 
 use header::render_header;
@@ -48,6 +54,33 @@ use footer::render_footer;
 use search::render_search_bar;
 use table::{render_table_headers, render_table_rows};
 
+/// Notice shown when the pane is too small for the UI.
+const TOO_SMALL_MESSAGE: &str = "Pane too small";
+
+/// Renders a one-line notice in a pane too small for the UI.
+///
+/// The message is centered on the middle row and cut to the pane width.
+/// Nothing is drawn in a zero-sized pane.
+///
+/// # Parameters
+///
+/// * `theme` - Active color theme (uses the `text_dim` color)
+/// * `rows` - Terminal height in rows
+/// * `cols` - Terminal width in columns
+pub fn render_too_small(theme: &Theme, rows: usize, cols: usize) {
+    if rows == 0 || cols == 0 {
+        return;
+    }
+
+    let message: String = TOO_SMALL_MESSAGE.chars().take(cols).collect();
+    let padding = cols.saturating_sub(message.chars().count()) / 2;
+
+    position_cursor((rows + 1) / 2, 1);
+    print!("{}", theme.fg(&theme.colors.text_dim));
+    print!("{}{message}", " ".repeat(padding));
+    print!("{}", theme.reset());
+}
+
 /// Renders a horizontal border line at the specified row.
 ///
 /// Used to separate UI sections (header/table, table/footer).
@@ -55,20 +88,125 @@ use table::{render_table_headers, render_table_rows};
 /// # Parameters
 ///
 /// * `row` - Row position to render the border (1-indexed)
-/// * `color` - Hex color for the border
+/// * `theme` - Active color theme (uses the `border` color)
 /// * `cols` - Terminal width in columns
 ///
 /// # Returns
 ///
 /// The next available row position (row + 1)
-fn render_border(row: usize, color: &str, cols: usize) -> usize {
+fn render_border(row: usize, theme: &Theme, cols: usize) -> usize {
     position_cursor(row, 1);
-    print!("{}", Theme::fg(color));
+    print!("{}", theme.fg(&theme.colors.border));
     print!("{}", "─".repeat(cols));
-    print!("{}", Theme::reset());
+    print!("{}", theme.reset());
     row + 1
 }
 
+/// Renders a scroll hint right-aligned to end just before column `end_col`.
+///
+/// Skipped if the hint would overlap the first `min_col` columns.
+fn render_scroll_hint(row: usize, hint: &str, min_col: usize, end_col: usize, theme: &Theme) {
+    let width = hint.chars().count() + 2;
+    let Some(start) = end_col.checked_sub(width).filter(|start| *start > min_col) else {
+        return;
+    };
+
+    position_cursor(row, start + 1);
+    print!("{}", theme.fg(theme.colors.scrollbar_fg()));
+    print!(" {hint} ");
+    print!("{}", theme.reset());
+}
+
+/// Renders the table headers, with the hint for rows scrolled out above.
+///
+/// # Returns
+///
+/// The next available row position
+fn render_headers_with_scroll_hint(row: usize, vm: &UIViewModel, theme: &Theme, cols: usize) -> usize {
+    let next_row = render_table_headers(row, theme, cols, vm.show_path, vm.show_time, &vm.column_separator);
+
+    if let Some(above) = vm.scroll_info.as_ref().and_then(|info| info.above.as_deref()) {
+        let min_col = if vm.show_path {
+            NAME_COLUMN_WIDTH + vm.column_separator.chars().count().max(1) + "PATH".len()
+        } else {
+            "NAME".len()
+        };
+        let end_col = if vm.show_time {
+            cols.saturating_sub(VISITS_COLUMN_WIDTH + TIME_COLUMN_WIDTH)
+        } else {
+            cols
+        };
+        render_scroll_hint(row, above, min_col, end_col, theme);
+    }
+
+    next_row
+}
+
+/// Renders the border under the table, with the hint for rows scrolled out below.
+///
+/// While rows are scrolled out below, the border is drawn in the scrollbar
+/// track color to carry the hint.
+fn render_bottom_border(row: usize, vm: &UIViewModel, theme: &Theme, cols: usize) {
+    let Some(below) = vm.scroll_info.as_ref().and_then(|info| info.below.as_deref()) else {
+        render_border(row, theme, cols);
+        return;
+    };
+
+    position_cursor(row, 1);
+    print!("{}", theme.fg(theme.colors.scrollbar_track()));
+    print!("{}", "─".repeat(cols));
+    print!("{}", theme.reset());
+    render_scroll_hint(row, below, 0, cols.saturating_sub(1), theme);
+}
+
+/// Renders the header, preceded by a blank line and followed by a border
+/// unless the layout is compact.
+///
+/// # Returns
+///
+/// The next available row position
+fn render_top(vm: &UIViewModel, theme: &Theme, cols: usize) -> usize {
+    match vm.layout_density {
+        LayoutDensity::Comfortable => {
+            let row = render_header(2, &vm.header, theme, cols); // Skip blank line at row 1
+            render_border(row, theme, cols)
+        }
+        LayoutDensity::Compact => render_header(1, &vm.header, theme, cols),
+    }
+}
+
+/// Renders the footer, preceded by the bottom border unless the layout is
+/// compact.
+///
+/// Compact layouts put the footer on the last row and have no border to
+/// carry the hint for rows scrolled out below.
+fn render_bottom(vm: &UIViewModel, theme: &Theme, cols: usize, rows: usize) {
+    match vm.layout_density {
+        LayoutDensity::Comfortable => {
+            let footer_start = rows.saturating_sub(1);
+            render_bottom_border(footer_start.saturating_sub(1), vm, theme, cols);
+            render_footer(footer_start, &vm.footer, theme, cols);
+        }
+        LayoutDensity::Compact => {
+            render_footer(rows, &vm.footer, theme, cols);
+        }
+    }
+}
+
+/// Renders the table rows, or the empty state message when there are none.
+///
+/// # Returns
+///
+/// The next available row position
+fn render_rows_or_empty_state(row: usize, vm: &UIViewModel, theme: &Theme, cols: usize) -> usize {
+    if let Some(empty) = &vm.empty_state {
+        render_empty_state(row + 1, empty, theme, cols);
+        return row + 3;
+    }
+
+    render_table_rows(row, &vm.display_items, theme, cols, vm.show_path, vm.show_time, &vm.column_separator)
+}
+
 /// Renders the normal mode layout (no search bar).
 ///
 /// Layout structure:
@@ -76,13 +214,15 @@ fn render_border(row: usize, color: &str, cols: usize) -> usize {
 /// [blank line]
 /// [Header]
 /// [Border]
-/// [Table Headers]
-/// [Table Rows]
+/// [Table Headers + "↑ n more"]
+/// [Table Rows or Empty State]
 /// [Blank padding to fill screen]
-/// [Border]
+/// [Border + "↓ n more"]
 /// [Footer]
 /// ```
 ///
+/// The compact layout leaves out the blank line and both borders.
+///
 /// # Parameters
 ///
 /// * `vm` - View model with display items and metadata
@@ -92,21 +232,15 @@ fn render_border(row: usize, color: &str, cols: usize) -> usize {
 ///
 /// # Line Accounting
 ///
-/// Reserves 6 lines for chrome (blank, header, 2 borders, header row, footer).
-/// Fills remaining space with table rows and blank lines.
+/// Reserves 6 lines for chrome (blank, header, 2 borders, header row, footer),
+/// or 3 when compact. Fills remaining space with table rows and blank lines.
 pub fn render_normal_mode(vm: &UIViewModel, theme: &Theme, cols: usize, rows: usize) {
-    let mut current_row = 2; // Start at row 2 (skip blank line at row 1)
-
-    current_row = render_header(current_row, &vm.header, theme, cols);
-    current_row = render_border(current_row, &theme.colors.border, cols);
-    current_row = render_table_headers(current_row, theme);
-    let _current_row = render_table_rows(current_row, &vm.display_items, theme, cols);
+    let mut current_row = render_top(vm, theme, cols);
 
-    let footer_start = rows.saturating_sub(1);
-    let border_row = footer_start.saturating_sub(1);
+    current_row = render_headers_with_scroll_hint(current_row, vm, theme, cols);
+    let _current_row = render_rows_or_empty_state(current_row, vm, theme, cols);
 
-    render_border(border_row, &theme.colors.border, cols);
-    render_footer(footer_start, &vm.footer, theme, cols);
+    render_bottom(vm, theme, cols, rows);
 }
 
 /// Renders the search mode layout (with search bar).
@@ -117,13 +251,15 @@ pub fn render_normal_mode(vm: &UIViewModel, theme: &Theme, cols: usize, rows: us
 /// [Header]
 /// [Border]
 /// [Search Bar - 3 lines]
-/// [Table Headers]
-/// [Table Rows]
+/// [Table Headers + "↑ n more"]
+/// [Table Rows or Empty State]
 /// [Blank padding to fill screen]
-/// [Border]
+/// [Border + "↓ n more"]
 /// [Footer]
 /// ```
 ///
+/// The compact layout leaves out the blank line and both borders.
+///
 /// # Parameters
 ///
 /// * `vm` - View model with display items and metadata
@@ -135,19 +271,14 @@ pub fn render_normal_mode(vm: &UIViewModel, theme: &Theme, cols: usize, rows: us
 /// # Line Accounting
 ///
 /// Reserves 9 lines for chrome (blank, header, 2 borders, search bar [3 lines],
-/// header row, footer). Fills remaining space with table rows and blank lines.
+/// header row, footer), or 6 when compact. Fills remaining space with table
+/// rows and blank lines.
 pub fn render_search_mode(vm: &UIViewModel, search: &SearchBarInfo, theme: &Theme, cols: usize, rows: usize) {
-    let mut current_row = 2; // Start at row 2 (skip blank line at row 1)
+    let mut current_row = render_top(vm, theme, cols);
 
-    current_row = render_header(current_row, &vm.header, theme, cols);
-    current_row = render_border(current_row, &theme.colors.border, cols);
     current_row = render_search_bar(current_row, search, theme, cols);
-    current_row = render_table_headers(current_row, theme);
-    let _current_row = render_table_rows(current_row, &vm.display_items, theme, cols);
-
-    let footer_start = rows.saturating_sub(1);
-    let border_row = footer_start.saturating_sub(1);
+    current_row = render_headers_with_scroll_hint(current_row, vm, theme, cols);
+    let _current_row = render_rows_or_empty_state(current_row, vm, theme, cols);
 
-    render_border(border_row, &theme.colors.border, cols);
-    render_footer(footer_start, &vm.footer, theme, cols);
+    render_bottom(vm, theme, cols, rows);
 }
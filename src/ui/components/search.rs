@@ -12,8 +12,9 @@ const SEARCH_BOX_MARGIN: usize = 5;
 
 /// Renders the search input box at the specified row.
 ///
-/// Displays a 3-line bordered box containing the search query text. The box
-/// is horizontally centered with margins on both sides.
+/// Displays a 3-line bordered box containing the search query text, or the
+/// new name while renaming a session. The box is horizontally centered with
+/// margins on both sides.
 ///
 /// # Parameters
 ///
@@ -41,7 +42,9 @@ const SEARCH_BOX_MARGIN: usize = 5;
 ///
 /// - Borders use theme `search_bar_border` color
 /// - Query text uses theme `text_normal` color
-/// - Query is displayed as " Search: {query}"
+/// - Query is displayed as " {label}: {query}"
+/// - The cursor, if any, is drawn in reverse video over the character it
+///   precedes, or over a space after the query
 /// - Right padding fills remaining space to box edge
 ///
 /// # Example
@@ -51,7 +54,7 @@ const SEARCH_BOX_MARGIN: usize = 5;
 /// use crate::ui::viewmodel::SearchBarInfo;
 /// use crate::ui::Theme;
 ///
-/// let search = SearchBarInfo { query: "proj".to_string() };
+/// let search = SearchBarInfo { label: "Search".to_string(), query: "proj".to_string(), cursor: Some(4) };
 /// let theme = Theme::default();
 /// let next_row = render_search_bar(1, &search, &theme, 80);
 /// ```
@@ -61,29 +64,50 @@ pub fn render_search_bar(row: usize, search: &SearchBarInfo, theme: &Theme, cols
 
     position_cursor(row, 1);
     print!("{}", " ".repeat(SEARCH_BOX_MARGIN));
-    print!("{}", Theme::fg(&theme.colors.search_bar_border));
+    print!("{}", theme.fg(&theme.colors.search_bar_border));
     print!("┌{}┐", "─".repeat(inner_width));
-    print!("{}", Theme::reset());
+    print!("{}", theme.reset());
 
-    let search_text = format!(" Search: {}", search.query);
-    let padding = inner_width.saturating_sub(search_text.len());
+    let prefix = format!(" {}: ", search.label);
+    let query: Vec<char> = search.query.chars().collect();
+    let cursor = search.cursor.map(|cursor| cursor.min(query.len()));
+    let cursor_at_end = cursor == Some(query.len());
+    let text_len = prefix.chars().count() + query.len() + usize::from(cursor_at_end);
+    let padding = inner_width.saturating_sub(text_len);
 
     position_cursor(row + 1, 1);
     print!("{}", " ".repeat(SEARCH_BOX_MARGIN));
-    print!("{}", Theme::fg(&theme.colors.search_bar_border));
+    print!("{}", theme.fg(&theme.colors.search_bar_border));
     print!("│");
-    print!("{}", Theme::fg(&theme.colors.text_normal));
-    print!("{search_text}");
+    print!("{}", theme.fg(&theme.colors.text_normal));
+    print!("{prefix}");
+    match cursor {
+        Some(cursor) => render_query_with_cursor(&query, cursor, theme),
+        None => print!("{}", search.query),
+    }
     print!("{}", " ".repeat(padding));
-    print!("{}", Theme::fg(&theme.colors.search_bar_border));
+    print!("{}", theme.fg(&theme.colors.search_bar_border));
     print!("│");
-    print!("{}", Theme::reset());
+    print!("{}", theme.reset());
 
     position_cursor(row + 2, 1);
     print!("{}", " ".repeat(SEARCH_BOX_MARGIN));
-    print!("{}", Theme::fg(&theme.colors.search_bar_border));
+    print!("{}", theme.fg(&theme.colors.search_bar_border));
     print!("└{}┘", "─".repeat(inner_width));
-    print!("{}", Theme::reset());
+    print!("{}", theme.reset());
 
     row + 3
 }
+
+/// Prints the query with the character at `cursor` (or a trailing space) in
+/// reverse video.
+fn render_query_with_cursor(query: &[char], cursor: usize, theme: &Theme) {
+    let before: String = query[..cursor].iter().collect();
+    let under = query.get(cursor).copied().unwrap_or(' ');
+    let after: String = query.get(cursor + 1..).unwrap_or_default().iter().collect();
+
+    print!("{before}");
+    print!("{}{under}{}", theme.reverse(), theme.reset());
+    print!("{}", theme.fg(&theme.colors.text_normal));
+    print!("{after}");
+}
@@ -1,22 +1,31 @@
 //! Table component renderer.
 //!
-//! This module renders the project/session list as a two-column table with
-//! NAME and PATH columns. It supports selection highlighting and fuzzy match
-//! highlighting.
+//! This module renders the project/session list as a table with NAME and PATH
+//! columns, followed by right-aligned VISITS and TIME columns on wide
+//! terminals. The
+//! PATH column can be hidden, leaving a single full-width NAME column. An
+//! optional separator, drawn in the theme's border color, marks the NAME/PATH
+//! boundary. It supports selection highlighting and fuzzy match highlighting.
 
 use crate::ui::theme::Theme;
-use crate::ui::viewmodel::DisplayItem;
+use crate::ui::viewmodel::{path_column, DisplayItem, NAME_COLUMN_WIDTH, TIME_COLUMN_WIDTH, VISITS_COLUMN_WIDTH};
 use crate::ui::helpers::{self, position_cursor};
 
 /// Renders the table column headers at the specified row.
 ///
 /// Displays "NAME" and "PATH" column headers with bold styling and theme colors.
-/// Uses fixed column width (37 characters for NAME).
+/// Uses fixed column width ([`NAME_COLUMN_WIDTH`] characters for NAME). Only "NAME" is shown when
+/// the PATH column is hidden. "VISITS" and "TIME" are right-aligned at the end
+/// of the row when those columns are visible.
 ///
 /// # Parameters
 ///
 /// * `row` - Row position to render the headers (1-indexed)
 /// * `theme` - Active color theme
+/// * `cols` - Terminal width in columns (for the VISITS/TIME position)
+/// * `show_path` - Whether the PATH column is visible
+/// * `show_time` - Whether the VISITS and TIME columns are visible
+/// * `separator` - Column separator text (empty for plain spacing)
 ///
 /// # Returns
 ///
@@ -29,15 +38,30 @@ use crate::ui::helpers::{self, position_cursor};
 /// use crate::ui::Theme;
 ///
 /// let theme = Theme::default();
-/// let next_row = render_table_headers(1, &theme);
-/// // Output: "NAME                                 PATH"
+/// let next_row = render_table_headers(1, &theme, 80, true, true, "");
+/// // Output: "NAME                                 PATH                  VISITS      TIME"
 /// ```
-pub fn render_table_headers(row: usize, theme: &Theme) -> usize {
+pub fn render_table_headers(row: usize, theme: &Theme, cols: usize, show_path: bool, show_time: bool, separator: &str) -> usize {
     position_cursor(row, 1);
-    print!("{}", Theme::bold());
-    print!("{}", Theme::fg(&theme.colors.header_fg));
-    print!("{:<37} {:<}", "NAME", "PATH");
-    print!("{}", Theme::reset());
+    print!("{}", theme.bold());
+    print!("{}", theme.fg(&theme.colors.header_fg));
+    if show_path && separator.is_empty() {
+        print!("{:<width$} {:<}", "NAME", "PATH", width = NAME_COLUMN_WIDTH);
+    } else if show_path {
+        print!("{:<width$}", "NAME", width = NAME_COLUMN_WIDTH);
+        render_separator(separator, theme);
+        print!("{}", theme.fg(&theme.colors.header_fg));
+        print!("PATH");
+    } else {
+        print!("NAME");
+    }
+    if show_time {
+        position_cursor(row, cols.saturating_sub(VISITS_COLUMN_WIDTH + TIME_COLUMN_WIDTH) + 1);
+        print!("{}", theme.fg(&theme.colors.header_fg));
+        print!("{:>width$}", "VISITS", width = VISITS_COLUMN_WIDTH);
+        print!("{:>width$}", "TIME", width = TIME_COLUMN_WIDTH);
+    }
+    print!("{}", theme.reset());
     row + 1
 }
 
@@ -52,25 +76,59 @@ pub fn render_table_headers(row: usize, theme: &Theme) -> usize {
 /// * `items` - List of display items to render
 /// * `theme` - Active color theme
 /// * `cols` - Terminal width in columns (for padding)
+/// * `show_path` - Whether the PATH column is visible
+/// * `show_time` - Whether the VISITS and TIME columns are visible
+/// * `separator` - Column separator text (empty for plain spacing)
 ///
 /// # Returns
 ///
 /// The next available row position (row + number of items)
-pub fn render_table_rows(row: usize, items: &[DisplayItem], theme: &Theme, cols: usize) -> usize {
+pub fn render_table_rows(row: usize, items: &[DisplayItem], theme: &Theme, cols: usize, show_path: bool, show_time: bool, separator: &str) -> usize {
     let mut current_row = row;
     for item in items {
-        current_row = render_table_row(current_row, item, theme, cols);
+        current_row = render_table_row(current_row, item, theme, cols, show_path, show_time, separator);
     }
     current_row
 }
 
+/// Prints the column separator in the border color.
+///
+/// Only the foreground changes, so a selected row's background is preserved.
+fn render_separator(separator: &str, theme: &Theme) {
+    print!("{}", theme.fg(&theme.colors.border));
+    print!("{separator}");
+}
+
+/// Prints an expanded path across the whole row, replacing the NAME column.
+///
+/// Paths wider than the row keep their trailing characters behind a `...`
+/// prefix. Returns the number of columns printed.
+fn render_expanded_path(path: &str, width: usize) -> usize {
+    let path_len = path.chars().count();
+    if path_len <= width {
+        print!("{path}");
+        return path_len;
+    }
+
+    let keep = width.saturating_sub(3);
+    let tail: String = path.chars().skip(path_len - keep).collect();
+    let shown = format!("{}{tail}", &"..."[..width.min(3)]);
+    print!("{shown}");
+    width
+}
+
 /// Renders a single table row at the specified row position.
 ///
 /// Displays one project/session with:
-/// - NAME column (37 chars fixed width, left-aligned)
-/// - PATH column (remaining width, left-aligned)
+/// - NAME column ([`NAME_COLUMN_WIDTH`] chars fixed width, left-aligned; full width if PATH is hidden)
+/// - PATH column (remaining width, left-aligned; omitted if hidden)
+/// - VISITS and TIME columns (right-aligned at the end of the row; omitted if
+///   hidden)
+/// - `✓ ` marked, `* ` current session and `^ ` pinned indicators before the
+///   name
 /// - Selection highlighting (full row background)
 /// - Fuzzy match highlighting (character ranges)
+/// - Expanded selected path (whole row, replacing NAME, when it does not fit)
 ///
 /// # Parameters
 ///
@@ -78,6 +136,9 @@ pub fn render_table_rows(row: usize, items: &[DisplayItem], theme: &Theme, cols:
 /// * `item` - Display item to render
 /// * `theme` - Active color theme
 /// * `cols` - Terminal width in columns
+/// * `show_path` - Whether the PATH column is visible
+/// * `show_time` - Whether the VISITS and TIME columns are visible
+/// * `separator` - Column separator text (empty for plain spacing)
 ///
 /// # Returns
 ///
@@ -86,7 +147,7 @@ pub fn render_table_rows(row: usize, items: &[DisplayItem], theme: &Theme, cols:
 /// # Layout
 ///
 /// ```text
-/// NAME (up to 35 chars) [2 spaces] PATH (variable) [padding to fill line]
+/// NAME (up to 35 chars) [2 spaces] [separator] PATH (variable) [padding] VISITS TIME
 /// ```
 ///
 /// # Styling Precedence
@@ -97,26 +158,59 @@ pub fn render_table_rows(row: usize, items: &[DisplayItem], theme: &Theme, cols:
 ///
 /// The row is padded to fill the entire terminal width to ensure consistent
 /// selection background rendering.
-fn render_table_row(row: usize, item: &DisplayItem, theme: &Theme, cols: usize) -> usize {
+fn render_table_row(row: usize, item: &DisplayItem, theme: &Theme, cols: usize, show_path: bool, show_time: bool, separator: &str) -> usize {
     position_cursor(row, 1);
 
     if item.is_selected {
-        print!("{}", Theme::fg(&theme.colors.selection_fg));
-        print!("{}", Theme::bg(&theme.colors.selection_bg));
+        print!("{}", theme.fg(&theme.colors.selection_fg));
+        print!("{}", theme.bg(&theme.colors.selection_bg));
     } else {
-        print!("{}", Theme::fg(&theme.colors.text_normal));
+        print!("{}", theme.fg(&theme.colors.text_normal));
+    }
+
+    if item.is_marked {
+        print!("{}", theme.fg(&theme.colors.match_highlight_fg));
+        print!("✓ ");
+        if item.is_selected {
+            print!("{}", theme.fg(&theme.colors.selection_fg));
+        } else {
+            print!("{}", theme.fg(&theme.colors.text_normal));
+        }
     }
 
     if item.is_current_session {
-        print!("{}", Theme::fg(&theme.colors.active_session_fg));
+        print!("{}", theme.fg(&theme.colors.active_session_fg));
         print!("* ");
         if item.is_selected {
-            print!("{}", Theme::fg(&theme.colors.selection_fg));
+            print!("{}", theme.fg(&theme.colors.selection_fg));
+        } else {
+            print!("{}", theme.fg(&theme.colors.text_normal));
+        }
+    }
+
+    if item.is_pinned {
+        print!("{}", theme.fg(&theme.colors.header_fg));
+        print!("^ ");
+        if item.is_selected {
+            print!("{}", theme.fg(&theme.colors.selection_fg));
         } else {
-            print!("{}", Theme::fg(&theme.colors.text_normal));
+            print!("{}", theme.fg(&theme.colors.text_normal));
         }
     }
 
+    let indicator_len = [item.is_marked, item.is_current_session, item.is_pinned]
+        .into_iter()
+        .filter(|&shown| shown)
+        .count()
+        * 2;
+
+    if show_path && item.path_expanded && path_column(separator) + item.path.chars().count() > cols {
+        let line_len = render_expanded_path(&item.path, cols.saturating_sub(indicator_len)) + indicator_len;
+        print!("{}", " ".repeat(cols.saturating_sub(line_len)));
+        print!("{}", theme.reset());
+        return row + 1;
+    }
+
     if item.highlight_ranges.is_empty() {
         print!("{}", item.name);
     } else {
@@ -128,17 +222,37 @@ fn render_table_row(row: usize, item: &DisplayItem, theme: &Theme, cols: usize)
         );
     }
 
-    let indicator_len = if item.is_current_session { 2 } else { 0 };
-    let name_visual_len = item.name.len().min(35) + indicator_len;
-    print!("{}", " ".repeat(37_usize.saturating_sub(name_visual_len)));
+    let line_len = if show_path {
+        let name_visual_len = item.name.chars().count().min(NAME_COLUMN_WIDTH - 2) + indicator_len;
+        print!("{}", " ".repeat(NAME_COLUMN_WIDTH.saturating_sub(name_visual_len)));
+
+        if !separator.is_empty() {
+            render_separator(separator, theme);
+            if item.is_selected {
+                print!("{}", theme.fg(&theme.colors.selection_fg));
+            } else {
+                print!("{}", theme.fg(&theme.colors.text_normal));
+            }
+        }
 
-    print!("{}", item.path);
-    let path_len = item.path.len();
+        helpers::render_highlighted_text(&item.path, &item.path_highlight_ranges, theme, item.is_selected);
+        path_column(separator) + item.path.chars().count()
+    } else {
+        item.name.chars().count() + indicator_len
+    };
 
-    let line_len = 37 + path_len;
-    let padding = cols.saturating_sub(line_len);
+    let time_width = if show_time { VISITS_COLUMN_WIDTH + TIME_COLUMN_WIDTH } else { 0 };
+    let padding = cols.saturating_sub(line_len + time_width);
     print!("{}", " ".repeat(padding));
 
-    print!("{}", Theme::reset());
+    if show_time {
+        if !item.is_selected {
+            print!("{}", theme.fg(&theme.colors.text_dim));
+        }
+        print!("{:>width$}", item.access_count, width = VISITS_COLUMN_WIDTH);
+        print!("{:>width$}", item.time_ago, width = TIME_COLUMN_WIDTH);
+    }
+
+    print!("{}", theme.reset());
     row + 1
 }
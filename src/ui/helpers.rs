@@ -64,7 +64,8 @@ pub fn position_cursor(row: usize, col: usize) {
 /// # Character Indices
 ///
 /// Ranges use UTF-8 character indices (not byte indices). The function converts
-/// the text to a character vector for proper indexing.
+/// the text to a character vector for proper indexing. Ranges past the end of
+/// the text (e.g. after name truncation) are clamped.
 ///
 /// # Selection Behavior
 ///
@@ -103,20 +104,22 @@ pub fn render_highlighted_text(
     let mut current_pos = 0;
 
     for &(start, end) in ranges {
+        let start = start.min(chars.len());
+        let end = end.min(chars.len());
         if start > current_pos {
             let normal_section: String = chars[current_pos..start].iter().collect();
             print!("{normal_section}");
         }
 
-        print!("{}", Theme::fg(&theme.colors.match_highlight_fg));
-        print!("{}", Theme::bg(&theme.colors.match_highlight_bg));
-        let highlighted_section: String = chars[start..end.min(chars.len())].iter().collect();
+        print!("{}", theme.fg(&theme.colors.match_highlight_fg));
+        print!("{}", theme.bg(&theme.colors.match_highlight_bg));
+        let highlighted_section: String = chars[start..end].iter().collect();
         print!("{highlighted_section}");
-        print!("{}", Theme::reset());
+        print!("{}", theme.reset());
 
         if is_selected {
-            print!("{}", Theme::fg(&theme.colors.selection_fg));
-            print!("{}", Theme::bg(&theme.colors.selection_bg));
+            print!("{}", theme.fg(&theme.colors.selection_fg));
+            print!("{}", theme.bg(&theme.colors.selection_bg));
         }
 
         current_pos = end;
@@ -37,7 +37,7 @@ pub mod helpers;
 pub mod theme;
 
 pub use viewmodel::{
-    UIViewModel, DisplayItem, HeaderInfo, FooterInfo, EmptyState, SearchBarInfo,
+    UIViewModel, DisplayItem, HeaderInfo, FooterInfo, EmptyState, EmptyStateKind, SearchBarInfo,
 };
 pub use renderer::render;
-pub use theme::Theme;
+pub use theme::{ColorMode, Theme};
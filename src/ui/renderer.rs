@@ -41,7 +41,9 @@ use crate::ui::theme::Theme;
 /// # Output
 ///
 /// Prints ANSI-styled output to stdout using `print!` and `println!` macros.
-/// Does not clear the screen or manage cursor position.
+/// Does not clear the screen or manage cursor position. Prints a short "too
+/// small" notice instead if the dimensions cannot fit the UI (see
+/// `AppState::can_render`), and nothing at all for a zero-sized pane.
 ///
 /// # Example
 ///
@@ -53,6 +55,12 @@ use crate::ui::theme::Theme;
 /// render(&state, 24, 80);
 /// ```
 pub fn render(state: &AppState, rows: usize, cols: usize) {
+    if !state.can_render(rows, cols) {
+        tracing::trace!(rows = rows, cols = cols, "dimensions too small, rendering notice");
+        components::render_too_small(&state.theme, rows, cols);
+        return;
+    }
+
     let viewmodel = state.compute_viewmodel(rows, cols);
 
     render_viewmodel(&viewmodel, &state.theme, rows, cols);
@@ -61,10 +69,11 @@ pub fn render(state: &AppState, rows: usize, cols: usize) {
 /// Renders a view model with mode-specific layout.
 ///
 /// Chooses rendering strategy based on view model state:
-/// - Empty state: Centered message display
 /// - Search mode: Header, search bar, table, footer
 /// - Normal mode: Header, table, footer
 ///
+/// Both modes show the empty state message in place of the table rows.
+///
 /// # Parameters
 ///
 /// * `vm` - Pre-computed view model
@@ -72,11 +81,6 @@ pub fn render(state: &AppState, rows: usize, cols: usize) {
 /// * `rows` - Terminal height in rows
 /// * `cols` - Terminal width in columns
 fn render_viewmodel(vm: &UIViewModel, theme: &Theme, rows: usize, cols: usize) {
-    if let Some(empty) = &vm.empty_state {
-        components::render_empty_state(empty, theme, cols);
-        return;
-    }
-
     if let Some(search) = &vm.search_bar {
         components::render_search_mode(vm, search, theme, cols, rows);
     } else {
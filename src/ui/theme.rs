@@ -1,8 +1,12 @@
 //! Theme management and ANSI escape sequence generation.
 //!
 //! This module defines the color scheme system for the plugin, supporting both
-//! built-in themes (Catppuccin variants) and custom themes loaded from TOML files.
-//! It provides utilities for converting hex colors to ANSI escape sequences.
+//! built-in themes (Catppuccin, Gruvbox, Tokyo Night, Nord) and custom themes
+//! loaded from TOML files.
+//! It provides utilities for converting hex colors to ANSI escape sequences,
+//! downsampled to 256 or 16 colors for terminals without truecolor support
+//! (see [`ColorMode`]), or left out entirely when `NO_COLOR` is set (see
+//! [`no_color_requested`]).
 //!
 //! # Built-in Themes
 //!
@@ -10,6 +14,10 @@
 //! - `catppuccin-latte`: Light theme with soft pastels
 //! - `catppuccin-frappe`: Cool dark theme
 //! - `catppuccin-macchiato`: Warm dark theme
+//! - `gruvbox-dark`: Retro dark theme with earthy tones
+//! - `gruvbox-light`: Retro light theme with earthy tones
+//! - `tokyonight`: Dark theme with deep blues
+//! - `nord`: Arctic dark theme with muted blues
 //!
 //! # TOML Format
 //!
@@ -28,6 +36,23 @@
 //! match_highlight_bg = "#f9e2af"
 //! empty_state_fg = "#89b4fa"
 //! active_session_fg = "#f9e2af"
+//!
+//! # Optional, fall back to existing colors when omitted
+//! scrollbar_fg = "#6c7086"
+//! scrollbar_track = "#45475a"
+//! status_fg = "#6c7086"
+//! ```
+//!
+//! # Extending Built-in Themes
+//!
+//! A theme file may set `extends` to a built-in theme name and override only
+//! some colors. Unspecified colors (and `name`, if omitted) are inherited:
+//!
+//! ```toml
+//! extends = "catppuccin-mocha"
+//!
+//! [colors]
+//! selection_bg = "#a6e3a1"
 //! ```
 //!
 //! # Example
@@ -36,14 +61,158 @@
 //! use crate::ui::theme::Theme;
 //!
 //! let theme = Theme::from_name("catppuccin-mocha").unwrap();
-//! println!("{}", Theme::fg(&theme.colors.header_fg));
-//! println!("{}Bold Text{}", Theme::bold(), Theme::reset());
+//! println!("{}", theme.fg(&theme.colors.header_fg));
+//! println!("{}Bold Text{}", theme.bold(), theme.reset());
 //! ```
 
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
+/// Names of all built-in themes accepted by [`Theme::from_name`].
+pub const BUILTIN_THEMES: [&str; 8] = [
+    "catppuccin-mocha",
+    "catppuccin-latte",
+    "catppuccin-frappe",
+    "catppuccin-macchiato",
+    "gruvbox-dark",
+    "gruvbox-light",
+    "tokyonight",
+    "nord",
+];
+
+/// Color depth used for escape sequences.
+///
+/// Themes are written in 24-bit hex; lower modes map each color to the
+/// nearest palette entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// 24-bit `38;2;r;g;b` sequences (default).
+    #[default]
+    TrueColor,
+    /// xterm 256-color palette (`38;5;n`).
+    Ansi256,
+    /// Basic 16-color palette (`30`-`37`, `90`-`97`).
+    Ansi16,
+}
+
+impl ColorMode {
+    /// Parses a `color_mode` config value.
+    ///
+    /// Accepts `auto`, `truecolor`, and `24bit` (truecolor), `256`, and `16`.
+    /// Plugins cannot inspect the terminal, so `auto` assumes truecolor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::ui::theme::ColorMode;
+    ///
+    /// assert_eq!(ColorMode::parse("256"), Some(ColorMode::Ansi256));
+    /// assert_eq!(ColorMode::parse("auto"), Some(ColorMode::TrueColor));
+    /// assert_eq!(ColorMode::parse("8"), None);
+    /// ```
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "auto" | "truecolor" | "24bit" => Some(Self::TrueColor),
+            "256" | "ansi256" => Some(Self::Ansi256),
+            "16" | "ansi16" => Some(Self::Ansi16),
+            _ => None,
+        }
+    }
+}
+
+/// Standard xterm RGB values of the 16 basic colors, in code order.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel levels of the 6x6x6 color cube (palette indices 16-231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the nearest xterm 256-color palette index for an RGB color.
+///
+/// Considers the 6x6x6 color cube (16-231) and the grayscale ramp (232-255),
+/// picking whichever candidate is closer.
+///
+/// # Example
+///
+/// ```rust
+/// use zessionizer::ui::theme::rgb_to_ansi256;
+///
+/// assert_eq!(rgb_to_ansi256((0, 0, 0)), 16);
+/// assert_eq!(rgb_to_ansi256((255, 255, 255)), 231);
+/// assert_eq!(rgb_to_ansi256((255, 0, 0)), 196);
+/// assert_eq!(rgb_to_ansi256((0, 0, 255)), 21);
+/// assert_eq!(rgb_to_ansi256((128, 128, 128)), 244);
+/// assert_eq!(rgb_to_ansi256((0x89, 0xb4, 0xfa)), 111); // catppuccin blue
+/// assert_eq!(rgb_to_ansi256((0x1e, 0x1e, 0x2e)), 235); // catppuccin base
+/// ```
+#[must_use]
+pub fn rgb_to_ansi256((r, g, b): (u8, u8, u8)) -> u8 {
+    let cube_index = |channel: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| level.abs_diff(channel))
+            .map_or(0, |(index, _)| index)
+    };
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray_index = (average.saturating_sub(3) / 10).min(23);
+    let gray_level = u8::try_from(8 + gray_index * 10).unwrap_or(u8::MAX);
+
+    if distance((r, g, b), (gray_level, gray_level, gray_level)) < distance((r, g, b), cube) {
+        u8::try_from(232 + gray_index).unwrap_or(u8::MAX)
+    } else {
+        u8::try_from(16 + 36 * ri + 6 * gi + bi).unwrap_or(u8::MAX)
+    }
+}
+
+/// Returns the nearest basic color (0-15) for an RGB color.
+///
+/// # Example
+///
+/// ```rust
+/// use zessionizer::ui::theme::rgb_to_ansi16;
+///
+/// assert_eq!(rgb_to_ansi16((0, 0, 0)), 0);
+/// assert_eq!(rgb_to_ansi16((250, 10, 10)), 9);
+/// assert_eq!(rgb_to_ansi16((0x45, 0x47, 0x5a)), 8); // bright black
+/// ```
+#[must_use]
+pub fn rgb_to_ansi16(rgb: (u8, u8, u8)) -> u8 {
+    ANSI16_PALETTE
+        .iter()
+        .zip(0u8..)
+        .min_by_key(|(color, _)| distance(rgb, **color))
+        .map_or(0, |(_, index)| index)
+}
+
+/// Squared Euclidean distance between two RGB colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
 /// Color scheme configuration for UI rendering.
 ///
 /// Contains theme metadata and color definitions. Can be loaded from built-in
@@ -54,6 +223,12 @@ pub struct Theme {
     pub name: String,
     /// Color palette for all UI elements.
     pub colors: ThemeColors,
+    /// Color depth of generated escape sequences. Set from config, not TOML.
+    #[serde(skip)]
+    pub color_mode: ColorMode,
+    /// Emits no escape sequences at all. Set from `NO_COLOR`, not TOML.
+    #[serde(skip)]
+    pub no_color: bool,
 }
 
 /// Color definitions for all UI elements.
@@ -93,13 +268,71 @@ pub struct ThemeColors {
 
     /// Active session indicator color.
     pub active_session_fg: String,
+
+    /// Optional scrollbar thumb color. Falls back to `text_dim`.
+    #[serde(default)]
+    pub scrollbar_fg: Option<String>,
+    /// Optional scrollbar track color. Falls back to `border`.
+    #[serde(default)]
+    pub scrollbar_track: Option<String>,
+
+    /// Optional status indicator color. Falls back to `text_dim`.
+    #[serde(default)]
+    pub status_fg: Option<String>,
+}
+
+impl ThemeColors {
+    /// Returns the scrollbar thumb color, falling back to `text_dim`.
+    ///
+    /// # Example
+    ///
+    /// Themes may omit the scrollbar and status colors, which then fall back
+    /// to existing colors; colors that are set are used as given:
+    ///
+    /// ```rust
+    /// use zessionizer::Theme;
+    ///
+    /// let base = Theme::from_name("catppuccin-mocha").unwrap();
+    /// assert_eq!(base.colors.scrollbar_fg, None);
+    /// assert_eq!(base.colors.scrollbar_fg(), base.colors.text_dim);
+    /// assert_eq!(base.colors.scrollbar_track(), base.colors.border);
+    /// assert_eq!(base.colors.status_fg(), base.colors.text_dim);
+    ///
+    /// let theme = Theme::from_toml_str(r##"
+    ///     extends = "catppuccin-mocha"
+    ///     [colors]
+    ///     scrollbar_fg = "#111111"
+    ///     scrollbar_track = "#222222"
+    ///     status_fg = "#333333"
+    /// "##).unwrap();
+    /// assert_eq!(theme.colors.scrollbar_fg(), "#111111");
+    /// assert_eq!(theme.colors.scrollbar_track(), "#222222");
+    /// assert_eq!(theme.colors.status_fg(), "#333333");
+    /// ```
+    #[must_use]
+    pub fn scrollbar_fg(&self) -> &str {
+        self.scrollbar_fg.as_deref().unwrap_or(&self.text_dim)
+    }
+
+    /// Returns the scrollbar track color, falling back to `border`.
+    #[must_use]
+    pub fn scrollbar_track(&self) -> &str {
+        self.scrollbar_track.as_deref().unwrap_or(&self.border)
+    }
+
+    /// Returns the status indicator color, falling back to `text_dim`.
+    #[must_use]
+    pub fn status_fg(&self) -> &str {
+        self.status_fg.as_deref().unwrap_or(&self.text_dim)
+    }
 }
 
 impl Theme {
     /// Loads a built-in theme by name.
     ///
     /// Supported names: `catppuccin-mocha`, `catppuccin-latte`,
-    /// `catppuccin-frappe`, `catppuccin-macchiato`.
+    /// `catppuccin-frappe`, `catppuccin-macchiato`, `gruvbox-dark`,
+    /// `gruvbox-light`, `tokyonight`, `nord` (see [`BUILTIN_THEMES`]).
     ///
     /// # Returns
     ///
@@ -114,6 +347,26 @@ impl Theme {
     /// let theme = Theme::from_name("catppuccin-mocha").unwrap();
     /// assert_eq!(theme.name, "catppuccin-mocha");
     /// ```
+    ///
+    /// Every built-in theme parses with all required colors set:
+    ///
+    /// ```rust
+    /// use zessionizer::ui::theme::{Theme, BUILTIN_THEMES};
+    ///
+    /// for name in BUILTIN_THEMES {
+    ///     let theme = Theme::from_name(name).expect("built-in theme should parse");
+    ///     assert_eq!(theme.name, name);
+    ///
+    ///     let c = &theme.colors;
+    ///     for color in [
+    ///         &c.header_fg, &c.selection_fg, &c.selection_bg, &c.text_normal,
+    ///         &c.text_dim, &c.border, &c.search_bar_border, &c.match_highlight_fg,
+    ///         &c.match_highlight_bg, &c.empty_state_fg, &c.active_session_fg,
+    ///     ] {
+    ///         assert!(color.starts_with('#') && color.len() == 7, "{name}: {color}");
+    ///     }
+    /// }
+    /// ```
     #[must_use]
     pub fn from_name(name: &str) -> Option<Self> {
         let toml_str = match name {
@@ -121,6 +374,10 @@ impl Theme {
             "catppuccin-latte" => include_str!("../../themes/catppuccin-latte.toml"),
             "catppuccin-frappe" => include_str!("../../themes/catppuccin-frappe.toml"),
             "catppuccin-macchiato" => include_str!("../../themes/catppuccin-macchiato.toml"),
+            "gruvbox-dark" => include_str!("../../themes/gruvbox-dark.toml"),
+            "gruvbox-light" => include_str!("../../themes/gruvbox-light.toml"),
+            "tokyonight" => include_str!("../../themes/tokyonight.toml"),
+            "nord" => include_str!("../../themes/nord.toml"),
             _ => return None,
         };
 
@@ -129,6 +386,9 @@ impl Theme {
 
     /// Loads a theme from a TOML file.
     ///
+    /// If the file sets `extends`, its colors are merged onto that built-in
+    /// theme via [`Theme::from_toml_str`], so only overrides need to be given.
+    ///
     /// # Parameters
     ///
     /// * `path` - Path to the TOML file
@@ -138,20 +398,94 @@ impl Theme {
     /// Returns an error if:
     /// - The file cannot be read (file not found, permission denied, etc.)
     /// - The TOML content cannot be parsed (invalid syntax, missing fields, type mismatches)
+    /// - `extends` names an unknown built-in theme
     ///
     /// # Example
     ///
     /// ```rust
-    /// use crate::ui::theme::Theme;
+    /// use zessionizer::Theme;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let path = dir.path().join("theme.toml");
+    /// std::fs::write(&path, "extends = \"nord\"\n[colors]\nborder = \"#000000\"\n").unwrap();
     ///
-    /// let theme = Theme::from_file("/path/to/theme.toml")?;
+    /// let theme = Theme::from_file(&path)?;
+    /// assert_eq!(theme.colors.border, "#000000");
+    /// assert_eq!(theme.colors.text_normal, Theme::from_name("nord").unwrap().colors.text_normal);
+    /// assert!(Theme::from_file(dir.path().join("missing.toml")).is_err());
     /// # Ok::<(), String>(())
     /// ```
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
         let contents = fs::read_to_string(path)
             .map_err(|e| format!("Failed to read theme file: {e}"))?;
 
-        toml::from_str(&contents)
+        Self::from_toml_str(&contents)
+    }
+
+    /// Parses a theme from TOML, resolving `extends` against built-in themes.
+    ///
+    /// Without `extends`, every required color must be present. With it, the
+    /// file's `[colors]` entries override the base theme's colors key by key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is invalid, `extends` is not one of
+    /// [`BUILTIN_THEMES`], or the merged result is missing required colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::Theme;
+    ///
+    /// let theme = Theme::from_toml_str(r##"
+    ///     extends = "catppuccin-mocha"
+    ///     [colors]
+    ///     border = "#000000"
+    /// "##)?;
+    ///
+    /// let base = Theme::from_name("catppuccin-mocha").unwrap();
+    /// assert_eq!(theme.colors.border, "#000000");
+    /// assert_eq!(theme.colors.text_normal, base.colors.text_normal);
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn from_toml_str(contents: &str) -> Result<Self, String> {
+        let mut table: toml::Table = toml::from_str(contents)
+            .map_err(|e| format!("Failed to parse theme TOML: {e}"))?;
+
+        let Some(extends) = table.remove("extends") else {
+            return toml::Value::Table(table)
+                .try_into()
+                .map_err(|e| format!("Failed to parse theme TOML: {e}"));
+        };
+
+        let base_name = extends
+            .as_str()
+            .ok_or_else(|| "Theme `extends` must be a string".to_string())?;
+        let base = Self::from_name(base_name).ok_or_else(|| {
+            format!(
+                "Unknown base theme `{base_name}` (expected one of: {})",
+                BUILTIN_THEMES.join(", ")
+            )
+        })?;
+
+        let mut merged = toml::Table::try_from(base)
+            .map_err(|e| format!("Failed to serialize base theme: {e}"))?;
+
+        for (key, value) in table {
+            match (key.as_str(), value, merged.get_mut("colors")) {
+                ("colors", toml::Value::Table(overrides), Some(toml::Value::Table(colors))) => {
+                    colors.extend(overrides);
+                }
+                (_, value, _) => {
+                    merged.insert(key, value);
+                }
+            }
+        }
+
+        tracing::debug!(base = %base_name, "extended built-in theme");
+
+        toml::Value::Table(merged)
+            .try_into()
             .map_err(|e| format!("Failed to parse theme TOML: {e}"))
     }
 
@@ -181,9 +515,10 @@ impl Theme {
         (r, g, b)
     }
 
-    /// Generates an ANSI 24-bit foreground color escape sequence.
+    /// Generates an ANSI foreground color escape sequence.
     ///
-    /// Converts a hex color to RGB and formats as `\x1b[38;2;r;g;bm`.
+    /// Converts a hex color to RGB and formats it for the theme's
+    /// [`ColorMode`]: `\x1b[38;2;r;g;bm`, `\x1b[38;5;nm`, or `\x1b[3Xm`/`\x1b[9Xm`.
     ///
     /// # Parameters
     ///
@@ -198,18 +533,19 @@ impl Theme {
     /// ```rust
     /// use crate::ui::theme::Theme;
     ///
-    /// let fg = Theme::fg("#cdd6f4");
-    /// print!("{}Colored text{}", fg, Theme::reset());
+    /// let theme = Theme::default();
+    /// let fg = theme.fg("#cdd6f4");
+    /// print!("{}Colored text{}", fg, theme.reset());
     /// ```
     #[must_use]
-    pub fn fg(hex: &str) -> String {
-        let (r, g, b) = Self::hex_to_rgb(hex);
-        format!("\u{001b}[38;2;{r};{g};{b}m")
+    pub fn fg(&self, hex: &str) -> String {
+        self.color_sequence(hex, 38, 30)
     }
 
-    /// Generates an ANSI 24-bit background color escape sequence.
+    /// Generates an ANSI background color escape sequence.
     ///
-    /// Converts a hex color to RGB and formats as `\x1b[48;2;r;g;bm`.
+    /// Converts a hex color to RGB and formats it for the theme's
+    /// [`ColorMode`]: `\x1b[48;2;r;g;bm`, `\x1b[48;5;nm`, or `\x1b[4Xm`/`\x1b[10Xm`.
     ///
     /// # Parameters
     ///
@@ -224,57 +560,127 @@ impl Theme {
     /// ```rust
     /// use crate::ui::theme::Theme;
     ///
-    /// let bg = Theme::bg("#f5c2e7");
-    /// print!("{}Highlighted{}", bg, Theme::reset());
+    /// let theme = Theme::default();
+    /// let bg = theme.bg("#f5c2e7");
+    /// print!("{}Highlighted{}", bg, theme.reset());
     /// ```
     #[must_use]
-    pub fn bg(hex: &str) -> String {
-        let (r, g, b) = Self::hex_to_rgb(hex);
-        format!("\u{001b}[48;2;{r};{g};{b}m")
+    pub fn bg(&self, hex: &str) -> String {
+        self.color_sequence(hex, 48, 40)
+    }
+
+    /// Formats a color escape sequence for the theme's color mode.
+    ///
+    /// `extended` selects foreground (38) or background (48) for truecolor and
+    /// 256-color sequences; `basic` is the matching 16-color base code (30/40).
+    /// Bright colors use `basic + 60` (90-97/100-107). Empty when
+    /// [`Theme::no_color`] is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zessionizer::ui::theme::Theme;
+    ///
+    /// let mut theme = Theme::default();
+    /// assert_eq!(theme.fg("#cdd6f4"), "\u{1b}[38;2;205;214;244m");
+    /// assert_eq!(theme.bg("#000000"), "\u{1b}[48;2;0;0;0m");
+    /// assert_eq!(theme.bold(), "\u{1b}[1m");
+    ///
+    /// theme.no_color = true;
+    /// assert_eq!(theme.fg("#cdd6f4"), "");
+    /// assert_eq!(theme.bg("#000000"), "");
+    /// assert_eq!(theme.bold(), "");
+    /// assert_eq!(theme.dim(), "");
+    /// assert_eq!(theme.reset(), "");
+    /// ```
+    fn color_sequence(&self, hex: &str, extended: u8, basic: u8) -> String {
+        if self.no_color {
+            return String::new();
+        }
+
+        let rgb = Self::hex_to_rgb(hex);
+        match self.color_mode {
+            ColorMode::TrueColor => {
+                let (r, g, b) = rgb;
+                format!("\u{001b}[{extended};2;{r};{g};{b}m")
+            }
+            ColorMode::Ansi256 => format!("\u{001b}[{extended};5;{}m", rgb_to_ansi256(rgb)),
+            ColorMode::Ansi16 => {
+                let index = rgb_to_ansi16(rgb);
+                let code = if index < 8 { basic + index } else { basic + 60 + index - 8 };
+                format!("\u{001b}[{code}m")
+            }
+        }
     }
 
     /// Returns the ANSI bold escape sequence (`\x1b[1m`).
     ///
+    /// Empty when [`Theme::no_color`] is set.
+    ///
     /// # Example
     ///
     /// ```rust
     /// use crate::ui::theme::Theme;
     ///
-    /// print!("{}Bold text{}", Theme::bold(), Theme::reset());
+    /// let theme = Theme::default();
+    /// print!("{}Bold text{}", theme.bold(), theme.reset());
     /// ```
     #[must_use]
-    pub const fn bold() -> &'static str {
-        "\u{001b}[1m"
+    pub const fn bold(&self) -> &'static str {
+        self.sequence("\u{001b}[1m")
     }
 
     /// Returns the ANSI dim escape sequence (`\x1b[2m`).
     ///
+    /// Empty when [`Theme::no_color`] is set.
+    ///
     /// # Example
     ///
     /// ```rust
     /// use crate::ui::theme::Theme;
     ///
-    /// print!("{}Dimmed text{}", Theme::dim(), Theme::reset());
+    /// let theme = Theme::default();
+    /// print!("{}Dimmed text{}", theme.dim(), theme.reset());
     /// ```
     #[must_use]
-    pub const fn dim() -> &'static str {
-        "\u{001b}[2m"
+    pub const fn dim(&self) -> &'static str {
+        self.sequence("\u{001b}[2m")
+    }
+
+    /// Returns the ANSI reverse video escape sequence (`\x1b[7m`).
+    ///
+    /// Used to draw the search bar cursor. Empty when [`Theme::no_color`] is
+    /// set.
+    #[must_use]
+    pub const fn reverse(&self) -> &'static str {
+        self.sequence("\u{001b}[7m")
     }
 
     /// Returns the ANSI reset escape sequence (`\x1b[0m`).
     ///
-    /// Clears all styling (colors, bold, dim, etc.).
+    /// Clears all styling (colors, bold, dim, etc.). Empty when
+    /// [`Theme::no_color`] is set, since nothing was styled.
     ///
     /// # Example
     ///
     /// ```rust
     /// use crate::ui::theme::Theme;
     ///
-    /// print!("{}Styled{} Normal", Theme::bold(), Theme::reset());
+    /// let theme = Theme::default();
+    /// print!("{}Styled{} Normal", theme.bold(), theme.reset());
     /// ```
     #[must_use]
-    pub const fn reset() -> &'static str {
-        "\u{001b}[0m"
+    pub const fn reset(&self) -> &'static str {
+        self.sequence("\u{001b}[0m")
+    }
+
+    /// Returns `sequence`, or an empty string when color is disabled.
+    const fn sequence(&self, sequence: &'static str) -> &'static str {
+        if self.no_color {
+            ""
+        } else {
+            sequence
+        }
     }
 }
 
@@ -298,3 +704,43 @@ impl Default for Theme {
             .expect("Built-in catppuccin-mocha theme should always parse")
     }
 }
+
+/// Returns whether the `NO_COLOR` environment variable asks for plain output.
+///
+/// Following <https://no-color.org>, any non-empty value disables color.
+#[must_use]
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Returns whether any changed path refers to the theme file.
+///
+/// Filesystem events may report paths under `/host` or relative to the
+/// plugin's working directory, and `theme_file` may use either form, so both
+/// sides are compared relative to the working directory.
+///
+/// # Example
+///
+/// ```rust
+/// use zessionizer::ui::theme::theme_file_changed;
+/// use std::path::PathBuf;
+///
+/// let changed = vec![PathBuf::from("/host/.config/zessionizer/theme.toml")];
+/// assert!(theme_file_changed("/host/.config/zessionizer/theme.toml", &changed));
+/// assert!(theme_file_changed(".config/zessionizer/theme.toml", &changed));
+/// assert!(!theme_file_changed("/host/.config/zessionizer/other.toml", &changed));
+/// assert!(!theme_file_changed("/host/.config/zessionizer/theme.toml", &[]));
+/// ```
+#[must_use]
+pub fn theme_file_changed(theme_file: &str, changed: &[std::path::PathBuf]) -> bool {
+    let theme_file = host_relative(Path::new(theme_file));
+    changed.iter().any(|path| host_relative(path) == theme_file)
+}
+
+/// Strips the `/host` or `.` prefix so equivalent paths compare equal.
+fn host_relative(path: &Path) -> &Path {
+    ["/host", "."]
+        .iter()
+        .find_map(|prefix| path.strip_prefix(prefix).ok())
+        .unwrap_or(path)
+}
@@ -20,16 +20,59 @@
 //!         name: "my-project".to_string(),
 //!         path: "/home/user/code/my-project".to_string(),
 //!         is_selected: true,
+//!         is_current_session: false,
+//!         is_pinned: false,
+//!         is_marked: false,
 //!         highlight_ranges: vec![(0, 2)],
+//!         path_highlight_ranges: vec![],
+//!         path_expanded: false,
+//!         time_ago: "5m ago".to_string(),
+//!         access_count: 3,
 //!     }],
 //!     selected_index: 0,
 //!     header: HeaderInfo { title: "Zessionizer".to_string() },
-//!     footer: FooterInfo { keybindings: "q: quit".to_string() },
+//!     footer: FooterInfo { keybindings: "q: quit".to_string(), counts: None },
 //!     empty_state: None,
 //!     search_bar: None,
+//!     show_path: true,
+//!     show_time: true,
+//!     column_separator: String::new(),
+//!     scroll_info: None,
+//!     layout_density: Default::default(),
 //! };
 //! ```
 
+/// Width of the left-aligned NAME column when the PATH column is shown.
+pub const NAME_COLUMN_WIDTH: usize = 37;
+
+/// Returns the zero-based column where row paths start.
+///
+/// The NAME column is padded to [`NAME_COLUMN_WIDTH`] and `separator` is drawn
+/// right after it, so the separator occupies the columns just before the path.
+///
+/// # Examples
+///
+/// ```
+/// use zessionizer::ui::viewmodel::{path_column, NAME_COLUMN_WIDTH};
+///
+/// assert_eq!(path_column(""), NAME_COLUMN_WIDTH);
+/// assert_eq!(path_column(" │ "), NAME_COLUMN_WIDTH + 3);
+/// ```
+#[must_use]
+pub fn path_column(separator: &str) -> usize {
+    NAME_COLUMN_WIDTH + separator.chars().count()
+}
+
+/// Width of the right-aligned TIME column, including its leading gap.
+pub const TIME_COLUMN_WIDTH: usize = 10;
+
+/// Width of the right-aligned VISITS column, including its leading gap.
+pub const VISITS_COLUMN_WIDTH: usize = 8;
+
+/// Narrowest terminal width, in columns, that still shows the VISITS and TIME
+/// columns.
+pub const TIME_COLUMN_MIN_COLS: usize = 60;
+
 /// Complete UI view model for rendering.
 ///
 /// Contains all display information needed to render the plugin UI. The view
@@ -54,6 +97,25 @@ pub struct UIViewModel {
 
     /// Optional search bar information (when in search mode).
     pub search_bar: Option<SearchBarInfo>,
+
+    /// Whether the PATH column is rendered.
+    ///
+    /// When `false`, NAME uses the full row width.
+    pub show_path: bool,
+
+    /// Whether the VISITS and TIME columns are rendered.
+    ///
+    /// Dropped on terminals narrower than [`TIME_COLUMN_MIN_COLS`].
+    pub show_time: bool,
+
+    /// Text drawn between the NAME and PATH columns (may be empty).
+    pub column_separator: String,
+
+    /// Hints for items scrolled out of view, `None` if all items are visible.
+    pub scroll_info: Option<ScrollInfo>,
+
+    /// Whether the blank top line and list borders are drawn.
+    pub layout_density: crate::app::LayoutDensity,
 }
 
 /// Display information for a single project or session item.
@@ -61,6 +123,7 @@ pub struct UIViewModel {
 /// Represents one row in the table view. Contains pre-computed highlight ranges
 /// for fuzzy match rendering.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct DisplayItem {
     /// Display name (project name or session name).
     pub name: String,
@@ -74,10 +137,34 @@ pub struct DisplayItem {
     /// Whether this is the current active session.
     pub is_current_session: bool,
 
+    /// Whether the project is pinned.
+    pub is_pinned: bool,
+
+    /// Whether the session is marked for a bulk kill.
+    pub is_marked: bool,
+
     /// Character ranges to highlight (for fuzzy search matches).
     ///
     /// Each tuple is `(start_index, end_index)` in UTF-8 character indices.
     pub highlight_ranges: Vec<(usize, usize)>,
+
+    /// Character ranges of `path` to highlight, when the search scope
+    /// includes paths.
+    ///
+    /// Indices refer to `path` as displayed, after any truncation.
+    pub path_highlight_ranges: Vec<(usize, usize)>,
+
+    /// Whether `path` is the full, untruncated path.
+    ///
+    /// Set for the selected item while path expansion is on. The renderer
+    /// lets an expanded path use the whole row when it does not fit.
+    pub path_expanded: bool,
+
+    /// How long ago the project was last accessed (e.g. `"3h ago"`).
+    pub time_ago: String,
+
+    /// Number of times the project has been opened.
+    pub access_count: i32,
 }
 
 /// Header display information.
@@ -96,6 +183,71 @@ pub struct HeaderInfo {
 pub struct FooterInfo {
     /// Keybinding help text (e.g., "q: quit | /: search | n: projects").
     pub keybindings: String,
+
+    /// Visible/total project counts (e.g., `"[12/340]"`), if enabled.
+    ///
+    /// Rendered after the keybindings and kept visible when they are truncated.
+    pub counts: Option<String>,
+}
+
+/// Hints for rows outside the visible window of the table.
+///
+/// The `above` hint is drawn at the right of the table header row, the `below`
+/// hint on the border under the table.
+///
+/// # Example
+///
+/// ```rust
+/// use zessionizer::ui::viewmodel::ScrollInfo;
+///
+/// let info = ScrollInfo::new(0, 5, 12).unwrap();
+/// assert_eq!(info.above, None);
+/// assert_eq!(info.below.as_deref(), Some("↓ 7 more"));
+///
+/// let info = ScrollInfo::new(3, 8, 8).unwrap();
+/// assert_eq!(info.above.as_deref(), Some("↑ 3 more"));
+/// assert_eq!(info.below, None);
+///
+/// assert!(ScrollInfo::new(0, 8, 8).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrollInfo {
+    /// Hint for items above the window (e.g. `"↑ 3 more"`), if any.
+    pub above: Option<String>,
+
+    /// Hint for items below the window (e.g. `"↓ 7 more"`), if any.
+    pub below: Option<String>,
+}
+
+impl ScrollInfo {
+    /// Builds the hints for a window `visible_start..visible_end` of `total` items.
+    ///
+    /// Returns `None` if the window shows every item.
+    #[must_use]
+    pub fn new(visible_start: usize, visible_end: usize, total: usize) -> Option<Self> {
+        let hidden_below = total.saturating_sub(visible_end);
+        if visible_start == 0 && hidden_below == 0 {
+            return None;
+        }
+
+        Some(Self {
+            above: (visible_start > 0).then(|| format!("↑ {visible_start} more")),
+            below: (hidden_below > 0).then(|| format!("↓ {hidden_below} more")),
+        })
+    }
+}
+
+/// Why the project list is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyStateKind {
+    /// No projects are stored at all; a scan has to find some first.
+    NoProjects,
+
+    /// Projects exist, but none belong in the current view mode.
+    NoViewMatches,
+
+    /// Projects exist, but the search query filtered all of them out.
+    NoSearchMatches,
 }
 
 /// Empty state message display information.
@@ -103,6 +255,9 @@ pub struct FooterInfo {
 /// Shown when no items are available (e.g., no projects found, no sessions).
 #[derive(Debug, Clone)]
 pub struct EmptyState {
+    /// Why the list is empty, which decides the suggested action.
+    pub kind: EmptyStateKind,
+
     /// Primary message (e.g., "No projects found").
     pub message: String,
 
@@ -112,9 +267,17 @@ pub struct EmptyState {
 
 /// Search bar display information.
 ///
-/// Contains the current search query for rendering the search input box.
+/// Contains the current search query for rendering the search input box. The
+/// same box is used for renaming a session.
 #[derive(Debug, Clone)]
 pub struct SearchBarInfo {
+    /// Label shown before the text (e.g. `"Search"`).
+    pub label: String,
+
     /// Current search query text.
     pub query: String,
+
+    /// Cursor position in `query` as a character index, `None` when the
+    /// input is not focused.
+    pub cursor: Option<usize>,
 }
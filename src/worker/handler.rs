@@ -3,18 +3,43 @@
 //! This module implements the Zellij worker thread interface, handling all storage
 //! operations asynchronously to avoid blocking the main plugin rendering loop. It
 //! includes distributed tracing support for cross-thread observability.
+//!
+//! # Operation Log
+//!
+//! When enabled via [`WorkerSettings::operation_log`], every handled message is
+//! appended as one JSON line to `zessionizer-worker-ops.json` in the data
+//! directory, using the same rotating [`FileWriter`] as trace export:
+//!
+//! ```json
+//! {"ts":1700000000,"op":"UpdateFrecency","path":"/code/api","result":"ok"}
+//! ```
+//!
+//! Each line has the same four fields; failed operations record the error
+//! message as their result.
 
 use crate::domain::error::{Result, ZessionizerError};
+use crate::domain::project::content_hash;
 use crate::domain::Project;
-use crate::infrastructure::paths;
-use crate::storage::backend::Storage;
+use crate::infrastructure::ignore::{IgnoreList, IGNORE_FILE};
+use crate::infrastructure::{import, marker};
+use crate::observability::FileWriter;
+use crate::storage::backend::{with_batch, Storage};
 use crate::storage::models::ProjectRecord;
-use crate::storage::{sort_by_frecency, JsonStorage};
-use crate::worker::{WorkerMessage, WorkerResponse};
+#[cfg(feature = "sqlite")]
+use crate::storage::SqliteStorage;
+use crate::storage::ui_state::UI_STATE_FILE;
+use crate::storage::{sort_by_frecency, JsonStorage, ProjectSort, ProjectsExport, StorageBackendKind, UiState};
+use crate::worker::{WorkerMessage, WorkerResponse, WorkerSettings};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
 use zellij_tile::prelude::{PluginMessage, ZellijWorker};
 use zellij_tile::shim::post_message_to_plugin;
 
+/// Mount point of the host filesystem, against which relative project paths
+/// are resolved when reading marker files.
+const HOST_ROOT: &str = "/host";
+
 /// Worker thread state for handling storage operations.
 ///
 /// This struct runs on a separate thread spawned by Zellij and processes
@@ -25,20 +50,71 @@ pub struct ZessionizerWorker {
     /// Storage backend, initialized lazily on first use.
     #[serde(skip)]
     storage: Option<Box<dyn Storage>>,
+
+    /// Settings received from the plugin via `Configure`.
+    #[serde(skip)]
+    settings: WorkerSettings,
+
+    /// Append-only operation log, open when `settings.operation_log` is set.
+    #[serde(skip)]
+    operation_log: Option<FileWriter>,
+
+    /// Hash of the project list in the most recent project response.
+    #[serde(skip)]
+    last_projects_hash: Option<u64>,
+
+    /// Responses posted after the main response of the current message.
+    #[serde(skip)]
+    follow_ups: Vec<WorkerResponse>,
 }
 
 impl ZessionizerWorker {
     /// Creates a new worker with an initialized storage backend.
     ///
-    /// Uses JSON file storage for persisting project and session data.
+    /// Uses JSON file storage until `Configure` selects the configured backend.
     ///
     /// # Errors
     ///
     /// Returns an error if the storage backend cannot be initialized.
     pub fn new(_backend_param: String) -> Result<Self> {
-        let path = paths::get_data_dir().join("projects.json");
-        let storage: Box<dyn Storage> = Box::new(JsonStorage::new(path)?);
-        Ok(Self { storage: Some(storage) })
+        let settings = WorkerSettings::default();
+        Ok(Self {
+            storage: Some(Self::open_storage(&settings)?),
+            settings,
+            ..Self::default()
+        })
+    }
+
+    /// Opens the storage backend according to the worker settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend cannot be initialized.
+    fn open_storage(settings: &WorkerSettings) -> Result<Box<dyn Storage>> {
+        match settings.storage_backend {
+            #[cfg(feature = "sqlite")]
+            StorageBackendKind::Sqlite => {
+                let path = settings.data_dir.join("projects.sqlite3");
+                Ok(Box::new(SqliteStorage::new(&path)?))
+            }
+            #[cfg(not(feature = "sqlite"))]
+            StorageBackendKind::Sqlite => {
+                tracing::warn!("sqlite storage requested but not compiled in - using JSON");
+                Self::open_json_storage(settings)
+            }
+            StorageBackendKind::Json => Self::open_json_storage(settings),
+        }
+    }
+
+    /// Opens the JSON storage file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage file cannot be opened.
+    fn open_json_storage(settings: &WorkerSettings) -> Result<Box<dyn Storage>> {
+        let path = settings.data_dir.join("projects.json");
+        let storage = JsonStorage::new(path)?.with_pretty(settings.storage_pretty);
+        Ok(Box::new(storage))
     }
 
     /// Returns a mutable reference to the storage backend, failing if not initialized.
@@ -52,17 +128,23 @@ impl ZessionizerWorker {
             .ok_or_else(|| ZessionizerError::Worker("Storage not initialized".to_string()))
     }
 
-    /// Converts a storage-layer `ProjectRecord` to a domain `Project`.
-    ///
-    /// This transformation is necessary because the worker returns domain types
-    /// to the main thread, not storage types.
-    fn project_record_to_project(record: ProjectRecord) -> Project {
-        Project {
-            id: None,
-            path: record.path,
-            name: record.name,
-            last_accessed: record.last_accessed.unwrap_or(record.created_at),
-            created_at: record.created_at,
+    /// Builds a project list response, honoring hash-only mode.
+    ///
+    /// In hash-only mode (unless `force_full`), returns `ProjectsHash`;
+    /// otherwise returns the response built by `full`.
+    fn projects_response<F>(&mut self, projects: Vec<Project>, force_full: bool, full: F) -> WorkerResponse
+    where
+        F: FnOnce(Vec<Project>) -> WorkerResponse,
+    {
+        let hash = content_hash(&projects);
+        let changed = self.last_projects_hash != Some(hash);
+        self.last_projects_hash = Some(hash);
+
+        if self.settings.hash_only_responses && !force_full {
+            tracing::debug!(hash = hash, changed = changed, "sending project hash only");
+            WorkerResponse::ProjectsHash { hash, changed }
+        } else {
+            full(projects)
         }
     }
 
@@ -88,15 +170,42 @@ impl ZessionizerWorker {
         }
     }
 
-    /// Handles the `LoadProjects` message.
+    /// Handles the `Configure` message.
+    ///
+    /// Stores the settings, reopens storage with them, and opens or closes the
+    /// operation log accordingly.
+    fn handle_configure(&mut self, settings: WorkerSettings) -> WorkerResponse {
+        self.operation_log = settings.operation_log.then(|| {
+            FileWriter::new(settings.data_dir.join("zessionizer-worker-ops.json"), settings.log_rotation)
+        });
+
+        match Self::open_storage(&settings) {
+            Ok(storage) => self.storage = Some(storage),
+            Err(e) => {
+                tracing::debug!(error = %e, "failed to reopen storage with new settings");
+                return WorkerResponse::Error {
+                    message: format!("configure: {e}"),
+                };
+            }
+        }
+
+        tracing::debug!(settings = ?settings, "worker configured");
+        self.settings = settings;
+        WorkerResponse::Configured
+    }
+
+    /// Handles the `LoadProjects` and `FetchProjects` messages.
     ///
-    /// Retrieves all projects from storage, sorted by frecency.
-    fn handle_load_projects(&mut self, _with_sessions: bool) -> WorkerResponse {
+    /// Retrieves all projects from storage, sorted by frecency. `force_full`
+    /// bypasses hash-only mode.
+    fn handle_load_projects(&mut self, _with_sessions: bool, force_full: bool) -> WorkerResponse {
+        let half_life = self.settings.frecency_half_life_hours;
+
         Self::handle_db_result(
             "load projects",
             self.get_storage().and_then(|storage| storage.get_all_projects()),
             |mut records| {
-                sort_by_frecency(&mut records);
+                sort_by_frecency(&mut records, half_life);
 
                 tracing::debug!(
                     project_count = records.len(),
@@ -104,9 +213,40 @@ impl ZessionizerWorker {
                 );
                 let projects = records
                     .into_iter()
-                    .map(Self::project_record_to_project)
+                    .map(Project::from)
                     .collect();
-                WorkerResponse::ProjectsLoaded { projects }
+                self.projects_response(projects, force_full, |projects| {
+                    WorkerResponse::ProjectsLoaded { projects }
+                })
+            },
+        )
+    }
+
+    /// Handles the `LoadProjectsPage` message.
+    ///
+    /// Retrieves `limit` projects from `offset` in frecency order, without
+    /// cloning the rest of the list.
+    fn handle_load_projects_page(&mut self, offset: usize, limit: usize) -> WorkerResponse {
+        let sort = ProjectSort::Frecency {
+            half_life_hours: self.settings.frecency_half_life_hours,
+        };
+
+        Self::handle_db_result(
+            "load projects page",
+            self.get_storage()
+                .and_then(|storage| storage.get_projects_paginated(offset, limit, sort)),
+            |page| {
+                tracing::debug!(
+                    offset = offset,
+                    project_count = page.projects.len(),
+                    total = page.total,
+                    "project page loaded from storage"
+                );
+                WorkerResponse::ProjectsPage {
+                    offset,
+                    total: page.total,
+                    projects: page.projects.into_iter().map(Project::from).collect(),
+                }
             },
         )
     }
@@ -128,50 +268,210 @@ impl ZessionizerWorker {
         )
     }
 
+    /// Handles the `ResetFrecency` message.
+    ///
+    /// Resets one project's frecency data, or every project's, then returns
+    /// all projects sorted by frecency so the list reloads in its new order.
+    fn handle_reset_frecency(&mut self, path: Option<&str>) -> WorkerResponse {
+        let half_life = self.settings.frecency_half_life_hours;
+
+        Self::handle_db_result(
+            "reset frecency",
+            self.get_storage().and_then(|storage| {
+                with_batch(storage.as_mut(), |storage| {
+                    storage.reset_frecency(path)?;
+                    storage.get_all_projects()
+                })
+            }),
+            |mut records| {
+                sort_by_frecency(&mut records, half_life);
+
+                tracing::debug!(project_path = ?path, "frecency reset");
+                let projects = records
+                    .into_iter()
+                    .map(Project::from)
+                    .collect();
+                self.projects_response(projects, false, |projects| {
+                    WorkerResponse::ProjectsLoaded { projects }
+                })
+            },
+        )
+    }
+
+    /// Handles the `DeleteProject` message.
+    ///
+    /// Removes the project and its session links from storage.
+    fn handle_delete_project(&mut self, path: String) -> WorkerResponse {
+        Self::handle_db_result(
+            "delete project",
+            self.get_storage().and_then(|storage| storage.remove_project(&path)),
+            |()| {
+                tracing::debug!(project_path = %path, "project deleted");
+                WorkerResponse::ProjectDeleted { path }
+            },
+        )
+    }
+
+    /// Handles the `TogglePin` message.
+    ///
+    /// Flips the project's pinned state in one storage batch, then returns all
+    /// projects sorted by frecency so the project moves into its new group.
+    fn handle_toggle_pin(&mut self, path: &str) -> WorkerResponse {
+        let half_life = self.settings.frecency_half_life_hours;
+
+        Self::handle_db_result(
+            "toggle pin",
+            self.get_storage().and_then(|storage| {
+                with_batch(storage.as_mut(), |storage| {
+                    let project = storage
+                        .get_project_by_path(path)?
+                        .ok_or_else(|| ZessionizerError::Storage(format!("project not found: {path}")))?;
+                    storage.set_project_pinned(path, !project.pinned)?;
+                    storage.get_all_projects()
+                })
+            }),
+            |mut records| {
+                sort_by_frecency(&mut records, half_life);
+
+                tracing::debug!(project_path = %path, "project pin toggled");
+                let projects = records
+                    .into_iter()
+                    .map(Project::from)
+                    .collect();
+                self.projects_response(projects, false, |projects| {
+                    WorkerResponse::ProjectsLoaded { projects }
+                })
+            },
+        )
+    }
+
+    /// Handles the `UpdateProjectLayout` message.
+    ///
+    /// Stores the layout used when creating the project's session.
+    fn handle_update_project_layout(&mut self, path: String, layout: Option<String>) -> WorkerResponse {
+        Self::handle_db_result(
+            "update project layout",
+            self.get_storage()
+                .and_then(|storage| storage.set_project_layout(&path, layout.as_deref())),
+            |()| {
+                tracing::debug!(project_path = %path, layout = ?layout, "project layout updated");
+                WorkerResponse::ProjectLayoutUpdated { path, layout }
+            },
+        )
+    }
+
+    /// Handles the `RenameProject` message.
+    ///
+    /// Renames the project and its session link in storage.
+    fn handle_rename_project(&mut self, path: String, name: String) -> WorkerResponse {
+        Self::handle_db_result(
+            "rename project",
+            self.get_storage().and_then(|storage| storage.rename_project(&path, &name)),
+            |()| {
+                tracing::debug!(project_path = %path, new_name = %name, "project renamed");
+                WorkerResponse::ProjectRenamed { path, name }
+            },
+        )
+    }
+
     /// Handles the `AddProjectsBatch` message.
     ///
-    /// Adds or updates multiple projects in a single transaction, then returns
-    /// all projects sorted by frecency.
+    /// Adds or updates multiple projects in a single transaction (one storage
+    /// batch), then returns all projects sorted by frecency. Scoped rescans
+    /// send only the projects under the changed directory, so the response
+    /// carries the full list rather than the batch, which would otherwise
+    /// replace the plugin's list. Each project's tags are read from its
+    /// `.zessionizer` marker file, if it has one. Projects matching the global
+    /// ignore file ([`IGNORE_FILE`]) are left out.
     fn handle_add_projects_batch(&mut self, projects: Vec<(String, String)>) -> WorkerResponse {
         let now = chrono::Utc::now().timestamp();
+        let ignore = IgnoreList::load(Path::new(IGNORE_FILE));
+        let scanned = projects.len();
         let records: Vec<ProjectRecord> = projects
             .into_iter()
+            .filter(|(path, _)| !ignore.is_ignored(path))
             .map(|(path, name)| ProjectRecord {
+                tags: marker::read_tags(Path::new(HOST_ROOT), &path),
                 path,
                 name,
                 last_accessed: Some(now),
                 created_at: now,
                 access_count: 1,
+                pinned: false,
+                layout: None,
             })
             .collect();
 
         let count = records.len();
+        if count < scanned {
+            tracing::debug!(ignored_count = scanned - count, "ignored projects left out of batch");
+        }
+        let half_life = self.settings.frecency_half_life_hours;
 
         Self::handle_db_result(
             "add projects batch",
-            self.get_storage().and_then(|storage| storage.add_projects_batch(&records)),
-            |mut project_records| {
-                sort_by_frecency(&mut project_records);
-
-                tracing::debug!(project_count = count, "projects batch added to storage");
+            self.get_storage().and_then(|storage| {
+                with_batch(storage.as_mut(), |storage| {
+                    let entries = storage.add_projects_batch(&records)?;
+                    Ok((entries, storage.get_all_projects()?))
+                })
+            }),
+            |(entries, mut project_records)| {
+                sort_by_frecency(&mut project_records, half_life);
+
+                let added = entries.iter().filter(|entry| entry.inserted).count();
+                self.follow_ups.push(WorkerResponse::ScanSummary {
+                    discovered: count,
+                    added,
+                    updated: entries.len() - added,
+                });
+
+                tracing::debug!(project_count = count, added = added, "projects batch added to storage");
                 let projects = project_records
                     .into_iter()
-                    .map(Self::project_record_to_project)
+                    .map(Project::from)
                     .collect();
-                WorkerResponse::ProjectsBatchAdded { count, projects }
+                self.projects_response(projects, false, |projects| {
+                    WorkerResponse::ProjectsBatchAdded { count, projects }
+                })
             },
         )
     }
 
+    /// Handles the `ImportProjects` message.
+    ///
+    /// Derives each project's name from its path (see
+    /// [`import_project`](crate::infrastructure::import::import_project)) and
+    /// stores the projects as one batch, like a scan.
+    fn handle_import_projects(&mut self, paths: &[String]) -> WorkerResponse {
+        let projects = import::projects_from_import(paths);
+        tracing::debug!(listed = paths.len(), project_count = projects.len(), "importing projects");
+        self.handle_add_projects_batch(projects)
+    }
+
+    /// Takes the responses queued by the last message besides its main response.
+    ///
+    /// `AddProjectsBatch` queues a `ScanSummary` with its new and updated
+    /// counts. The Zellij entry point posts these right after the main response.
+    pub fn take_follow_ups(&mut self) -> Vec<WorkerResponse> {
+        std::mem::take(&mut self.follow_ups)
+    }
+
     /// Handles the `SyncSessions` message.
     ///
-    /// Synchronizes the sessions table with the list of active Zellij sessions.
-    fn handle_sync_sessions(&mut self, active_sessions: &[String]) -> WorkerResponse {
+    /// Synchronizes the sessions table with the list of active Zellij sessions,
+    /// using their working directories to pick the matching projects.
+    fn handle_sync_sessions(
+        &mut self,
+        active_sessions: &[String],
+        session_cwds: &HashMap<String, String>,
+    ) -> WorkerResponse {
         let count = active_sessions.len();
 
         Self::handle_db_result(
             "sync sessions",
-            self.get_storage().and_then(|storage| storage.sync_sessions(active_sessions)),
+            self.get_storage()
+                .and_then(|storage| storage.sync_sessions(active_sessions, session_cwds)),
             |()| {
                 tracing::debug!(session_count = count, "sessions synced successfully");
                 WorkerResponse::SessionsSynced { count }
@@ -179,6 +479,102 @@ impl ZessionizerWorker {
         )
     }
 
+    /// Handles the `LoadSessions` message.
+    ///
+    /// Returns the session names persisted by the last sync.
+    fn handle_load_sessions(&mut self) -> WorkerResponse {
+        Self::handle_db_result(
+            "load sessions",
+            self.get_storage().and_then(|storage| storage.get_all_sessions()),
+            |sessions| {
+                tracing::debug!(session_count = sessions.len(), "stored sessions loaded");
+                WorkerResponse::SessionsLoaded {
+                    names: sessions.into_iter().map(|s| s.name).collect(),
+                }
+            },
+        )
+    }
+
+    /// Handles the `ExportProjects` message.
+    ///
+    /// Serializes every stored project in frecency order, scored with the
+    /// configured half-life.
+    fn handle_export_projects(&mut self) -> WorkerResponse {
+        let half_life = self.settings.frecency_half_life_hours;
+
+        Self::handle_db_result(
+            "export projects",
+            self.get_storage().and_then(|storage| storage.get_all_projects()),
+            |mut records| {
+                sort_by_frecency(&mut records, half_life);
+                let projects: Vec<Project> = records.into_iter().map(Project::from).collect();
+                let export = ProjectsExport::from_projects(&projects, chrono::Utc::now().timestamp(), half_life);
+
+                tracing::debug!(project_count = projects.len(), "projects exported");
+                WorkerResponse::ProjectsExported { json: export.to_json() }
+            },
+        )
+    }
+
+    /// Handles the `LoadSelection` message.
+    ///
+    /// Returns the project path selected when the plugin was last closed.
+    fn handle_load_selection(&mut self) -> WorkerResponse {
+        Self::handle_db_result(
+            "load selection",
+            self.get_storage().and_then(|storage| storage.get_last_selected()),
+            |path| {
+                tracing::debug!(path = ?path, "last selection loaded");
+                WorkerResponse::SelectionLoaded { path }
+            },
+        )
+    }
+
+    /// Handles the `SaveSelection` message.
+    ///
+    /// Persists the selected project path for restoring on the next load.
+    fn handle_save_selection(&mut self, path: String) -> WorkerResponse {
+        Self::handle_db_result(
+            "save selection",
+            self.get_storage().and_then(|storage| storage.set_last_selected(&path)),
+            |()| {
+                tracing::debug!(path = %path, "selection saved");
+                WorkerResponse::SelectionSaved { path }
+            },
+        )
+    }
+
+    /// Handles the `LoadUiState` message.
+    ///
+    /// Returns the view state saved when the plugin was last closed, or the
+    /// default state if the file is missing or corrupt.
+    fn handle_load_ui_state(&self) -> WorkerResponse {
+        let ui_state = UiState::load(&self.settings.data_dir.join(UI_STATE_FILE));
+        tracing::debug!(view_mode = ?ui_state.view_mode, query = %ui_state.query, "UI state loaded");
+        WorkerResponse::UiStateLoaded {
+            view_mode: ui_state.view_mode,
+            query: ui_state.query,
+            search_history: ui_state.search_history,
+        }
+    }
+
+    /// Handles the `SaveUiState` message.
+    ///
+    /// Persists the view mode, search query and search history for restoring
+    /// on the next load.
+    fn handle_save_ui_state(&self, view_mode: String, query: String, search_history: Vec<String>) -> WorkerResponse {
+        let ui_state = UiState {
+            view_mode: Some(view_mode),
+            query,
+            search_history,
+        };
+        Self::handle_db_result(
+            "save UI state",
+            ui_state.save(&self.settings.data_dir.join(UI_STATE_FILE)),
+            |()| WorkerResponse::UiStateSaved,
+        )
+    }
+
     /// Attaches the parent trace context from a message to the current thread.
     ///
     /// This function reconstructs the OpenTelemetry context from the serialized
@@ -190,10 +586,25 @@ impl ZessionizerWorker {
         use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
 
         let trace_context = match message {
-            WorkerMessage::LoadProjects { trace_context, .. }
+            WorkerMessage::Configure { trace_context, .. }
+            | WorkerMessage::LoadProjects { trace_context, .. }
+            | WorkerMessage::FetchProjects { trace_context }
+            | WorkerMessage::LoadProjectsPage { trace_context, .. }
             | WorkerMessage::UpdateFrecency { trace_context, .. }
+            | WorkerMessage::ResetFrecency { trace_context, .. }
+            | WorkerMessage::DeleteProject { trace_context, .. }
+            | WorkerMessage::TogglePin { trace_context, .. }
+            | WorkerMessage::RenameProject { trace_context, .. }
+            | WorkerMessage::UpdateProjectLayout { trace_context, .. }
             | WorkerMessage::AddProjectsBatch { trace_context, .. }
-            | WorkerMessage::SyncSessions { trace_context, .. } => trace_context,
+            | WorkerMessage::ImportProjects { trace_context, .. }
+            | WorkerMessage::SyncSessions { trace_context, .. }
+            | WorkerMessage::LoadSessions { trace_context }
+            | WorkerMessage::LoadSelection { trace_context }
+            | WorkerMessage::SaveSelection { trace_context, .. }
+            | WorkerMessage::LoadUiState { trace_context }
+            | WorkerMessage::SaveUiState { trace_context, .. }
+            | WorkerMessage::ExportProjects { trace_context } => trace_context,
         }
         .as_ref()?;
 
@@ -224,20 +635,120 @@ impl ZessionizerWorker {
         let span = tracing::debug_span!("worker_handle_message", message_type = ?message);
         let _guard = span.entered();
 
-        match message {
+        let operation = self.operation_log.is_some().then(|| Self::describe_operation(&message));
+
+        let response = match message {
+            WorkerMessage::Configure { settings, .. } => self.handle_configure(settings),
+
             WorkerMessage::LoadProjects { with_sessions, .. } => {
-                self.handle_load_projects(with_sessions)
+                self.handle_load_projects(with_sessions, false)
+            }
+
+            WorkerMessage::FetchProjects { .. } => self.handle_load_projects(false, true),
+
+            WorkerMessage::LoadProjectsPage { offset, limit, .. } => {
+                self.handle_load_projects_page(offset, limit)
             }
 
             WorkerMessage::UpdateFrecency { path, .. } => self.handle_update_frecency(path),
 
+            WorkerMessage::ResetFrecency { path, .. } => self.handle_reset_frecency(path.as_deref()),
+
+            WorkerMessage::DeleteProject { path, .. } => self.handle_delete_project(path),
+
+            WorkerMessage::TogglePin { path, .. } => self.handle_toggle_pin(&path),
+
+            WorkerMessage::RenameProject { path, name, .. } => self.handle_rename_project(path, name),
+
+            WorkerMessage::UpdateProjectLayout { path, layout, .. } => {
+                self.handle_update_project_layout(path, layout)
+            }
+
             WorkerMessage::AddProjectsBatch { projects, .. } => {
                 self.handle_add_projects_batch(projects)
             }
 
-            WorkerMessage::SyncSessions { active_sessions, .. } => {
-                self.handle_sync_sessions(&active_sessions)
+            WorkerMessage::ImportProjects { paths, .. } => self.handle_import_projects(&paths),
+
+            WorkerMessage::SyncSessions { active_sessions, session_cwds, .. } => {
+                self.handle_sync_sessions(&active_sessions, &session_cwds)
+            }
+
+            WorkerMessage::LoadSessions { .. } => self.handle_load_sessions(),
+
+            WorkerMessage::LoadSelection { .. } => self.handle_load_selection(),
+
+            WorkerMessage::SaveSelection { path, .. } => self.handle_save_selection(path),
+
+            WorkerMessage::LoadUiState { .. } => self.handle_load_ui_state(),
+
+            WorkerMessage::SaveUiState { view_mode, query, search_history, .. } => {
+                self.handle_save_ui_state(view_mode, query, search_history)
+            }
+
+            WorkerMessage::ExportProjects { .. } => self.handle_export_projects(),
+        };
+
+        if let Some((op, path)) = operation {
+            self.log_operation(op, path.as_deref(), &response);
+        }
+
+        response
+    }
+
+    /// Returns the operation name and target path recorded in the operation log.
+    fn describe_operation(message: &WorkerMessage) -> (&'static str, Option<String>) {
+        match message {
+            WorkerMessage::Configure { .. } => ("Configure", None),
+            WorkerMessage::LoadProjects { .. } => ("LoadProjects", None),
+            WorkerMessage::FetchProjects { .. } => ("FetchProjects", None),
+            WorkerMessage::LoadProjectsPage { offset, limit, .. } => {
+                ("LoadProjectsPage", Some(format!("{limit} projects from {offset}")))
+            }
+            WorkerMessage::UpdateFrecency { path, .. } => ("UpdateFrecency", Some(path.clone())),
+            WorkerMessage::ResetFrecency { path, .. } => ("ResetFrecency", path.clone()),
+            WorkerMessage::DeleteProject { path, .. } => ("DeleteProject", Some(path.clone())),
+            WorkerMessage::TogglePin { path, .. } => ("TogglePin", Some(path.clone())),
+            WorkerMessage::RenameProject { path, .. } => ("RenameProject", Some(path.clone())),
+            WorkerMessage::UpdateProjectLayout { path, .. } => ("UpdateProjectLayout", Some(path.clone())),
+            WorkerMessage::AddProjectsBatch { projects, .. } => {
+                ("AddProjectsBatch", Some(format!("{} projects", projects.len())))
+            }
+            WorkerMessage::ImportProjects { paths, .. } => {
+                ("ImportProjects", Some(format!("{} paths", paths.len())))
             }
+            WorkerMessage::SyncSessions { .. } => ("SyncSessions", None),
+            WorkerMessage::LoadSessions { .. } => ("LoadSessions", None),
+            WorkerMessage::LoadSelection { .. } => ("LoadSelection", None),
+            WorkerMessage::SaveSelection { path, .. } => ("SaveSelection", Some(path.clone())),
+            WorkerMessage::LoadUiState { .. } => ("LoadUiState", None),
+            WorkerMessage::SaveUiState { .. } => ("SaveUiState", None),
+            WorkerMessage::ExportProjects { .. } => ("ExportProjects", None),
+        }
+    }
+
+    /// Appends one JSON line describing a handled message to the operation log.
+    ///
+    /// Write failures are traced but never affect the response.
+    fn log_operation(&self, op: &str, path: Option<&str>, response: &WorkerResponse) {
+        let Some(log) = &self.operation_log else {
+            return;
+        };
+
+        let result = match response {
+            WorkerResponse::Error { message } => message.as_str(),
+            _ => "ok",
+        };
+
+        let line = serde_json::json!({
+            "ts": chrono::Utc::now().timestamp(),
+            "op": op,
+            "path": path,
+            "result": result,
+        });
+
+        if let Err(e) = log.write_line(&line.to_string()) {
+            tracing::debug!(error = %e, "failed to write operation log");
         }
     }
 }
@@ -245,13 +756,14 @@ impl ZessionizerWorker {
 /// Initializes tracing for the worker thread.
 ///
 /// Sets up the same tracing configuration as the main thread, ensuring logs
-/// from both threads are written to the same file.
+/// from both threads are written to the same file. Workers get no unload
+/// notification, so the provider is not kept for flushing.
 fn init_worker_tracing() {
     use crate::observability;
     use crate::Config;
 
     let config = Config::default();
-    observability::init_tracing(&config);
+    let _ = observability::init_tracing(&config);
 }
 
 /// Tracks whether worker tracing has been initialized.
@@ -268,7 +780,8 @@ impl ZellijWorker<'_> for ZessionizerWorker {
     /// 2. Lazy-initializes the storage backend if needed
     /// 3. Deserializes the message payload
     /// 4. Processes the message via `handle_message`
-    /// 5. Serializes and sends the response back to the main thread
+    /// 5. Serializes and sends the response, followed by any queued follow-up
+    ///    responses, back to the main thread
     ///
     /// # Arguments
     ///
@@ -290,7 +803,7 @@ impl ZellijWorker<'_> for ZessionizerWorker {
                     let error_response = WorkerResponse::Error {
                         message: format!("Failed to initialize storage: {e}"),
                     };
-                    if let Ok(payload) = serde_json::to_string(&error_response) {
+                    if let Ok(payload) = error_response.to_json() {
                         post_message_to_plugin(PluginMessage {
                             name: message,
                             payload,
@@ -302,7 +815,7 @@ impl ZellijWorker<'_> for ZessionizerWorker {
             }
         }
 
-        let worker_message: WorkerMessage = match serde_json::from_str(&payload) {
+        let worker_message = match WorkerMessage::from_json(&payload) {
             Ok(msg) => msg,
             Err(e) => {
                 tracing::debug!(error = %e, "failed to deserialize worker message");
@@ -312,17 +825,19 @@ impl ZellijWorker<'_> for ZessionizerWorker {
 
         let response = self.handle_message(worker_message);
 
-        match serde_json::to_string(&response) {
-            Ok(payload) => {
-                let plugin_message = PluginMessage {
-                    name: message,
-                    payload,
-                    worker_name: None,
-                };
-                post_message_to_plugin(plugin_message);
-            }
-            Err(e) => {
-                tracing::debug!(error = %e, "failed to serialize worker response");
+        for response in std::iter::once(response).chain(self.take_follow_ups()) {
+            match response.to_json() {
+                Ok(payload) => {
+                    let plugin_message = PluginMessage {
+                        name: message.clone(),
+                        payload,
+                        worker_name: None,
+                    };
+                    post_message_to_plugin(plugin_message);
+                }
+                Err(e) => {
+                    tracing::debug!(error = %e, "failed to serialize worker response");
+                }
             }
         }
     }
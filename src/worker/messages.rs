@@ -3,9 +3,17 @@
 //! This module defines the request and response protocol between the main plugin
 //! thread and the background worker thread that handles storage operations. It
 //! also implements distributed tracing context propagation across thread boundaries.
+//!
+//! Messages and responses serialize to JSON, which is also the format of the
+//! optional worker operation log.
 
-use crate::domain::Project;
+use crate::domain::{Project, Result, ZessionizerError};
+use crate::infrastructure::paths;
+use crate::observability::Rotation;
+use crate::storage::{StorageBackendKind, DEFAULT_HALF_LIFE_HOURS};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Distributed tracing context for cross-thread span propagation.
 ///
@@ -67,6 +75,68 @@ impl TraceContext {
     }
 }
 
+/// Worker-side settings derived from the plugin configuration.
+///
+/// The worker thread has no access to the plugin's configuration map, so the
+/// plugin sends these settings in a `Configure` message before any other work.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerSettings {
+    /// Directory holding storage, UI state, and the operation log.
+    #[serde(default = "paths::get_data_dir")]
+    pub data_dir: PathBuf,
+
+    /// Whether to append one line per handled message to the operation log.
+    #[serde(default)]
+    pub operation_log: bool,
+
+    /// Whether project list responses carry only a content hash.
+    ///
+    /// The main thread then fetches the full list with `FetchProjects` only if
+    /// the hash differs from its own, keeping no-op reloads cheap.
+    #[serde(default)]
+    pub hash_only_responses: bool,
+
+    /// Whether the storage file is pretty-printed (otherwise compact).
+    #[serde(default = "default_true")]
+    pub storage_pretty: bool,
+
+    /// Frecency decay half-life in hours.
+    #[serde(default = "default_half_life_hours")]
+    pub frecency_half_life_hours: f64,
+
+    /// Storage backend to open.
+    #[serde(default)]
+    pub storage_backend: StorageBackendKind,
+
+    /// Rotation of the operation log, matching the trace file's.
+    #[serde(default)]
+    pub log_rotation: Rotation,
+}
+
+impl Default for WorkerSettings {
+    fn default() -> Self {
+        Self {
+            data_dir: paths::get_data_dir(),
+            operation_log: false,
+            hash_only_responses: false,
+            storage_pretty: true,
+            frecency_half_life_hours: DEFAULT_HALF_LIFE_HOURS,
+            storage_backend: StorageBackendKind::Json,
+            log_rotation: Rotation::default(),
+        }
+    }
+}
+
+/// Serde default for boolean settings that are on unless disabled.
+const fn default_true() -> bool {
+    true
+}
+
+/// Serde default for `frecency_half_life_hours`.
+const fn default_half_life_hours() -> f64 {
+    DEFAULT_HALF_LIFE_HOURS
+}
+
 /// Macro to generate builder methods for `WorkerMessage` variants.
 ///
 /// Generates convenience constructors that automatically attach the current
@@ -92,10 +162,25 @@ macro_rules! worker_message_builders {
 }
 
 worker_message_builders! {
+    configure(Configure { settings: WorkerSettings }),
     load_projects(LoadProjects { with_sessions: bool }),
+    fetch_projects(FetchProjects {}),
+    load_projects_page(LoadProjectsPage { offset: usize, limit: usize }),
     update_frecency(UpdateFrecency { path: String }),
+    reset_frecency(ResetFrecency { path: Option<String> }),
+    delete_project(DeleteProject { path: String }),
+    toggle_pin(TogglePin { path: String }),
+    rename_project(RenameProject { path: String, name: String }),
+    update_project_layout(UpdateProjectLayout { path: String, layout: Option<String> }),
     add_projects_batch(AddProjectsBatch { projects: Vec<(String, String)> }),
-    sync_sessions(SyncSessions { active_sessions: Vec<String> }),
+    import_projects(ImportProjects { paths: Vec<String> }),
+    sync_sessions(SyncSessions { active_sessions: Vec<String>, session_cwds: HashMap<String, String> }),
+    load_sessions(LoadSessions {}),
+    load_selection(LoadSelection {}),
+    save_selection(SaveSelection { path: String }),
+    load_ui_state(LoadUiState {}),
+    save_ui_state(SaveUiState { view_mode: String, query: String, search_history: Vec<String> }),
+    export_projects(ExportProjects {}),
 }
 
 /// Messages sent from the main thread to the worker thread.
@@ -103,8 +188,18 @@ worker_message_builders! {
 /// Each variant corresponds to a storage operation that should be performed
 /// asynchronously. All variants include an optional trace context for distributed
 /// tracing support.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WorkerMessage {
+    /// Apply plugin configuration to the worker.
+    Configure {
+        /// Settings to apply.
+        settings: WorkerSettings,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
     /// Load all projects from storage, optionally including session information.
     LoadProjects {
         /// Whether to include active session associations.
@@ -115,6 +210,31 @@ pub enum WorkerMessage {
         trace_context: Option<TraceContext>,
     },
 
+    /// Load all projects, always returning the full list.
+    ///
+    /// Sent in hash-only mode when the reported hash differs from the main
+    /// thread's current projects.
+    FetchProjects {
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Load one page of projects, sorted by frecency.
+    ///
+    /// Answered with `ProjectsPage`, never a hash-only response.
+    LoadProjectsPage {
+        /// Index of the first project of the page in the sorted list.
+        offset: usize,
+
+        /// Maximum number of projects in the page.
+        limit: usize,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
     /// Update the frecency data for a specific project.
     UpdateFrecency {
         /// Filesystem path of the project to update.
@@ -125,6 +245,66 @@ pub enum WorkerMessage {
         trace_context: Option<TraceContext>,
     },
 
+    /// Reset frecency data as if the project was newly added.
+    ///
+    /// Answered with the re-sorted project list.
+    ResetFrecency {
+        /// Filesystem path of the project to reset, or `None` to reset all.
+        path: Option<String>,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Remove a project from storage.
+    DeleteProject {
+        /// Filesystem path of the project to remove.
+        path: String,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Flip a project's pinned state.
+    ///
+    /// Answered with the re-sorted project list.
+    TogglePin {
+        /// Filesystem path of the project to pin or unpin.
+        path: String,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Rename a project and its session link, after its session was renamed.
+    RenameProject {
+        /// Filesystem path of the project to rename.
+        path: String,
+
+        /// New project (and session) name.
+        name: String,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Set or clear the layout a project's session is created with.
+    UpdateProjectLayout {
+        /// Filesystem path of the project.
+        path: String,
+
+        /// Layout name, or `None` to use Zellij's default layout.
+        layout: Option<String>,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
     /// Add or update multiple projects in a single transaction.
     AddProjectsBatch {
         /// Project tuples of (path, name) to add.
@@ -135,11 +315,81 @@ pub enum WorkerMessage {
         trace_context: Option<TraceContext>,
     },
 
+    /// Add projects from an explicit path list, naming each after its last
+    /// path segment. Answered like `AddProjectsBatch`.
+    ImportProjects {
+        /// Project directories, as listed in the import file.
+        paths: Vec<String>,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
     /// Synchronize the sessions table with active Zellij sessions.
     SyncSessions {
         /// Names of currently active Zellij sessions.
         active_sessions: Vec<String>,
 
+        /// Known working directories of active sessions, keyed by name.
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        session_cwds: HashMap<String, String>,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Load the session names stored by the last `SyncSessions`.
+    LoadSessions {
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Load the project path selected when the plugin was last closed.
+    LoadSelection {
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Persist the currently selected project path.
+    SaveSelection {
+        /// Filesystem path of the selected project.
+        path: String,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Load the view mode and search query saved when the plugin was last closed.
+    LoadUiState {
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Persist the current view mode, search query and search history.
+    SaveUiState {
+        /// View mode name (`sessions`, `projects`, or `all`).
+        view_mode: String,
+
+        /// Current search query, empty if no search is active.
+        query: String,
+
+        /// Recent search queries, newest first.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        search_history: Vec<String>,
+
+        /// Trace context for linking spans across threads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trace_context: Option<TraceContext>,
+    },
+
+    /// Serialize all stored projects with their frecency scores.
+    ExportProjects {
         /// Trace context for linking spans across threads.
         #[serde(skip_serializing_if = "Option::is_none")]
         trace_context: Option<TraceContext>,
@@ -152,18 +402,68 @@ pub enum WorkerMessage {
 /// successfully with result data or with an error message.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WorkerResponse {
+    /// Worker settings were applied.
+    Configured,
+
     /// Projects were successfully loaded from storage.
     ProjectsLoaded {
         /// The loaded projects, sorted by frecency.
         projects: Vec<Project>,
     },
 
+    /// One page of projects was loaded.
+    ProjectsPage {
+        /// Index of the page's first project in the sorted list.
+        offset: usize,
+
+        /// Number of stored projects across all pages.
+        total: usize,
+
+        /// Projects of the page, sorted by frecency.
+        projects: Vec<Project>,
+    },
+
+    /// Projects were loaded or changed, reported by hash only.
+    ///
+    /// Replaces `ProjectsLoaded` and `ProjectsBatchAdded` in hash-only mode.
+    ProjectsHash {
+        /// Content hash of the sorted project list (see `content_hash`).
+        hash: u64,
+
+        /// Whether the hash differs from the previous one reported by the worker.
+        changed: bool,
+    },
+
     /// Project frecency was successfully updated.
     FrecencyUpdated {
         /// Path of the updated project.
         path: String,
     },
 
+    /// A project was removed from storage.
+    ProjectDeleted {
+        /// Path of the removed project.
+        path: String,
+    },
+
+    /// A project was renamed.
+    ProjectRenamed {
+        /// Path of the renamed project.
+        path: String,
+
+        /// New project name.
+        name: String,
+    },
+
+    /// A project's layout was updated.
+    ProjectLayoutUpdated {
+        /// Path of the updated project.
+        path: String,
+
+        /// New layout name, `None` if cleared.
+        layout: Option<String>,
+    },
+
     /// Multiple projects were successfully added or updated.
     ProjectsBatchAdded {
         /// Number of projects in the batch.
@@ -173,15 +473,133 @@ pub enum WorkerResponse {
         projects: Vec<Project>,
     },
 
+    /// Counts of a stored scan batch, sent after `ProjectsBatchAdded`.
+    ScanSummary {
+        /// Number of projects reported by the scan.
+        discovered: usize,
+
+        /// Number of projects that were not in storage before.
+        added: usize,
+
+        /// Number of projects that were already stored and got refreshed.
+        updated: usize,
+    },
+
     /// Sessions were successfully synchronized.
     SessionsSynced {
         /// Number of sessions synchronized.
         count: usize,
     },
 
+    /// The last-known session names were loaded from storage.
+    SessionsLoaded {
+        /// Names of the sessions active when they were last synchronized.
+        names: Vec<String>,
+    },
+
+    /// The last saved selection was loaded.
+    SelectionLoaded {
+        /// Path of the previously selected project, if one was saved.
+        path: Option<String>,
+    },
+
+    /// The current selection was persisted.
+    SelectionSaved {
+        /// Path of the saved project.
+        path: String,
+    },
+
+    /// The last saved view state was loaded.
+    UiStateLoaded {
+        /// Name of the saved view mode, if one was saved.
+        view_mode: Option<String>,
+
+        /// Saved search query, empty if none.
+        query: String,
+
+        /// Saved recent search queries, newest first.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        search_history: Vec<String>,
+    },
+
+    /// The current view state was persisted.
+    UiStateSaved,
+
+    /// All stored projects were serialized.
+    ProjectsExported {
+        /// Export JSON in frecency order (see `storage::ProjectsExport`).
+        json: String,
+    },
+
     /// An error occurred during the worker operation.
     Error {
         /// Human-readable error message.
         message: String,
     },
 }
+
+impl WorkerMessage {
+    /// Serializes the message into the JSON payload sent to the worker.
+    ///
+    /// A `None` trace context is omitted from the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZessionizerError::Worker`] if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use zessionizer::worker::WorkerMessage;
+    ///
+    /// let message = WorkerMessage::update_frecency("/code/api".to_string());
+    /// let json = message.to_json().unwrap();
+    /// assert_eq!(WorkerMessage::from_json(&json).unwrap(), message);
+    /// ```
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self)
+            .map_err(|e| ZessionizerError::Worker(format!("failed to serialize message: {e}")))
+    }
+
+    /// Deserializes a message from a worker payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZessionizerError::Worker`] if the payload is not a valid message.
+    pub fn from_json(payload: &str) -> Result<Self> {
+        serde_json::from_str(payload)
+            .map_err(|e| ZessionizerError::Worker(format!("failed to deserialize message: {e}")))
+    }
+}
+
+impl WorkerResponse {
+    /// Serializes the response into the JSON payload sent to the plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZessionizerError::Worker`] if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use zessionizer::worker::WorkerResponse;
+    ///
+    /// let response = WorkerResponse::FrecencyUpdated { path: "/code/api".to_string() };
+    /// let json = response.to_json().unwrap();
+    /// assert_eq!(WorkerResponse::from_json(&json).unwrap(), response);
+    /// ```
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self)
+            .map_err(|e| ZessionizerError::Worker(format!("failed to serialize response: {e}")))
+    }
+
+    /// Deserializes a response from a plugin message payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZessionizerError::Worker`] if the payload is not a valid response.
+    pub fn from_json(payload: &str) -> Result<Self> {
+        serde_json::from_str(payload)
+            .map_err(|e| ZessionizerError::Worker(format!("failed to deserialize response: {e}")))
+    }
+}
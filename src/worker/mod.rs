@@ -13,4 +13,4 @@ pub mod handler;
 pub mod messages;
 
 pub use handler::ZessionizerWorker;
-pub use messages::{TraceContext, WorkerMessage, WorkerResponse};
+pub use messages::{TraceContext, WorkerMessage, WorkerResponse, WorkerSettings};
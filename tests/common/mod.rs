@@ -0,0 +1,60 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Each test binary uses a different subset, hence the `dead_code` allowance.
+
+#![allow(dead_code)]
+
+use std::path::Path;
+use zessionizer::storage::JsonStorage;
+use zessionizer::worker::{WorkerMessage, WorkerSettings, ZessionizerWorker};
+use zessionizer::{AppState, Project, Theme};
+
+/// Projects named `names`, located at `/code/<name>`, in frecency order.
+pub fn projects(names: &[&str]) -> Vec<Project> {
+    names
+        .iter()
+        .map(|name| Project::new(format!("/code/{name}"), (*name).to_string()))
+        .collect()
+}
+
+/// App state listing [`projects`] with the default theme.
+pub fn state(names: &[&str]) -> AppState {
+    AppState::new(projects(names), Theme::default())
+}
+
+/// Names of the projects `state` shows, in display order.
+pub fn shown(state: &AppState) -> Vec<String> {
+    state
+        .filtered_projects
+        .iter()
+        .map(|project| project.name.clone())
+        .collect()
+}
+
+/// Scanned `(path, name)` pairs for [`projects`] named `names`.
+pub fn scan(names: &[&str]) -> Vec<(String, String)> {
+    names
+        .iter()
+        .map(|name| (format!("/code/{name}"), (*name).to_string()))
+        .collect()
+}
+
+/// Worker settings storing data in `dir`.
+pub fn settings(dir: &Path) -> WorkerSettings {
+    WorkerSettings {
+        data_dir: dir.to_path_buf(),
+        ..WorkerSettings::default()
+    }
+}
+
+/// Worker configured to store its data in `dir`.
+pub fn worker(dir: &Path) -> ZessionizerWorker {
+    let mut worker = ZessionizerWorker::default();
+    worker.handle_message(WorkerMessage::configure(settings(dir)));
+    worker
+}
+
+/// JSON storage at `projects.json` in `dir`.
+pub fn storage(dir: &Path) -> JsonStorage {
+    JsonStorage::new(dir.join("projects.json")).unwrap()
+}
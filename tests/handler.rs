@@ -0,0 +1,286 @@
+//! Event handling scenarios driven through `handle_event`.
+
+mod common;
+
+use std::path::PathBuf;
+use zessionizer::worker::WorkerMessage;
+use zessionizer::{
+    handle_event, Action, AppState, EnterActions, Event, InputMode, Project, Theme, ViewMode,
+};
+
+#[test]
+fn hiding_the_path_column_widens_names() {
+    let name = "customer-portal-frontend-with-a-rather-long-name";
+    let projects = vec![Project::new("/code/portal".to_string(), name.to_string())];
+    let mut state = AppState::new(projects, Theme::default());
+    state.view_mode = ViewMode::All;
+    state.apply_search_filter();
+
+    let vm = state.compute_viewmodel(24, 80);
+    assert!(vm.show_path);
+    assert_eq!(vm.display_items[0].path, "/code/portal");
+    assert!(vm.display_items[0].name.chars().count() <= 35);
+
+    handle_event(&mut state, &Event::TogglePath).unwrap();
+    let vm = state.compute_viewmodel(24, 80);
+    assert!(!vm.show_path);
+    assert_eq!(vm.display_items[0].path, "");
+    assert_eq!(vm.display_items[0].name, name);
+
+    handle_event(&mut state, &Event::TogglePath).unwrap();
+    assert!(state.compute_viewmodel(24, 80).show_path);
+}
+
+#[test]
+fn enter_emits_the_action_configured_for_the_view() {
+    let mut state = common::state(&["api", "web"]);
+    state.active_sessions.insert("api".to_string());
+    state.enter_actions = EnterActions::parse("sessions=session,projects=tab,all=session");
+    let enter = |state: &mut AppState, view_mode| {
+        state.view_mode = view_mode;
+        state.selected_index = 0;
+        state.apply_search_filter();
+        handle_event(state, &Event::SelectProject)
+            .unwrap()
+            .1
+            .pop()
+            .unwrap()
+    };
+
+    assert!(
+        matches!(enter(&mut state, ViewMode::Sessions), Action::SwitchSession { name, .. } if name == "api")
+    );
+    assert!(matches!(
+        enter(&mut state, ViewMode::ProjectsWithoutSessions),
+        Action::OpenTab { name, .. } if name == "web"
+    ));
+
+    state.enter_actions = EnterActions::parse("all=tab");
+    assert!(
+        matches!(enter(&mut state, ViewMode::All), Action::OpenTab { name, .. } if name == "api")
+    );
+    assert!(matches!(
+        enter(&mut state, ViewMode::ProjectsWithoutSessions),
+        Action::CreateSession { name, .. } if name == "web"
+    ));
+}
+
+#[test]
+fn ctrl_enter_opens_a_tab_whatever_enter_does() {
+    let mut state = common::state(&["api", "web"]);
+    state.view_mode = ViewMode::All;
+    state.active_sessions.insert("web".to_string());
+    state.apply_search_filter();
+
+    for (name, session_action) in [
+        (
+            "api",
+            Action::CreateSession {
+                name: "api".to_string(),
+                path: PathBuf::from("/code/api"),
+                layout: None,
+            },
+        ),
+        (
+            "web",
+            Action::SwitchSession {
+                name: "web".to_string(),
+                path: PathBuf::from("/code/web"),
+                layout: None,
+            },
+        ),
+    ] {
+        state.select_by_name(name);
+        let (_, actions) = handle_event(&mut state, &Event::SelectProject).unwrap();
+        assert_eq!(actions.last(), Some(&session_action));
+
+        let (_, actions) = handle_event(&mut state, &Event::OpenProjectInTab).unwrap();
+        let tab_action = Action::OpenTab {
+            name: name.to_string(),
+            path: PathBuf::from(format!("/code/{name}")),
+        };
+        assert_eq!(actions.last(), Some(&tab_action));
+    }
+
+    state.set_search_query("missing".to_string());
+    let (_, actions) = handle_event(&mut state, &Event::OpenProjectInTab).unwrap();
+    assert!(actions.is_empty());
+}
+
+#[test]
+fn sessions_above_the_pane_threshold_need_a_second_kill() {
+    let mut state = common::state(&["scratch", "api"]);
+    state
+        .active_sessions
+        .extend(["scratch", "api"].map(String::from));
+    state
+        .session_panes
+        .extend([("scratch".to_string(), 1), ("api".to_string(), 4)]);
+    state.kill_confirm_panes = Some(1);
+    state.apply_search_filter();
+
+    // At or below the threshold, `K` kills straight away.
+    state.select_by_name("scratch");
+    let (_, actions) = handle_event(&mut state, &Event::KillSession).unwrap();
+    assert_eq!(
+        actions,
+        [Action::KillSession {
+            name: "scratch".to_string()
+        }]
+    );
+
+    // Above it, the first `K` only asks for confirmation...
+    state.select_by_name("api");
+    let (render, actions) = handle_event(&mut state, &Event::KillSession).unwrap();
+    assert!(render && actions.is_empty());
+    assert_eq!(state.pending_kill.as_deref(), Some("api"));
+
+    // ...any other key cancels it...
+    assert!(state.cancel_pending_kill());
+    let (_, actions) = handle_event(&mut state, &Event::KillSession).unwrap();
+    assert!(actions.is_empty());
+
+    // ...and a second `K` confirms the kill.
+    let (_, actions) = handle_event(&mut state, &Event::KillSession).unwrap();
+    assert_eq!(
+        actions,
+        [Action::KillSession {
+            name: "api".to_string()
+        }]
+    );
+    assert!(state.pending_kill.is_none());
+}
+
+#[test]
+fn d_deletes_the_selected_project() {
+    let mut state = common::state(&["api"]);
+    state.view_mode = ViewMode::All;
+    state.apply_search_filter();
+
+    let (_, actions) = handle_event(&mut state, &Event::DeleteProject).unwrap();
+    let [Action::PostToWorker(WorkerMessage::DeleteProject { path, .. })] = actions.as_slice()
+    else {
+        panic!("expected a delete");
+    };
+    assert_eq!(path, "/code/api");
+
+    // Nothing to delete without a selection.
+    let mut state = common::state(&[]);
+    assert_eq!(
+        handle_event(&mut state, &Event::DeleteProject).unwrap(),
+        (false, vec![])
+    );
+}
+
+#[test]
+fn only_the_current_session_can_be_renamed() {
+    let mut state = common::state(&["api", "web"]);
+    state
+        .active_sessions
+        .extend(["api", "web"].map(String::from));
+    state.current_session = Some("api".to_string());
+    state.apply_search_filter();
+
+    state.select_by_name("web");
+    handle_event(&mut state, &Event::StartRename).unwrap();
+    assert_eq!(state.input_mode, InputMode::Normal);
+    assert_eq!(
+        state.footer_notice.as_deref(),
+        Some("Only the current session can be renamed")
+    );
+
+    state.select_by_name("api");
+    handle_event(&mut state, &Event::StartRename).unwrap();
+    assert_eq!(state.input_mode, InputMode::Rename);
+    assert_eq!(state.rename_buffer, "api");
+}
+
+#[test]
+fn renames_to_existing_sessions_are_rejected_after_sanitizing() {
+    let mut state = common::state(&["api", "web"]);
+    state
+        .active_sessions
+        .extend(["api", "web", "my-app-v2"].map(String::from));
+    state.current_session = Some("api".to_string());
+    state.apply_search_filter();
+    state.select_by_name("api");
+    handle_event(&mut state, &Event::StartRename).unwrap();
+
+    // Names of other active sessions are rejected, keeping the input open.
+    for (typed, taken) in [("web", "web"), ("my app/v2", "my-app-v2")] {
+        state.rename_buffer = typed.to_string();
+        let (_, actions) = handle_event(&mut state, &Event::ConfirmRename).unwrap();
+        assert!(actions.is_empty());
+        assert_eq!(state.input_mode, InputMode::Rename);
+        assert_eq!(
+            state.footer_notice,
+            Some(format!("Session '{taken}' already exists"))
+        );
+    }
+}
+
+#[test]
+fn confirmed_renames_rename_the_session_and_its_project() {
+    let mut state = common::state(&["api", "web"]);
+    state
+        .active_sessions
+        .extend(["api", "web"].map(String::from));
+    state.current_session = Some("api".to_string());
+    state.apply_search_filter();
+    state.select_by_name("api");
+    handle_event(&mut state, &Event::StartRename).unwrap();
+
+    // Free names are sanitized like new session names.
+    state.rename_buffer = "api v2".to_string();
+    let (_, actions) = handle_event(&mut state, &Event::ConfirmRename).unwrap();
+    assert_eq!(
+        actions[0],
+        Action::RenameSession {
+            old_name: "api".to_string(),
+            new_name: "api-v2".to_string()
+        }
+    );
+    assert!(matches!(
+        &actions[1],
+        Action::PostToWorker(WorkerMessage::RenameProject { path, name, .. })
+            if path == "/code/api" && name == "api-v2"
+    ));
+    assert_eq!(actions.len(), 2);
+    assert_eq!(state.input_mode, InputMode::Normal);
+    assert!(state.active_sessions.contains("api-v2"));
+}
+
+#[test]
+fn marked_sessions_are_killed_together() {
+    let mut state = common::state(&["api", "web", "docs"]);
+    state
+        .active_sessions
+        .extend(["api", "web", "docs"].map(String::from));
+    state.apply_search_filter();
+
+    for name in ["web", "api", "docs"] {
+        state.select_by_name(name);
+        handle_event(&mut state, &Event::ToggleMark).unwrap();
+    }
+    handle_event(&mut state, &Event::ToggleMark).unwrap();
+    assert_eq!(state.marked_sessions.len(), 2);
+
+    // Marked sessions are killed together, regardless of the selection.
+    let (_, actions) = handle_event(&mut state, &Event::KillSession).unwrap();
+    assert_eq!(
+        actions,
+        [Action::KillSessions {
+            names: vec!["api".to_string(), "web".to_string()]
+        }]
+    );
+    assert!(state.marked_sessions.is_empty());
+
+    // Without marks, the selected session is killed.
+    let (_, actions) = handle_event(&mut state, &Event::KillSession).unwrap();
+    assert_eq!(
+        actions,
+        [Action::KillSession {
+            name: "docs".to_string()
+        }]
+    );
+}
@@ -0,0 +1,61 @@
+//! Trace export scenarios.
+
+use zessionizer::observability::span_formatter::SpanFormatter;
+use zessionizer::observability::trace_resource;
+use zessionizer::Config;
+
+/// Resource attributes of a formatted batch, sorted by key.
+fn attributes(config: &Config) -> Vec<(String, String)> {
+    let batch = SpanFormatter::new(trace_resource(config)).format_batch(&[]);
+    let mut attributes: Vec<(String, String)> = batch["resourceSpans"][0]["resource"]["attributes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|attr| {
+            let value = attr["value"]["stringValue"].as_str().unwrap();
+            (attr["key"].as_str().unwrap().to_string(), value.to_string())
+        })
+        .collect();
+    attributes.sort();
+    attributes
+}
+
+fn pair(key: &str, value: &str) -> (String, String) {
+    (key.to_string(), value.to_string())
+}
+
+#[test]
+fn batches_carry_the_service_name_by_default() {
+    assert_eq!(
+        attributes(&Config::default()),
+        [pair("service.name", "Zessionizer")]
+    );
+}
+
+#[test]
+fn batches_carry_configured_trace_attributes() {
+    let config = Config {
+        trace_attributes: vec![
+            pair("host.name", "laptop"),
+            pair("deployment.environment", "dev"),
+        ],
+        ..Config::default()
+    };
+    assert_eq!(
+        attributes(&config),
+        [
+            pair("deployment.environment", "dev"),
+            pair("host.name", "laptop"),
+            pair("service.name", "Zessionizer")
+        ]
+    );
+}
+
+#[test]
+fn configured_service_name_overrides_the_default() {
+    let config = Config {
+        trace_attributes: vec![pair("service.name", "zs-work")],
+        ..Config::default()
+    };
+    assert_eq!(attributes(&config), [pair("service.name", "zs-work")]);
+}
@@ -0,0 +1,348 @@
+//! Filtering, ordering and view model scenarios for `AppState`.
+
+mod common;
+
+use std::collections::{HashMap, HashSet};
+use zessionizer::worker::{WorkerMessage, WorkerResponse};
+use zessionizer::{
+    handle_event, AppState, Event, InputMode, Project, SearchFocus, SortMode, Theme, ViewMode,
+};
+
+#[test]
+fn sessions_first_moves_active_sessions_ahead_in_frecency_order() {
+    let mut state = common::state(&["web", "docs", "cli", "api"]);
+    state.view_mode = ViewMode::All;
+    state
+        .active_sessions
+        .extend(["api", "cli"].map(String::from));
+
+    state.apply_search_filter();
+    assert_eq!(common::shown(&state), ["web", "docs", "cli", "api"]);
+
+    state.sessions_first = true;
+    state.apply_search_filter();
+    assert_eq!(common::shown(&state), ["cli", "api", "web", "docs"]);
+}
+
+#[test]
+fn restored_selection_follows_its_project_to_a_new_position() {
+    let mut state = common::state(&[]);
+    state.view_mode = ViewMode::All;
+
+    // The saved path waits until projects are visible.
+    state.pending_selection = Some("/code/api".to_string());
+    assert!(!state.restore_selection());
+    assert!(state.pending_selection.is_some());
+
+    // "api" was first when saved; frecency now ranks it last.
+    state.projects = common::projects(&["web", "cli", "api"]);
+    state.apply_search_filter();
+    assert!(state.restore_selection());
+    assert_eq!(state.selected_index, 2);
+    assert_eq!(state.selected_project().unwrap().path, "/code/api");
+
+    // The path is consumed, so later reloads leave the cursor alone.
+    assert_eq!(state.pending_selection, None);
+    state.selected_index = 0;
+    assert!(!state.restore_selection());
+    assert_eq!(state.selected_index, 0);
+
+    // A path no longer listed keeps the cursor where it is.
+    state.pending_selection = Some("/code/gone".to_string());
+    assert!(!state.restore_selection());
+    assert_eq!(state.selected_index, 0);
+}
+
+#[test]
+fn zero_and_tiny_sizes_yield_degenerate_view_models() {
+    let projects = (0..30)
+        .map(|i| Project::new(format!("/code/p{i}"), format!("p{i}")))
+        .collect();
+    let mut state = AppState::new(projects, Theme::default());
+    state.view_mode = ViewMode::All;
+    state.selected_index = 20;
+    state.apply_search_filter();
+
+    for searching in [false, true] {
+        if searching {
+            state.input_mode = InputMode::Search(SearchFocus::Typing);
+            state.set_search_query("p".to_string());
+        }
+        for (rows, cols) in [(0, 0), (1, 1), (0, 80), (24, 0)] {
+            let viewmodel = state.compute_viewmodel(rows, cols);
+            assert!(viewmodel.display_items.is_empty(), "{rows}x{cols}");
+            assert!(viewmodel.empty_state.is_none());
+            assert!(viewmodel.scroll_info.is_none());
+            assert_eq!(viewmodel.selected_index, 0);
+        }
+    }
+}
+
+#[test]
+fn session_names_are_highlighted_in_normal_mode() {
+    let mut state = common::state(&["api-gateway", "web", "docs"]);
+    state.view_mode = ViewMode::Sessions;
+    state
+        .active_sessions
+        .extend(["api-gateway", "web", "docs"].map(String::from));
+    state.set_search_query("gate".to_string());
+    assert_eq!(state.input_mode, InputMode::Normal);
+
+    let items = state.compute_viewmodel(24, 80).display_items;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "api-gateway");
+    assert_eq!(items[0].highlight_ranges, [(4, 8)]);
+
+    state.set_search_query(String::new());
+    let items = state.compute_viewmodel(24, 80).display_items;
+    assert_eq!(items.len(), 3);
+    assert!(items.iter().all(|item| item.highlight_ranges.is_empty()));
+}
+
+#[test]
+fn current_session_leads_the_sessions_view() {
+    let mut state = common::state(&["api", "web", "docs"]);
+    state.view_mode = ViewMode::Sessions;
+    state
+        .active_sessions
+        .extend(["api", "web", "docs"].map(String::from));
+    state.current_session = Some("docs".to_string());
+
+    state.apply_search_filter();
+    assert_eq!(common::shown(&state), ["api", "web", "docs"]);
+
+    state.current_session_first = true;
+    state.apply_search_filter();
+    assert_eq!(common::shown(&state), ["docs", "api", "web"]);
+
+    let items = state.compute_viewmodel(24, 80).display_items;
+    assert!(items[0].is_current_session && items[0].is_selected);
+    assert!(items[1..]
+        .iter()
+        .all(|item| !item.is_current_session && !item.is_selected));
+
+    // Other views keep their order.
+    state.view_mode = ViewMode::All;
+    state.apply_search_filter();
+    assert_eq!(common::shown(&state), ["api", "web", "docs"]);
+}
+
+#[test]
+fn stored_sessions_seed_the_view_until_the_first_update() {
+    // After a restart, the worker returns the project sessions stored by the
+    // last sync.
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+    let added = ["api", "web", "docs"].map(|name| (format!("/code/{name}"), name.to_string()));
+    worker.handle_message(WorkerMessage::add_projects_batch(added.to_vec()));
+    let synced = ["api", "web", "scratch"].map(String::from).to_vec();
+    worker.handle_message(WorkerMessage::sync_sessions(synced, HashMap::new()));
+
+    let loaded = common::worker(dir.path()).handle_message(WorkerMessage::load_sessions());
+    let WorkerResponse::SessionsLoaded { names } = &loaded else {
+        panic!("unexpected response: {loaded:?}");
+    };
+    let mut stored = names.clone();
+    stored.sort();
+    assert_eq!(stored, ["api", "web"]);
+
+    // They seed the Sessions view at startup...
+    let mut state = common::state(&["api", "web", "docs"]);
+    state.view_mode = ViewMode::Sessions;
+    let names =
+        |state: &AppState| -> HashSet<String> { common::shown(state).into_iter().collect() };
+    let (render, _) = handle_event(&mut state, &Event::WorkerResponse(loaded.clone())).unwrap();
+    assert!(render);
+    assert_eq!(
+        names(&state),
+        HashSet::from(["api".to_string(), "web".to_string()])
+    );
+
+    // ...until the first live update reconciles them...
+    let update = Event::SessionUpdate {
+        active_sessions: HashSet::from(["web".to_string(), "docs".to_string()]),
+        current_session: None,
+        session_panes: HashMap::new(),
+        session_cwds: HashMap::new(),
+    };
+    handle_event(&mut state, &update).unwrap();
+    assert_eq!(
+        names(&state),
+        HashSet::from(["web".to_string(), "docs".to_string()])
+    );
+
+    // ...after which stored sessions are ignored.
+    assert!(!state.seed_sessions(&["api".to_string()]));
+    assert_eq!(
+        names(&state),
+        HashSet::from(["web".to_string(), "docs".to_string()])
+    );
+}
+
+#[test]
+fn filtered_matches_the_view_model_across_views_and_searches() {
+    let mut state = common::state(&["api", "web", "apps"]);
+    state.active_sessions.insert("web".to_string());
+
+    let matches_viewmodel = |state: &AppState| {
+        let shown: Vec<String> = state
+            .compute_viewmodel(40, 200)
+            .display_items
+            .into_iter()
+            .map(|item| item.name)
+            .collect();
+        let filtered: Vec<String> = state.filtered().map(|p| p.name.clone()).collect();
+        shown == filtered
+    };
+    for view in [
+        ViewMode::Sessions,
+        ViewMode::ProjectsWithoutSessions,
+        ViewMode::All,
+    ] {
+        state.view_mode = view;
+        for query in ["", "ap", "zzz"] {
+            state.set_search_query(query.to_string());
+            assert!(matches_viewmodel(&state), "{view:?} / {query:?}");
+        }
+    }
+
+    state.view_mode = ViewMode::All;
+    state.set_search_query("zzz".to_string());
+    assert_eq!(state.filtered().count(), 0);
+}
+
+#[test]
+fn footer_counts_follow_footer_show_counts() {
+    let mut state = common::state(&["api", "web", "apps"]);
+    state.view_mode = ViewMode::All;
+    state.set_search_query("ap".to_string());
+
+    let footer = state.compute_viewmodel(24, 80).footer;
+    assert_eq!(footer.counts, None);
+    assert!(!footer.keybindings.contains("[2/3]"));
+
+    state.footer_show_counts = true;
+    assert_eq!(
+        state.compute_viewmodel(24, 80).footer.counts.as_deref(),
+        Some("[2/3]")
+    );
+
+    // Counts stay while a message replaces the keybindings.
+    state.footer_notice = Some("Session 'api' already exists".to_string());
+    assert_eq!(
+        state.compute_viewmodel(24, 80).footer.counts.as_deref(),
+        Some("[2/3]")
+    );
+    state.footer_message = Some("Config: invalid sort_mode".to_string());
+    state.footer_notice = None;
+    assert_eq!(
+        state.compute_viewmodel(24, 80).footer.counts.as_deref(),
+        Some("[2/3]")
+    );
+}
+
+#[test]
+fn multibyte_names_are_cut_at_character_boundaries() {
+    let names = [
+        "проект-с-очень-длинным-именем-и-ещё-хвостом",
+        "🦀 ferris-🚀-launch-pad ✨ with a long emoji name 🎉",
+    ];
+    for name in names {
+        let projects = vec![Project::new("/code/p".to_string(), name.to_string())];
+        let mut state = AppState::new(projects, Theme::default());
+        state.view_mode = ViewMode::All;
+        state.apply_search_filter();
+        let shown = |state: &AppState, cols| {
+            state.compute_viewmodel(24, cols).display_items[0]
+                .name
+                .clone()
+        };
+        let cut = |keep| format!("{}...", name.chars().take(keep).collect::<String>());
+
+        // The NAME column keeps 32 characters and the ellipsis.
+        assert_eq!(shown(&state, 80), cut(32));
+
+        // Without paths, the name uses the terminal width less a margin.
+        state.show_path = false;
+        assert_eq!(shown(&state, 20), cut(15));
+        assert_eq!(shown(&state, 120), name);
+    }
+}
+
+#[test]
+fn each_sort_mode_orders_projects_and_names_itself_in_the_header() {
+    let now = chrono::Utc::now().timestamp();
+    let project = |name: &str, access_count, hours_ago: i64, pinned| {
+        let mut project = Project::new(format!("/code/{name}"), name.to_string());
+        project.access_count = access_count;
+        project.last_accessed = now - hours_ago * 3600;
+        project.pinned = pinned;
+        project
+    };
+    // In frecency order, as the worker sends them.
+    let projects = vec![
+        project("delta", 1, 120, true),
+        project("bravo", 9, 2, false),
+        project("Charlie", 5, 72, false),
+        project("alpha", 2, 1, false),
+    ];
+    let mut state = AppState::new(projects, Theme::default());
+    state.view_mode = ViewMode::All;
+
+    for (mode, label, order) in [
+        (
+            SortMode::Frecency,
+            "frecency",
+            ["delta", "bravo", "Charlie", "alpha"],
+        ),
+        (
+            SortMode::Alphabetical,
+            "alpha",
+            ["delta", "alpha", "bravo", "Charlie"],
+        ),
+        (
+            SortMode::RecentlyAccessed,
+            "recent",
+            ["delta", "alpha", "bravo", "Charlie"],
+        ),
+        (
+            SortMode::MostFrequent,
+            "frequent",
+            ["delta", "bravo", "Charlie", "alpha"],
+        ),
+    ] {
+        state.sort_mode = mode;
+        state.apply_search_filter();
+
+        let viewmodel = state.compute_viewmodel(24, 80);
+        assert_eq!(
+            viewmodel.header.title,
+            format!(" All Projects (4) [{label}] ")
+        );
+        assert_eq!(common::shown(&state), order, "{label}");
+        assert!(viewmodel.display_items[0].is_pinned);
+    }
+}
+
+#[test]
+fn tag_tokens_filter_by_marker_file_tags() {
+    let mut state = common::state(&["api", "web", "blog"]);
+    for (project, tags) in
+        state
+            .projects
+            .iter_mut()
+            .zip([&["work", "rust"][..], &["work"], &["personal"]])
+    {
+        project.tags = tags.iter().map(ToString::to_string).collect();
+    }
+    state.view_mode = ViewMode::All;
+
+    state.set_search_query("#work".to_string());
+    assert_eq!(common::shown(&state), ["api", "web"]);
+    state.set_search_query("#Work #rust".to_string());
+    assert_eq!(common::shown(&state), ["api"]);
+    state.set_search_query("#wo we".to_string());
+    assert_eq!(common::shown(&state), ["web"]);
+    state.set_search_query("#ops".to_string());
+    assert!(state.filtered_projects.is_empty());
+}
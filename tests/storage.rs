@@ -0,0 +1,470 @@
+//! Storage backend scenarios.
+
+mod common;
+
+use std::collections::HashMap;
+use zessionizer::storage::json::STORAGE_VERSION;
+use zessionizer::storage::{with_batch, JsonStorage, ProjectRecord, ProjectSort, Storage};
+
+#[test]
+fn compact_and_pretty_files_round_trip_the_same_projects() {
+    let dir = tempfile::tempdir().unwrap();
+    let records: Vec<ProjectRecord> = (0..50)
+        .map(|i| ProjectRecord::new(format!("/code/project-{i}"), format!("project-{i}")))
+        .collect();
+
+    let mut sizes = Vec::new();
+    let mut loaded = Vec::new();
+    for pretty in [true, false] {
+        let path = dir.path().join(format!("projects-{pretty}.json"));
+        let mut storage = JsonStorage::new(path.clone()).unwrap().with_pretty(pretty);
+        with_batch(&mut storage, |storage| storage.add_projects_batch(&records)).unwrap();
+        storage.set_project_pinned("/code/project-7", true).unwrap();
+        drop(storage);
+
+        sizes.push(std::fs::metadata(&path).unwrap().len());
+        let mut projects = JsonStorage::new(path).unwrap().get_all_projects().unwrap();
+        projects.sort_by(|a, b| a.path.cmp(&b.path));
+        loaded.push(projects);
+    }
+
+    // Both modes round-trip the same projects; compact output is smaller.
+    assert_eq!(loaded[0].len(), 50);
+    assert_eq!(loaded[0], loaded[1]);
+    assert!(sizes[1] < sizes[0]);
+}
+
+#[test]
+fn removed_projects_and_their_sessions_stay_removed() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = common::storage(dir.path());
+    for name in ["api", "web"] {
+        storage
+            .add_project(&ProjectRecord::new(format!("/code/{name}"), name))
+            .unwrap();
+    }
+    storage
+        .sync_sessions(&["api".to_string(), "web".to_string()], &HashMap::new())
+        .unwrap();
+
+    storage.remove_project("/code/api").unwrap();
+    storage.remove_project("/code/gone").unwrap();
+
+    // The removal is persisted, sessions included.
+    drop(storage);
+    let storage = common::storage(dir.path());
+    assert!(storage.get_project_by_path("/code/api").unwrap().is_none());
+    assert_eq!(storage.get_all_projects().unwrap().len(), 1);
+    let sessions: Vec<String> = storage
+        .get_all_sessions()
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(sessions, ["web"]);
+}
+
+#[test]
+fn pins_are_persisted() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = common::storage(dir.path());
+    storage
+        .add_project(&ProjectRecord::new("/code/api", "api"))
+        .unwrap();
+    let pinned = |storage: &JsonStorage| {
+        storage
+            .get_project_by_path("/code/api")
+            .unwrap()
+            .unwrap()
+            .pinned
+    };
+    assert!(!pinned(&storage));
+
+    storage.set_project_pinned("/code/api", true).unwrap();
+    assert!(pinned(&storage));
+    assert!(storage.set_project_pinned("/code/cli", true).is_err());
+
+    drop(storage);
+    let mut storage = common::storage(dir.path());
+    assert!(pinned(&storage));
+    storage.set_project_pinned("/code/api", false).unwrap();
+    assert!(!pinned(&storage));
+}
+
+#[test]
+fn batches_write_the_file_once_and_only_when_changed() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("projects.json");
+    let mut storage = common::storage(dir.path());
+
+    storage.begin_batch();
+    for name in ["api", "web", "cli"] {
+        storage
+            .add_project(&ProjectRecord::new(format!("/code/{name}"), name))
+            .unwrap();
+    }
+    assert!(!path.exists()); // nothing written while batching
+
+    storage.end_batch().unwrap();
+    assert_eq!(storage.write_count(), 1);
+
+    storage.begin_batch();
+    storage.end_batch().unwrap();
+    assert_eq!(storage.write_count(), 1);
+
+    let reopened = JsonStorage::new(path).unwrap();
+    assert_eq!(reopened.get_all_projects().unwrap().len(), 3);
+}
+
+#[test]
+fn version_1_files_are_migrated_on_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("projects.json");
+    std::fs::write(
+        &path,
+        r#"{
+          "version": 1,
+          "projects": {
+            "/code/api": {
+              "path": "/code/api",
+              "name": "api",
+              "last_accessed": 1700000000,
+              "access_count": 7,
+              "created_at": 1690000000
+            }
+          },
+          "sessions": [{ "name": "api", "project_path": "/code/api" }],
+          "last_selected": "/code/api"
+        }"#,
+    )
+    .unwrap();
+
+    let storage = common::storage(dir.path());
+    let project = storage.get_project_by_path("/code/api").unwrap().unwrap();
+    assert_eq!(
+        (project.access_count, project.last_accessed),
+        (7, Some(1_700_000_000))
+    );
+    assert!(!project.pinned);
+    assert_eq!(project.layout, None);
+    assert_eq!(storage.get_all_sessions().unwrap().len(), 1);
+    assert_eq!(
+        storage.get_last_selected().unwrap().as_deref(),
+        Some("/code/api")
+    );
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(saved["version"], STORAGE_VERSION);
+}
+
+#[test]
+fn corrupt_files_are_backed_up_and_storage_starts_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    let contents = "{ \"version\": 2, \"projects\": ";
+    std::fs::write(dir.path().join("projects.json"), contents).unwrap();
+
+    let mut storage = common::storage(dir.path());
+    assert!(storage.get_all_projects().unwrap().is_empty());
+    storage
+        .add_project(&ProjectRecord::new("/code/api", "api"))
+        .unwrap();
+
+    let backups: Vec<String> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("projects.json.corrupt."))
+        .collect();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join(&backups[0])).unwrap(),
+        contents
+    );
+}
+
+#[test]
+fn batches_report_which_projects_are_new() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = common::storage(dir.path());
+    storage
+        .add_project(&ProjectRecord::new("/code/api", "api"))
+        .unwrap();
+
+    let entries = storage
+        .add_projects_batch(&[
+            ProjectRecord::new("/code/api", "api"),
+            ProjectRecord::new("/code/web", "web"),
+        ])
+        .unwrap();
+    let inserted: Vec<bool> = entries.iter().map(|entry| entry.inserted).collect();
+    assert_eq!(inserted, [false, true]);
+    assert_eq!(entries[1].record.path, "/code/web");
+}
+
+#[test]
+fn pages_cover_the_sorted_projects_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = common::storage(dir.path());
+    for name in ["e", "d", "c", "b", "a"] {
+        storage
+            .add_project(&ProjectRecord::new(format!("/code/{name}"), name))
+            .unwrap();
+    }
+    let names = |offset, limit| {
+        let page = storage
+            .get_projects_paginated(offset, limit, ProjectSort::Name)
+            .unwrap();
+        assert_eq!(page.total, 5);
+        page.projects
+            .into_iter()
+            .map(|p| p.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names(0, 2), ["a", "b"]);
+    assert_eq!(names(2, 2), ["c", "d"]);
+    assert_eq!(names(4, 2), ["e"]);
+    assert!(names(5, 2).is_empty());
+    assert!(names(9, 2).is_empty());
+    assert!(names(0, 0).is_empty());
+    assert_eq!(names(0, usize::MAX).len(), 5);
+}
+
+#[test]
+fn sessions_link_to_the_same_named_project_in_their_working_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = common::storage(dir.path());
+    storage
+        .add_project(&ProjectRecord::new("/work/api", "api"))
+        .unwrap();
+    storage
+        .add_project(&ProjectRecord::new("/home/me/api", "api"))
+        .unwrap();
+
+    let sessions = ["api".to_string()];
+    let cwds = HashMap::from([("api".to_string(), "/home/me/api".to_string())]);
+    storage.sync_sessions(&sessions, &cwds).unwrap();
+    assert_eq!(
+        storage.get_all_sessions().unwrap()[0].project_path,
+        "/home/me/api"
+    );
+
+    // Without a working directory, the previous link is kept.
+    storage.sync_sessions(&sessions, &HashMap::new()).unwrap();
+    assert_eq!(
+        storage.get_all_sessions().unwrap()[0].project_path,
+        "/home/me/api"
+    );
+}
+
+/// Stores `api` and `web`, each accessed at the given timestamps.
+fn accessed_storage(dir: &std::path::Path, timestamps: &[i64]) -> JsonStorage {
+    let mut storage = common::storage(dir);
+    for name in ["api", "web"] {
+        let path = format!("/code/{name}");
+        storage
+            .add_project(&ProjectRecord::new(path.as_str(), name))
+            .unwrap();
+        for &timestamp in timestamps {
+            storage.update_project_access(&path, timestamp).unwrap();
+        }
+    }
+    storage
+}
+
+/// Access count and last access time of the project at `path`.
+fn frecency(storage: &JsonStorage, path: &str) -> (i32, Option<i64>) {
+    let project = storage.get_project_by_path(path).unwrap().unwrap();
+    (project.access_count, project.last_accessed)
+}
+
+#[test]
+fn resetting_one_project_leaves_the_others_alone() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = accessed_storage(dir.path(), &[1_700_000_000, 1_700_000_100]);
+
+    storage.reset_frecency(Some("/code/api")).unwrap();
+    assert_eq!(frecency(&storage, "/code/api"), (1, None));
+    assert_eq!(frecency(&storage, "/code/web"), (3, Some(1_700_000_100)));
+    assert!(storage.reset_frecency(Some("/code/cli")).is_err());
+}
+
+#[test]
+fn resetting_every_project_is_persisted() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut storage = accessed_storage(dir.path(), &[1_700_000_000]);
+
+    storage.reset_frecency(None).unwrap();
+    drop(storage);
+
+    let storage = common::storage(dir.path());
+    for project in storage.get_all_projects().unwrap() {
+        assert_eq!(frecency(&storage, &project.path), (1, None));
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use std::collections::HashMap;
+    use zessionizer::storage::{JsonStorage, ProjectRecord, ProjectSort, SqliteStorage, Storage};
+
+    #[test]
+    fn sqlite_and_json_backends_behave_alike() {
+        let dir = tempfile::tempdir().unwrap();
+        let backends: Vec<Box<dyn Storage>> = vec![
+            Box::new(SqliteStorage::new(&dir.path().join("projects.sqlite3")).unwrap()),
+            Box::new(JsonStorage::new(dir.path().join("projects.json")).unwrap()),
+        ];
+
+        for mut storage in backends {
+            let get =
+                |storage: &dyn Storage, path: &str| storage.get_project_by_path(path).unwrap();
+
+            storage
+                .add_project(&ProjectRecord::new("/code/api", "api"))
+                .unwrap();
+            let entries = storage
+                .add_projects_batch(&[
+                    ProjectRecord::new("/code/api", "api"),
+                    ProjectRecord::new("/code/web", "web"),
+                ])
+                .unwrap();
+            let inserted: Vec<bool> = entries.iter().map(|entry| entry.inserted).collect();
+            assert_eq!(inserted, [false, true]);
+            assert_eq!(storage.get_all_projects().unwrap().len(), 2);
+
+            storage
+                .update_project_access("/code/web", 1_700_000_000)
+                .unwrap();
+            let web = get(storage.as_ref(), "/code/web").unwrap();
+            assert_eq!(
+                (web.access_count, web.last_accessed),
+                (2, Some(1_700_000_000))
+            );
+            assert!(storage
+                .update_project_access("/code/cli", 1_700_000_000)
+                .is_err());
+
+            storage.rename_project("/code/api", "gateway").unwrap();
+            storage
+                .set_project_layout("/code/api", Some("compact"))
+                .unwrap();
+            let api = get(storage.as_ref(), "/code/api").unwrap();
+            assert_eq!(
+                (api.name.as_str(), api.layout.as_deref()),
+                ("gateway", Some("compact"))
+            );
+
+            storage
+                .sync_sessions(&["gateway".to_string()], &HashMap::new())
+                .unwrap();
+            let sessions = storage.get_all_sessions().unwrap();
+            assert_eq!(sessions.len(), 1);
+            assert_eq!(sessions[0].project_path, "/code/api");
+
+            assert_eq!(storage.get_last_selected().unwrap(), None);
+            storage.set_last_selected("/code/web").unwrap();
+            assert_eq!(
+                storage.get_last_selected().unwrap().as_deref(),
+                Some("/code/web")
+            );
+
+            let page = storage
+                .get_projects_paginated(1, 5, ProjectSort::Name)
+                .unwrap();
+            assert_eq!(page.total, 2);
+            assert_eq!(page.projects[0].name, "web");
+
+            storage.remove_project("/code/api").unwrap();
+            assert!(get(storage.as_ref(), "/code/api").is_none());
+            assert!(storage.get_all_sessions().unwrap().is_empty());
+            storage.remove_project("/code/api").unwrap();
+        }
+    }
+
+    #[test]
+    fn sqlite_schema_is_created_once_at_the_latest_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/projects.sqlite3");
+        let mut storage = SqliteStorage::new(&path).unwrap();
+        assert!(storage.get_all_projects().unwrap().is_empty());
+        storage
+            .add_project(&ProjectRecord::new("/code/api", "api"))
+            .unwrap();
+        drop(storage);
+
+        let schema = || {
+            let conn = rusqlite::Connection::open(&path).unwrap();
+            let version: i64 = conn
+                .pragma_query_value(None, "user_version", |row| row.get(0))
+                .unwrap();
+            let mut stmt = conn
+                .prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name")
+                .unwrap();
+            let tables: Vec<String> = stmt
+                .query_map([], |row| row.get(0))
+                .unwrap()
+                .map(Result::unwrap)
+                .collect();
+            (version, tables)
+        };
+        assert_eq!(
+            schema(),
+            (
+                4,
+                vec![
+                    "meta".to_string(),
+                    "projects".to_string(),
+                    "sessions".to_string()
+                ]
+            )
+        );
+
+        let storage = SqliteStorage::new(&path).unwrap();
+        assert_eq!(storage.get_all_projects().unwrap().len(), 1);
+        drop(storage);
+        assert_eq!(schema().0, 4);
+    }
+
+    #[test]
+    fn sqlite_databases_without_pins_gain_them_unpinned() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("projects.sqlite3");
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "
+            CREATE TABLE projects (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                path TEXT NOT NULL UNIQUE,
+                name TEXT NOT NULL,
+                last_accessed INTEGER,
+                access_count INTEGER NOT NULL DEFAULT 1,
+                created_at INTEGER NOT NULL
+            );
+            CREATE TABLE sessions (name TEXT NOT NULL, project_path TEXT NOT NULL);
+            CREATE TABLE meta (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+            INSERT INTO projects (path, name, access_count, created_at) VALUES ('/code/api', 'api', 3, 0);
+            PRAGMA user_version = 1;
+            ",
+        )
+        .unwrap();
+        drop(conn);
+
+        let mut storage = SqliteStorage::new(&path).unwrap();
+        let api = storage.get_project_by_path("/code/api").unwrap().unwrap();
+        assert_eq!((api.access_count, api.pinned), (3, false));
+
+        storage.set_project_pinned("/code/api", true).unwrap();
+        assert!(storage.set_project_pinned("/code/cli", true).is_err());
+        drop(storage);
+
+        let storage = SqliteStorage::new(&path).unwrap();
+        assert!(
+            storage
+                .get_project_by_path("/code/api")
+                .unwrap()
+                .unwrap()
+                .pinned
+        );
+    }
+}
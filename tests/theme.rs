@@ -0,0 +1,34 @@
+//! Theme file parsing scenarios.
+
+use zessionizer::Theme;
+
+#[test]
+fn extending_themes_inherit_unspecified_colors() {
+    let base = Theme::from_name("catppuccin-mocha").unwrap();
+    let theme = Theme::from_toml_str(
+        r##"
+        extends = "catppuccin-mocha"
+        [colors]
+        border = "#000000"
+        scrollbar_fg = "#111111"
+        "##,
+    )
+    .unwrap();
+
+    // Overridden colors are replaced, the rest inherited from the base.
+    assert_eq!(theme.colors.border, "#000000");
+    assert_eq!(theme.colors.scrollbar_fg.as_deref(), Some("#111111"));
+    assert_eq!(theme.colors.text_normal, base.colors.text_normal);
+    assert_eq!(theme.colors.selection_bg, base.colors.selection_bg);
+    assert_eq!(theme.colors.header_bg, base.colors.header_bg);
+}
+
+#[test]
+fn unknown_bases_and_incomplete_themes_are_rejected() {
+    let unknown = Theme::from_toml_str("extends = \"solarized\"").unwrap_err();
+    assert!(
+        unknown.contains("Unknown base theme `solarized`"),
+        "{unknown}"
+    );
+    assert!(Theme::from_toml_str("name = \"mine\"\n[colors]\nborder = \"#000000\"").is_err());
+}
@@ -0,0 +1,415 @@
+//! Message handling scenarios for the storage worker.
+
+mod common;
+
+use std::collections::{HashMap, HashSet};
+use zessionizer::storage::ProjectsExport;
+use zessionizer::worker::{
+    TraceContext, WorkerMessage, WorkerResponse, WorkerSettings, ZessionizerWorker,
+};
+use zessionizer::Project;
+
+#[test]
+fn operation_log_records_one_line_per_message() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = ZessionizerWorker::default();
+    worker.handle_message(WorkerMessage::configure(WorkerSettings {
+        operation_log: true,
+        ..common::settings(dir.path())
+    }));
+
+    worker.handle_message(WorkerMessage::load_projects(false));
+    let WorkerResponse::Error { message } =
+        worker.handle_message(WorkerMessage::update_frecency("/code/missing".to_string()))
+    else {
+        panic!("unknown projects cannot be visited");
+    };
+
+    let log = std::fs::read_to_string(dir.path().join("zessionizer-worker-ops.json")).unwrap();
+    let lines: Vec<serde_json::Value> = log
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    for line in &lines {
+        assert!(line["ts"].as_i64().unwrap() > 0);
+        assert_eq!(line.as_object().unwrap().len(), 4);
+    }
+    assert_eq!(lines[0]["op"], "LoadProjects");
+    assert_eq!(lines[0]["path"], serde_json::Value::Null);
+    assert_eq!(lines[0]["result"], "ok");
+    assert_eq!(lines[1]["op"], "UpdateFrecency");
+    assert_eq!(lines[1]["path"], "/code/missing");
+    assert_eq!(lines[1]["result"], message);
+}
+
+/// One message of every variant, all carrying `trace_context`.
+fn all_messages(trace_context: Option<&TraceContext>) -> Vec<WorkerMessage> {
+    let t = || trace_context.cloned();
+    vec![
+        WorkerMessage::Configure {
+            settings: WorkerSettings::default(),
+            trace_context: t(),
+        },
+        WorkerMessage::LoadProjects {
+            with_sessions: true,
+            trace_context: t(),
+        },
+        WorkerMessage::FetchProjects { trace_context: t() },
+        WorkerMessage::LoadProjectsPage {
+            offset: 10,
+            limit: 5,
+            trace_context: t(),
+        },
+        WorkerMessage::UpdateFrecency {
+            path: "/code/api".into(),
+            trace_context: t(),
+        },
+        WorkerMessage::ResetFrecency {
+            path: None,
+            trace_context: t(),
+        },
+        WorkerMessage::DeleteProject {
+            path: "/code/api".into(),
+            trace_context: t(),
+        },
+        WorkerMessage::TogglePin {
+            path: "/code/api".into(),
+            trace_context: t(),
+        },
+        WorkerMessage::RenameProject {
+            path: "/code/api".into(),
+            name: "svc".into(),
+            trace_context: t(),
+        },
+        WorkerMessage::UpdateProjectLayout {
+            path: "/code/api".into(),
+            layout: Some("compact".into()),
+            trace_context: t(),
+        },
+        WorkerMessage::AddProjectsBatch {
+            projects: vec![("/code/api".into(), "api".into())],
+            trace_context: t(),
+        },
+        WorkerMessage::ImportProjects {
+            paths: vec!["~/code/api".into()],
+            trace_context: t(),
+        },
+        WorkerMessage::SyncSessions {
+            active_sessions: vec!["api".into()],
+            session_cwds: HashMap::from([("api".into(), "/code/api".into())]),
+            trace_context: t(),
+        },
+        WorkerMessage::LoadSessions { trace_context: t() },
+        WorkerMessage::LoadSelection { trace_context: t() },
+        WorkerMessage::SaveSelection {
+            path: "/code/api".into(),
+            trace_context: t(),
+        },
+        WorkerMessage::LoadUiState { trace_context: t() },
+        WorkerMessage::SaveUiState {
+            view_mode: "all".into(),
+            query: "api".into(),
+            search_history: vec!["api".into(), "web".into()],
+            trace_context: t(),
+        },
+        WorkerMessage::ExportProjects { trace_context: t() },
+    ]
+}
+
+#[test]
+fn every_message_variant_is_covered() {
+    // Exhaustive on purpose: a new variant fails to compile until it is listed
+    // in `all_messages`.
+    let variant = |message: &WorkerMessage| match message {
+        WorkerMessage::Configure { .. } => "Configure",
+        WorkerMessage::LoadProjects { .. } => "LoadProjects",
+        WorkerMessage::FetchProjects { .. } => "FetchProjects",
+        WorkerMessage::LoadProjectsPage { .. } => "LoadProjectsPage",
+        WorkerMessage::UpdateFrecency { .. } => "UpdateFrecency",
+        WorkerMessage::ResetFrecency { .. } => "ResetFrecency",
+        WorkerMessage::DeleteProject { .. } => "DeleteProject",
+        WorkerMessage::TogglePin { .. } => "TogglePin",
+        WorkerMessage::RenameProject { .. } => "RenameProject",
+        WorkerMessage::UpdateProjectLayout { .. } => "UpdateProjectLayout",
+        WorkerMessage::AddProjectsBatch { .. } => "AddProjectsBatch",
+        WorkerMessage::ImportProjects { .. } => "ImportProjects",
+        WorkerMessage::SyncSessions { .. } => "SyncSessions",
+        WorkerMessage::LoadSessions { .. } => "LoadSessions",
+        WorkerMessage::LoadSelection { .. } => "LoadSelection",
+        WorkerMessage::SaveSelection { .. } => "SaveSelection",
+        WorkerMessage::LoadUiState { .. } => "LoadUiState",
+        WorkerMessage::SaveUiState { .. } => "SaveUiState",
+        WorkerMessage::ExportProjects { .. } => "ExportProjects",
+    };
+    let messages = all_messages(None);
+    assert_eq!(
+        messages.iter().map(variant).collect::<HashSet<_>>().len(),
+        messages.len()
+    );
+}
+
+#[test]
+fn messages_round_trip_with_a_trace_context() {
+    let trace = TraceContext {
+        trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".to_string(),
+        parent_span_id: "00f067aa0ba902b7".to_string(),
+    };
+    for message in all_messages(Some(&trace)) {
+        let json = message.to_json().unwrap();
+        assert!(json.contains("\"trace_context\""), "{json}");
+        assert_eq!(WorkerMessage::from_json(&json).unwrap(), message);
+    }
+}
+
+#[test]
+fn messages_round_trip_without_a_trace_context() {
+    // An absent trace context is left out of the payload and stays absent.
+    for message in all_messages(None) {
+        let json = message.to_json().unwrap();
+        assert!(!json.contains("trace_context"), "{json}");
+        assert_eq!(WorkerMessage::from_json(&json).unwrap(), message);
+    }
+}
+
+#[test]
+fn every_response_variant_round_trips() {
+    let mut project = Project::new("/code/api".to_string(), "api".to_string());
+    project.pinned = true;
+    project.layout = Some("compact".to_string());
+    project.tags = vec!["work".to_string()];
+    let projects = vec![
+        project,
+        Project::new("/code/web".to_string(), "web".to_string()),
+    ];
+
+    let responses = vec![
+        WorkerResponse::Configured,
+        WorkerResponse::ProjectsLoaded {
+            projects: projects.clone(),
+        },
+        WorkerResponse::ProjectsPage {
+            offset: 2,
+            total: 4,
+            projects: projects.clone(),
+        },
+        WorkerResponse::ProjectsHash {
+            hash: u64::MAX,
+            changed: true,
+        },
+        WorkerResponse::FrecencyUpdated {
+            path: "/code/api".into(),
+        },
+        WorkerResponse::ProjectDeleted {
+            path: "/code/api".into(),
+        },
+        WorkerResponse::ProjectRenamed {
+            path: "/code/api".into(),
+            name: "svc".into(),
+        },
+        WorkerResponse::ProjectLayoutUpdated {
+            path: "/code/api".into(),
+            layout: None,
+        },
+        WorkerResponse::ProjectsBatchAdded { count: 2, projects },
+        WorkerResponse::ScanSummary {
+            discovered: 3,
+            added: 1,
+            updated: 2,
+        },
+        WorkerResponse::SessionsSynced { count: 1 },
+        WorkerResponse::SessionsLoaded {
+            names: vec!["api".into()],
+        },
+        WorkerResponse::SelectionLoaded {
+            path: Some("/code/api".into()),
+        },
+        WorkerResponse::SelectionSaved {
+            path: "/code/api".into(),
+        },
+        WorkerResponse::UiStateLoaded {
+            view_mode: Some("sessions".into()),
+            query: "api".into(),
+            search_history: vec!["api".into()],
+        },
+        WorkerResponse::UiStateSaved,
+        WorkerResponse::ProjectsExported {
+            json: "{\"version\":1}".into(),
+        },
+        WorkerResponse::Error {
+            message: "storage not initialized".into(),
+        },
+    ];
+
+    // Exhaustive on purpose: a new variant fails to compile until it is listed above.
+    let variant = |response: &WorkerResponse| match response {
+        WorkerResponse::Configured => "Configured",
+        WorkerResponse::ProjectsLoaded { .. } => "ProjectsLoaded",
+        WorkerResponse::ProjectsPage { .. } => "ProjectsPage",
+        WorkerResponse::ProjectsHash { .. } => "ProjectsHash",
+        WorkerResponse::FrecencyUpdated { .. } => "FrecencyUpdated",
+        WorkerResponse::ProjectDeleted { .. } => "ProjectDeleted",
+        WorkerResponse::ProjectRenamed { .. } => "ProjectRenamed",
+        WorkerResponse::ProjectLayoutUpdated { .. } => "ProjectLayoutUpdated",
+        WorkerResponse::ProjectsBatchAdded { .. } => "ProjectsBatchAdded",
+        WorkerResponse::ScanSummary { .. } => "ScanSummary",
+        WorkerResponse::SessionsSynced { .. } => "SessionsSynced",
+        WorkerResponse::SessionsLoaded { .. } => "SessionsLoaded",
+        WorkerResponse::SelectionLoaded { .. } => "SelectionLoaded",
+        WorkerResponse::SelectionSaved { .. } => "SelectionSaved",
+        WorkerResponse::UiStateLoaded { .. } => "UiStateLoaded",
+        WorkerResponse::UiStateSaved => "UiStateSaved",
+        WorkerResponse::ProjectsExported { .. } => "ProjectsExported",
+        WorkerResponse::Error { .. } => "Error",
+    };
+    assert_eq!(
+        responses.iter().map(variant).collect::<HashSet<_>>().len(),
+        responses.len()
+    );
+
+    for response in responses {
+        let json = response.to_json().unwrap();
+        assert_eq!(WorkerResponse::from_json(&json).unwrap(), response);
+    }
+}
+
+#[test]
+fn deleted_projects_are_no_longer_loaded() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+    let scan = ["api", "web"].map(|name| (format!("/code/{name}"), name.to_string()));
+    worker.handle_message(WorkerMessage::add_projects_batch(scan.to_vec()));
+
+    assert_eq!(
+        worker.handle_message(WorkerMessage::delete_project("/code/api".to_string())),
+        WorkerResponse::ProjectDeleted {
+            path: "/code/api".to_string()
+        }
+    );
+    let WorkerResponse::ProjectsLoaded { projects } =
+        worker.handle_message(WorkerMessage::load_projects(false))
+    else {
+        panic!("expected projects");
+    };
+    let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["web"]);
+}
+
+#[test]
+fn scans_are_summarized_after_the_main_response() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+
+    worker.handle_message(WorkerMessage::add_projects_batch(common::scan(&[
+        "api", "web",
+    ])));
+    assert_eq!(
+        worker.take_follow_ups(),
+        [WorkerResponse::ScanSummary {
+            discovered: 2,
+            added: 2,
+            updated: 0
+        }]
+    );
+
+    worker.handle_message(WorkerMessage::add_projects_batch(common::scan(&[
+        "api", "web", "cli",
+    ])));
+    assert_eq!(
+        worker.take_follow_ups(),
+        [WorkerResponse::ScanSummary {
+            discovered: 3,
+            added: 1,
+            updated: 2
+        }]
+    );
+    assert!(worker.take_follow_ups().is_empty());
+}
+
+#[test]
+fn pages_start_at_the_requested_offset() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+    worker.handle_message(WorkerMessage::add_projects_batch(common::scan(&[
+        "api", "cli", "web",
+    ])));
+
+    let WorkerResponse::ProjectsPage {
+        offset,
+        total,
+        projects,
+    } = worker.handle_message(WorkerMessage::load_projects_page(1, 5))
+    else {
+        panic!("expected a project page");
+    };
+    assert_eq!((offset, total), (1, 3));
+    let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["cli", "web"]);
+}
+
+#[test]
+fn exports_list_projects_in_frecency_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+    worker.handle_message(WorkerMessage::add_projects_batch(common::scan(&[
+        "api", "web",
+    ])));
+    worker.handle_message(WorkerMessage::update_frecency("/code/web".to_string()));
+
+    let WorkerResponse::ProjectsExported { json } =
+        worker.handle_message(WorkerMessage::export_projects())
+    else {
+        panic!("expected an export");
+    };
+    let export = ProjectsExport::from_json(&json).unwrap();
+    let names: Vec<_> = export.projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["web", "api"]);
+    assert!(export.projects[0].frecency > export.projects[1].frecency);
+}
+
+#[test]
+fn imports_are_stored_like_a_scan() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+
+    let paths = ["~/code/api", "/opt/src/web/", "~", "~/code/api"].map(String::from);
+    let WorkerResponse::ProjectsBatchAdded { count, projects } =
+        worker.handle_message(WorkerMessage::import_projects(paths.to_vec()))
+    else {
+        panic!("expected a batch response");
+    };
+    assert_eq!(count, 2);
+    let mut stored: Vec<_> = projects
+        .iter()
+        .map(|p| (p.path.as_str(), p.name.as_str()))
+        .collect();
+    stored.sort_unstable();
+    assert_eq!(stored, [("/opt/src/web", "web"), ("code/api", "api")]);
+    assert_eq!(
+        worker.take_follow_ups(),
+        [WorkerResponse::ScanSummary {
+            discovered: 2,
+            added: 2,
+            updated: 0
+        }]
+    );
+}
+
+#[test]
+fn reset_projects_reload_in_their_new_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut worker = common::worker(dir.path());
+    worker.handle_message(WorkerMessage::add_projects_batch(common::scan(&[
+        "api", "web",
+    ])));
+    worker.handle_message(WorkerMessage::update_frecency("/code/web".to_string()));
+
+    let WorkerResponse::ProjectsLoaded { projects } =
+        worker.handle_message(WorkerMessage::reset_frecency(Some("/code/web".to_string())))
+    else {
+        panic!("expected the reloaded projects");
+    };
+    let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["api", "web"]);
+    assert!(projects.iter().all(|p| p.access_count == 1));
+}